@@ -1,5 +1,6 @@
 //! GitHub Pull Request review comment creation operation.
 
+use crate::github::suggested_change::{SuggestedChange, validate_suggestions};
 use crate::github::{error::GitHubError, util::spawn_task};
 use crate::runtime::AsyncTask;
 use octocrab::Octocrab;
@@ -32,6 +33,9 @@ pub struct AddPullRequestReviewCommentRequest {
     pub subject_type: Option<String>,
     /// Comment ID to reply to (for threaded comments)
     pub in_reply_to: Option<u64>,
+    /// Replacement text for `start_line..=line`, posted as a suggested change.
+    /// `body` becomes the explanation above the suggestion block.
+    pub suggestion: Option<String>,
 }
 
 /// Add a single review comment (or reply).
@@ -40,6 +44,58 @@ pub(crate) fn add_pull_request_review_comment(
     request: AddPullRequestReviewCommentRequest,
 ) -> AsyncTask<Result<octocrab::models::pulls::ReviewComment, GitHubError>> {
     spawn_task(async move {
+        let mut request = request;
+
+        // A suggestion is validated against the diff and rewritten into a regular comment
+        if let Some(replacement) = request.suggestion.take() {
+            if request.in_reply_to.is_some() {
+                return Err(GitHubError::InvalidInput(
+                    "Suggestions cannot be posted as replies".to_string(),
+                ));
+            }
+            if request
+                .side
+                .as_deref()
+                .is_some_and(|s| !s.eq_ignore_ascii_case("RIGHT"))
+                || request
+                    .start_side
+                    .as_deref()
+                    .is_some_and(|s| !s.eq_ignore_ascii_case("RIGHT"))
+            {
+                return Err(GitHubError::InvalidInput(
+                    "Suggestions can only target the RIGHT side".to_string(),
+                ));
+            }
+            let (Some(path), Some(line)) = (request.path.clone(), request.line) else {
+                return Err(GitHubError::InvalidInput(
+                    "Suggestions require path and line".to_string(),
+                ));
+            };
+
+            let suggestion = SuggestedChange {
+                path,
+                start_line: request.start_line,
+                line,
+                replacement,
+                comment: Some(request.body.clone()),
+            };
+            validate_suggestions(
+                &inner,
+                &request.owner,
+                &request.repo,
+                request.pr_number,
+                std::slice::from_ref(&suggestion),
+            )
+            .await?;
+
+            request.body = suggestion.body();
+            // GitHub rejects a multi-line range that starts and ends on the same line
+            request.start_line = suggestion.start_line.filter(|&s| s != line);
+            request.side = Some("RIGHT".to_string());
+            request.start_side = request.start_line.map(|_| "RIGHT".to_string());
+            request.subject_type = None;
+        }
+
         // If this is a reply to an existing comment, use reply_to_comment
         if let Some(comment_id) = request.in_reply_to {
             return inner
@@ -0,0 +1,512 @@
+//! Bulk issue triage: apply one label/assignee/milestone/state change to many
+//! issues, either listed by number or matched by a search query.
+//!
+//! Each issue is planned against its current state first, so only real
+//! changes are sent and a dry run can report exactly what would happen.
+//! Failures are recorded per issue instead of aborting the batch.
+
+use crate::github::error::GitHubError;
+use crate::github::issue_moderation::CloseReason;
+use crate::github::search_issues::search_issues_page;
+use crate::github::sub_issues::IssueRef;
+use crate::github::util::spawn_task;
+use crate::runtime::AsyncTask;
+use futures::stream::{self, StreamExt};
+use octocrab::{Octocrab, models::IssueState, models::issues::Issue};
+use serde::Serialize;
+use std::sync::Arc;
+
+/// Concurrent requests used when none is given.
+pub const DEFAULT_BULK_CONCURRENCY: usize = 4;
+
+/// Upper bound on concurrent requests, to stay clear of secondary rate limits.
+pub const MAX_BULK_CONCURRENCY: usize = 10;
+
+/// Upper bound on issues touched by one batch (also the search API's cap).
+pub const MAX_BULK_ISSUES: usize = 1000;
+
+/// Which issues a batch applies to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BulkIssueTarget {
+    /// Issue or PR numbers in the request's repository
+    Numbers(Vec<u64>),
+    /// A `search_issues` query; scoped to the request's repository unless it
+    /// names a `repo:`, `org:` or `user:` itself
+    Query(String),
+}
+
+/// The change applied to every targeted issue.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BulkIssueChange {
+    /// Labels to add
+    pub add_labels: Vec<String>,
+    /// Labels to remove
+    pub remove_labels: Vec<String>,
+    /// Replace all labels with these
+    pub set_labels: Option<Vec<String>>,
+    /// Users to assign
+    pub add_assignees: Vec<String>,
+    /// Users to unassign
+    pub remove_assignees: Vec<String>,
+    /// Milestone number to set
+    pub milestone: Option<u64>,
+    /// Remove the milestone (ignored when `milestone` is set)
+    pub clear_milestone: bool,
+    /// Open or close
+    pub state: Option<IssueState>,
+    /// Close with this reason (implies `state: closed`)
+    pub state_reason: Option<CloseReason>,
+}
+
+impl BulkIssueChange {
+    /// Reject changes that do nothing or contradict themselves.
+    pub fn validate(&self) -> Result<(), GitHubError> {
+        let invalid = |msg: &str| Err(GitHubError::InvalidInput(msg.to_string()));
+
+        if self.set_labels.is_some()
+            && (!self.add_labels.is_empty() || !self.remove_labels.is_empty())
+        {
+            return invalid("set_labels cannot be combined with add_labels or remove_labels");
+        }
+        if let Some(label) = self
+            .add_labels
+            .iter()
+            .find(|a| contains_ignore_case(&self.remove_labels, a))
+        {
+            return invalid(&format!("Label '{label}' is both added and removed"));
+        }
+        if let Some(user) = self
+            .add_assignees
+            .iter()
+            .find(|a| contains_ignore_case(&self.remove_assignees, a))
+        {
+            return invalid(&format!("User '{user}' is both assigned and unassigned"));
+        }
+        if self.state_reason.is_some() && matches!(self.state, Some(IssueState::Open)) {
+            return invalid("state_reason requires state closed");
+        }
+        if self.add_labels.is_empty()
+            && self.remove_labels.is_empty()
+            && self.set_labels.is_none()
+            && self.add_assignees.is_empty()
+            && self.remove_assignees.is_empty()
+            && self.milestone.is_none()
+            && !self.clear_milestone
+            && self.state.is_none()
+            && self.state_reason.is_none()
+        {
+            return invalid("No changes requested");
+        }
+        Ok(())
+    }
+}
+
+/// The fields of an issue that a bulk change looks at.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IssueSnapshot {
+    /// Label names
+    pub labels: Vec<String>,
+    /// Assignee logins
+    pub assignees: Vec<String>,
+    /// Milestone number
+    pub milestone: Option<u64>,
+    /// Whether the issue is closed
+    pub closed: bool,
+}
+
+impl From<&Issue> for IssueSnapshot {
+    fn from(issue: &Issue) -> Self {
+        Self {
+            labels: issue.labels.iter().map(|l| l.name.clone()).collect(),
+            assignees: issue.assignees.iter().map(|a| a.login.clone()).collect(),
+            milestone: issue
+                .milestone
+                .as_ref()
+                .and_then(|m| u64::try_from(m.number).ok()),
+            closed: matches!(issue.state, IssueState::Closed),
+        }
+    }
+}
+
+/// The fields to send for one issue; `None` leaves a field alone.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IssuePatch {
+    /// Complete new label set
+    pub labels: Option<Vec<String>>,
+    /// Complete new assignee set
+    pub assignees: Option<Vec<String>>,
+    /// New milestone; `Some(None)` clears it
+    pub milestone: Option<Option<u64>>,
+    /// New state
+    pub state: Option<IssueState>,
+    /// Close reason, sent with `state: closed`
+    pub state_reason: Option<CloseReason>,
+    /// Human readable summary, e.g. `+label:bug`, `milestone: none`
+    pub changes: Vec<String>,
+}
+
+impl IssuePatch {
+    /// Whether the issue already matches the requested change.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Request body for `PATCH /repos/{owner}/{repo}/issues/{number}`.
+    #[must_use]
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut body = serde_json::Map::new();
+        if let Some(labels) = &self.labels {
+            body.insert("labels".to_string(), serde_json::json!(labels));
+        }
+        if let Some(assignees) = &self.assignees {
+            body.insert("assignees".to_string(), serde_json::json!(assignees));
+        }
+        if let Some(milestone) = self.milestone {
+            body.insert("milestone".to_string(), serde_json::json!(milestone));
+        }
+        match self.state {
+            Some(IssueState::Closed) => {
+                body.insert("state".to_string(), "closed".into());
+                if let Some(reason) = self.state_reason {
+                    body.insert("state_reason".to_string(), reason.as_str().into());
+                }
+            }
+            Some(_) => {
+                body.insert("state".to_string(), "open".into());
+            }
+            None => {}
+        }
+        serde_json::Value::Object(body)
+    }
+}
+
+/// Work out what `change` means for an issue currently in state `current`.
+///
+/// Labels and assignees compare case-insensitively, as on GitHub. Closing an
+/// already closed issue is a no-op, since its close reason is not known here.
+#[must_use]
+pub fn plan_issue_patch(current: &IssueSnapshot, change: &BulkIssueChange) -> IssuePatch {
+    let mut patch = IssuePatch::default();
+
+    let labels = match &change.set_labels {
+        Some(set) => dedup_ignore_case(set.clone()),
+        None => {
+            let mut labels: Vec<String> = current
+                .labels
+                .iter()
+                .filter(|l| !contains_ignore_case(&change.remove_labels, l))
+                .cloned()
+                .collect();
+            for label in &change.add_labels {
+                if !contains_ignore_case(&labels, label) {
+                    labels.push(label.clone());
+                }
+            }
+            labels
+        }
+    };
+    let label_changes = diff("label", &current.labels, &labels);
+    if !label_changes.is_empty() {
+        patch.changes.extend(label_changes);
+        patch.labels = Some(labels);
+    }
+
+    let mut assignees: Vec<String> = current
+        .assignees
+        .iter()
+        .filter(|a| !contains_ignore_case(&change.remove_assignees, a))
+        .cloned()
+        .collect();
+    for user in &change.add_assignees {
+        if !contains_ignore_case(&assignees, user) {
+            assignees.push(user.clone());
+        }
+    }
+    let assignee_changes = diff("assignee", &current.assignees, &assignees);
+    if !assignee_changes.is_empty() {
+        patch.changes.extend(assignee_changes);
+        patch.assignees = Some(assignees);
+    }
+
+    let milestone = match change.milestone {
+        Some(number) => Some(Some(number)),
+        None if change.clear_milestone => Some(None),
+        None => None,
+    };
+    if let Some(milestone) = milestone.filter(|m| *m != current.milestone) {
+        patch.changes.push(match milestone {
+            Some(number) => format!("milestone: {number}"),
+            None => "milestone: none".to_string(),
+        });
+        patch.milestone = Some(milestone);
+    }
+
+    let close = change.state_reason.is_some() || matches!(change.state, Some(IssueState::Closed));
+    let reopen = matches!(change.state, Some(IssueState::Open));
+    if close && !current.closed {
+        patch.changes.push(match change.state_reason {
+            Some(reason) => format!("state: closed ({})", reason.as_str()),
+            None => "state: closed".to_string(),
+        });
+        patch.state = Some(IssueState::Closed);
+        patch.state_reason = change.state_reason;
+    } else if reopen && current.closed {
+        patch.changes.push("state: open".to_string());
+        patch.state = Some(IssueState::Open);
+    }
+
+    patch
+}
+
+/// Scope a search query to `owner/repo` and to issues and PRs, unless it
+/// already names where to search.
+#[must_use]
+pub fn scope_bulk_query(owner: &str, repo: &str, query: &str) -> String {
+    let query = query.trim();
+    let scoped = query
+        .split_whitespace()
+        .any(|t| ["repo:", "org:", "user:"].iter().any(|p| t.starts_with(p)));
+    if scoped {
+        query.to_string()
+    } else {
+        format!("repo:{owner}/{repo} {query}")
+    }
+}
+
+fn contains_ignore_case(haystack: &[String], needle: &str) -> bool {
+    haystack.iter().any(|h| h.eq_ignore_ascii_case(needle))
+}
+
+fn dedup_ignore_case(values: Vec<String>) -> Vec<String> {
+    let mut out: Vec<String> = Vec::with_capacity(values.len());
+    for value in values {
+        if !contains_ignore_case(&out, &value) {
+            out.push(value);
+        }
+    }
+    out
+}
+
+fn diff(kind: &str, before: &[String], after: &[String]) -> Vec<String> {
+    let added = after
+        .iter()
+        .filter(|a| !contains_ignore_case(before, a))
+        .map(|a| format!("+{kind}:{a}"));
+    let removed = before
+        .iter()
+        .filter(|b| !contains_ignore_case(after, b))
+        .map(|b| format!("-{kind}:{b}"));
+    added.chain(removed).collect()
+}
+
+/// Request parameters for a bulk issue update
+#[derive(Debug, Clone)]
+pub struct BulkIssueRequest {
+    /// Repository owner (user or organization)
+    pub owner: String,
+    /// Repository name
+    pub repo: String,
+    /// Issues to update
+    pub target: BulkIssueTarget,
+    /// Change applied to each issue
+    pub change: BulkIssueChange,
+    /// Maximum concurrent requests; defaults to `DEFAULT_BULK_CONCURRENCY`
+    /// and is capped at `MAX_BULK_CONCURRENCY`
+    pub concurrency: Option<usize>,
+    /// Maximum number of issues taken from a query; defaults to and is
+    /// capped at `MAX_BULK_ISSUES`
+    pub limit: Option<usize>,
+    /// Only report what would change
+    pub dry_run: bool,
+}
+
+impl BulkIssueRequest {
+    /// Concurrency to use, rejecting an explicit zero.
+    pub fn effective_concurrency(&self) -> Result<usize, GitHubError> {
+        match self.concurrency {
+            Some(0) => Err(GitHubError::InvalidInput(
+                "concurrency must be at least 1".to_string(),
+            )),
+            c => Ok(c
+                .unwrap_or(DEFAULT_BULK_CONCURRENCY)
+                .min(MAX_BULK_CONCURRENCY)),
+        }
+    }
+
+    /// Number of issues to take from a query, rejecting an explicit zero.
+    pub fn effective_limit(&self) -> Result<usize, GitHubError> {
+        match self.limit {
+            Some(0) => Err(GitHubError::InvalidInput(
+                "limit must be at least 1".to_string(),
+            )),
+            l => Ok(l.unwrap_or(MAX_BULK_ISSUES).min(MAX_BULK_ISSUES)),
+        }
+    }
+}
+
+/// What happened to one issue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkIssueStatus {
+    /// The change was applied
+    Updated,
+    /// The change would be applied (dry run)
+    WouldUpdate,
+    /// The issue already matched
+    Unchanged,
+    /// Fetching or updating the issue failed
+    Failed,
+}
+
+/// Per-issue result of a bulk update.
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkIssueResult {
+    /// Issue reference
+    pub issue: String,
+    /// Issue number
+    pub number: u64,
+    /// Issue title, when it could be fetched
+    pub title: Option<String>,
+    /// Outcome
+    pub status: BulkIssueStatus,
+    /// Changes applied or planned
+    pub changes: Vec<String>,
+    /// Error message for failures
+    pub error: Option<String>,
+}
+
+/// Outcome of a bulk update, in target order.
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkIssueReport {
+    /// Whether this was a dry run
+    pub dry_run: bool,
+    /// Per-issue results
+    pub results: Vec<BulkIssueResult>,
+}
+
+impl BulkIssueReport {
+    /// Number of results with the given status.
+    #[must_use]
+    pub fn count(&self, status: BulkIssueStatus) -> usize {
+        self.results.iter().filter(|r| r.status == status).count()
+    }
+}
+
+/// Apply `request.change` to every targeted issue.
+///
+/// Fails outright only for invalid input or a failed search; per-issue errors
+/// are reported in the results.
+pub(crate) fn bulk_update_issues(
+    inner: Arc<Octocrab>,
+    request: BulkIssueRequest,
+) -> AsyncTask<Result<BulkIssueReport, GitHubError>> {
+    spawn_task(async move {
+        request.change.validate()?;
+        let concurrency = request.effective_concurrency()?;
+        let limit = request.effective_limit()?;
+
+        let targets: Vec<(IssueRef, Option<Issue>)> = match &request.target {
+            BulkIssueTarget::Numbers(numbers) => {
+                if numbers.is_empty() {
+                    return Err(GitHubError::InvalidInput(
+                        "At least one issue number is required".to_string(),
+                    ));
+                }
+                if numbers.len() > MAX_BULK_ISSUES {
+                    return Err(GitHubError::InvalidInput(format!(
+                        "At most {MAX_BULK_ISSUES} issues can be updated at once"
+                    )));
+                }
+                let mut seen = std::collections::BTreeSet::new();
+                numbers
+                    .iter()
+                    .filter(|n| seen.insert(**n))
+                    .map(|&n| (IssueRef::new(&request.owner, &request.repo, n), None))
+                    .collect()
+            }
+            BulkIssueTarget::Query(query) => {
+                let query = scope_bulk_query(&request.owner, &request.repo, query);
+                search_issues_page(&inner, &query, limit)
+                    .await?
+                    .into_iter()
+                    .map(|issue| {
+                        let target = IssueRef::from_issue(&issue).unwrap_or_else(|| {
+                            IssueRef::new(&request.owner, &request.repo, issue.number)
+                        });
+                        (target, Some(issue))
+                    })
+                    .collect()
+            }
+        };
+
+        let (change, dry_run) = (&request.change, request.dry_run);
+        let results = stream::iter(targets)
+            .map(|(target, issue)| {
+                let inner = &inner;
+                async move { update_one(inner, target, issue, change, dry_run).await }
+            })
+            .buffered(concurrency)
+            .collect::<Vec<_>>()
+            .await;
+
+        Ok(BulkIssueReport { dry_run, results })
+    })
+}
+
+async fn update_one(
+    inner: &Octocrab,
+    target: IssueRef,
+    issue: Option<Issue>,
+    change: &BulkIssueChange,
+    dry_run: bool,
+) -> BulkIssueResult {
+    let mut result = BulkIssueResult {
+        issue: target.to_string(),
+        number: target.number,
+        title: issue.as_ref().map(|i| i.title.clone()),
+        status: BulkIssueStatus::Failed,
+        changes: Vec::new(),
+        error: None,
+    };
+
+    let issue = match issue {
+        Some(issue) => issue,
+        None => match inner
+            .issues(&target.owner, &target.repo)
+            .get(target.number)
+            .await
+        {
+            Ok(issue) => issue,
+            Err(e) => {
+                result.error = Some(GitHubError::from(e).to_string());
+                return result;
+            }
+        },
+    };
+    result.title = Some(issue.title.clone());
+
+    let patch = plan_issue_patch(&IssueSnapshot::from(&issue), change);
+    result.changes.clone_from(&patch.changes);
+    if patch.is_empty() {
+        result.status = BulkIssueStatus::Unchanged;
+        return result;
+    }
+    if dry_run {
+        result.status = BulkIssueStatus::WouldUpdate;
+        return result;
+    }
+
+    let route = format!(
+        "/repos/{}/{}/issues/{}",
+        target.owner, target.repo, target.number
+    );
+    match inner
+        .patch::<Issue, _, _>(route, Some(&patch.to_json()))
+        .await
+    {
+        Ok(_) => result.status = BulkIssueStatus::Updated,
+        Err(e) => result.error = Some(GitHubError::from(e).to_string()),
+    }
+    result
+}
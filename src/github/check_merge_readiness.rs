@@ -0,0 +1,359 @@
+//! GitHub Pull Request pre-merge readiness check.
+
+use crate::github::{error::GitHubError, util::spawn_task};
+use crate::runtime::AsyncTask;
+use octocrab::{
+    Octocrab, Page,
+    models::{
+        CombinedStatus, IssueState, StatusState,
+        pulls::{MergeableState, PullRequest, Review, ReviewState},
+    },
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// A single reason why a pull request cannot be merged right now.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum MergeBlocker {
+    /// The pull request is closed or already merged.
+    NotOpen,
+    /// The pull request is still a draft.
+    Draft,
+    /// The head branch conflicts with the base branch.
+    Conflicts,
+    /// GitHub has not finished computing mergeability yet; retry shortly.
+    MergeabilityUnknown,
+    /// The head branch is behind the base and the base requires up-to-date branches.
+    BehindBase,
+    /// The head moved since the caller last looked at the pull request.
+    HeadChanged { expected: String, actual: String },
+    /// A required check or status finished unsuccessfully.
+    CheckFailed { name: String, conclusion: String },
+    /// A required check or status has not finished.
+    CheckPending { name: String },
+    /// A required check has not reported on the head commit at all.
+    CheckMissing { name: String },
+    /// Fewer approving reviews than branch protection requires.
+    InsufficientApprovals { required: u32, approved: u32 },
+    /// One or more reviewers currently request changes.
+    ChangesRequested { reviewers: Vec<String> },
+    /// GitHub reports the merge as blocked for a reason not covered above.
+    BlockedByProtection,
+}
+
+impl std::fmt::Display for MergeBlocker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotOpen => write!(f, "pull request is not open"),
+            Self::Draft => write!(f, "pull request is a draft"),
+            Self::Conflicts => write!(f, "head branch has merge conflicts with base"),
+            Self::MergeabilityUnknown => {
+                write!(f, "mergeability is still being computed by GitHub")
+            }
+            Self::BehindBase => write!(f, "head branch is behind base"),
+            Self::HeadChanged { expected, actual } => {
+                write!(f, "head SHA changed (expected {expected}, found {actual})")
+            }
+            Self::CheckFailed { name, conclusion } => {
+                write!(f, "required check '{name}' finished with '{conclusion}'")
+            }
+            Self::CheckPending { name } => write!(f, "required check '{name}' is pending"),
+            Self::CheckMissing { name } => {
+                write!(f, "required check '{name}' has not reported")
+            }
+            Self::InsufficientApprovals { required, approved } => {
+                write!(f, "{approved} of {required} required approvals")
+            }
+            Self::ChangesRequested { reviewers } => {
+                write!(f, "changes requested by {}", reviewers.join(", "))
+            }
+            Self::BlockedByProtection => write!(f, "merge blocked by branch protection"),
+        }
+    }
+}
+
+/// Result of a pre-merge readiness check.
+#[derive(Debug, Clone, Serialize)]
+pub struct MergeReadiness {
+    /// Head SHA the check was evaluated against.
+    pub head_sha: String,
+    /// Everything currently preventing the merge. Empty when ready.
+    pub blockers: Vec<MergeBlocker>,
+}
+
+impl MergeReadiness {
+    /// Whether the pull request can be merged.
+    #[must_use]
+    pub fn is_ready(&self) -> bool {
+        self.blockers.is_empty()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CheckRunList {
+    total_count: usize,
+    check_runs: Vec<CheckRun>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CheckRun {
+    name: String,
+    status: String,
+    conclusion: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct RequiredStatusChecks {
+    #[serde(default)]
+    strict: bool,
+    #[serde(default)]
+    contexts: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct RequiredReviews {
+    #[serde(default)]
+    required_approving_review_count: u32,
+}
+
+/// Check whether a pull request is ready to merge.
+///
+/// Branch protection is read when the token is allowed to; otherwise every
+/// check reported on the head commit is treated as required.
+pub(crate) fn check_merge_readiness(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    pr_number: u64,
+    expected_head_sha: Option<String>,
+) -> AsyncTask<Result<MergeReadiness, GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+
+    spawn_task(async move {
+        evaluate_merge_readiness(
+            &inner,
+            &owner,
+            &repo,
+            pr_number,
+            expected_head_sha.as_deref(),
+        )
+        .await
+    })
+}
+
+/// Shared implementation used by both the readiness check and the merge guard.
+pub(crate) async fn evaluate_merge_readiness(
+    inner: &Octocrab,
+    owner: &str,
+    repo: &str,
+    pr_number: u64,
+    expected_head_sha: Option<&str>,
+) -> Result<MergeReadiness, GitHubError> {
+    let pr: PullRequest = inner
+        .pulls(owner, repo)
+        .get(pr_number)
+        .await
+        .map_err(GitHubError::from)?;
+
+    let head_sha = pr.head.sha.clone();
+    let base = pr.base.ref_field.clone();
+    let mut blockers = Vec::new();
+
+    if pr.state != Some(IssueState::Open) || pr.merged_at.is_some() {
+        blockers.push(MergeBlocker::NotOpen);
+    }
+    if pr.draft.unwrap_or(false) {
+        blockers.push(MergeBlocker::Draft);
+    }
+    if let Some(expected) = expected_head_sha
+        && expected != head_sha
+    {
+        blockers.push(MergeBlocker::HeadChanged {
+            expected: expected.to_string(),
+            actual: head_sha.clone(),
+        });
+    }
+
+    match (pr.mergeable, &pr.mergeable_state) {
+        (Some(false), _) | (_, Some(MergeableState::Dirty)) => {
+            blockers.push(MergeBlocker::Conflicts);
+        }
+        (None, _) | (_, Some(MergeableState::Unknown)) => {
+            blockers.push(MergeBlocker::MergeabilityUnknown);
+        }
+        _ => {}
+    }
+
+    // Branch protection endpoints need admin read access; missing access is not a blocker.
+    let encoded_base = urlencoding::encode(&base);
+    let status_checks: Option<RequiredStatusChecks> = inner
+        .get(
+            format!(
+                "/repos/{owner}/{repo}/branches/{encoded_base}/protection/required_status_checks"
+            ),
+            None::<&()>,
+        )
+        .await
+        .ok();
+    let required_reviews: Option<RequiredReviews> = inner
+        .get(
+            format!(
+                "/repos/{owner}/{repo}/branches/{encoded_base}/protection/required_pull_request_reviews"
+            ),
+            None::<&()>,
+        )
+        .await
+        .ok();
+
+    if matches!(pr.mergeable_state, Some(MergeableState::Behind))
+        && status_checks.as_ref().is_some_and(|c| c.strict)
+    {
+        blockers.push(MergeBlocker::BehindBase);
+    }
+
+    // Collect check runs and commit statuses into one name -> outcome map.
+    let check_runs = fetch_check_runs(inner, owner, repo, &head_sha).await?;
+    let combined_status: CombinedStatus = inner
+        .get(
+            format!("/repos/{owner}/{repo}/commits/{head_sha}/status"),
+            None::<&()>,
+        )
+        .await
+        .map_err(GitHubError::from)?;
+
+    let mut outcomes: HashMap<String, Option<String>> = HashMap::new();
+    for run in check_runs {
+        let outcome = if run.status == "completed" {
+            Some(run.conclusion.unwrap_or_else(|| "unknown".to_string()))
+        } else {
+            None
+        };
+        outcomes.insert(run.name, outcome);
+    }
+    for status in combined_status.statuses {
+        let Some(context) = status.context else {
+            continue;
+        };
+        let outcome = match status.state {
+            StatusState::Pending => None,
+            StatusState::Success => Some("success".to_string()),
+            StatusState::Failure => Some("failure".to_string()),
+            _ => Some("error".to_string()),
+        };
+        outcomes.insert(context, outcome);
+    }
+
+    let required: Vec<String> = match &status_checks {
+        Some(checks) => checks.contexts.clone(),
+        None => {
+            let mut names: Vec<String> = outcomes.keys().cloned().collect();
+            names.sort();
+            names
+        }
+    };
+    for name in required {
+        match outcomes.get(&name) {
+            None => blockers.push(MergeBlocker::CheckMissing { name }),
+            Some(None) => blockers.push(MergeBlocker::CheckPending { name }),
+            Some(Some(conclusion)) => {
+                if !matches!(conclusion.as_str(), "success" | "neutral" | "skipped") {
+                    blockers.push(MergeBlocker::CheckFailed {
+                        name,
+                        conclusion: conclusion.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    // Only the latest non-comment review from each reviewer counts.
+    let mut reviews = Vec::new();
+    let mut page: Page<Review> = inner
+        .pulls(owner, repo)
+        .list_reviews(pr_number)
+        .per_page(100)
+        .send()
+        .await
+        .map_err(GitHubError::from)?;
+    reviews.extend(page.items);
+    while let Some(next) = inner.get_page::<Review>(&page.next).await? {
+        page = next;
+        reviews.extend(page.items);
+    }
+
+    let mut latest: HashMap<String, ReviewState> = HashMap::new();
+    for review in reviews {
+        let (Some(user), Some(state)) = (review.user, review.state) else {
+            continue;
+        };
+        if matches!(state, ReviewState::Commented | ReviewState::Pending) {
+            continue;
+        }
+        latest.insert(user.login, state);
+    }
+
+    let approved = latest
+        .values()
+        .filter(|s| **s == ReviewState::Approved)
+        .count() as u32;
+    let mut requesting: Vec<String> = latest
+        .iter()
+        .filter(|(_, s)| **s == ReviewState::ChangesRequested)
+        .map(|(login, _)| login.clone())
+        .collect();
+    requesting.sort();
+
+    if !requesting.is_empty() {
+        blockers.push(MergeBlocker::ChangesRequested {
+            reviewers: requesting,
+        });
+    }
+    if let Some(reviews) = &required_reviews
+        && approved < reviews.required_approving_review_count
+    {
+        blockers.push(MergeBlocker::InsufficientApprovals {
+            required: reviews.required_approving_review_count,
+            approved,
+        });
+    }
+
+    // GitHub knows about rules we cannot read (rulesets, CODEOWNERS); surface them generically.
+    if matches!(pr.mergeable_state, Some(MergeableState::Blocked)) && blockers.is_empty() {
+        blockers.push(MergeBlocker::BlockedByProtection);
+    }
+
+    // Dedupe in case a context was reported twice through different APIs.
+    let mut seen = HashSet::new();
+    blockers.retain(|b| seen.insert(b.to_string()));
+
+    Ok(MergeReadiness { head_sha, blockers })
+}
+
+/// Every check run on `sha`. The endpoint wraps runs in an object that `Page`
+/// cannot decode, so pages are requested by number until `total_count` is reached.
+async fn fetch_check_runs(
+    inner: &Octocrab,
+    owner: &str,
+    repo: &str,
+    sha: &str,
+) -> Result<Vec<CheckRun>, GitHubError> {
+    let mut runs = Vec::new();
+    let mut page = 1u32;
+    loop {
+        let list: CheckRunList = inner
+            .get(
+                format!("/repos/{owner}/{repo}/commits/{sha}/check-runs?per_page=100&page={page}"),
+                None::<&()>,
+            )
+            .await
+            .map_err(GitHubError::from)?;
+        let fetched = list.check_runs.len();
+        runs.extend(list.check_runs);
+        if fetched < 100 || runs.len() >= list.total_count {
+            return Ok(runs);
+        }
+        page += 1;
+    }
+}
@@ -15,6 +15,7 @@ impl GitHubClient {
     }
 
     /// Create a new issue
+    #[allow(clippy::too_many_arguments)]
     pub fn create_issue(
         &self,
         owner: impl Into<String>,
@@ -23,6 +24,7 @@ impl GitHubClient {
         body: Option<String>,
         assignees: Option<Vec<String>>,
         labels: Option<Vec<String>>,
+        milestone: Option<u64>,
     ) -> crate::runtime::AsyncTask<Result<octocrab::models::issues::Issue, GitHubError>> {
         crate::github::create_issue::create_issue(
             self.inner.clone(),
@@ -32,6 +34,7 @@ impl GitHubClient {
             body,
             assignees,
             labels,
+            milestone,
         )
     }
 
@@ -76,6 +79,16 @@ impl GitHubClient {
         crate::github::list_issues::list_issues(self.inner.clone(), request)
     }
 
+    /// Stream the timeline of an issue or pull request
+    pub fn get_issue_timeline(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        issue_number: u64,
+    ) -> crate::runtime::AsyncStream<Result<crate::github::TimelineEvent, GitHubError>> {
+        crate::github::issue_timeline::get_issue_timeline(self.inner.clone(), owner, repo, issue_number)
+    }
+
     /// Update an issue
     #[must_use]
     pub fn update_issue(
@@ -103,4 +116,449 @@ impl GitHubClient {
             per_page,
         )
     }
+
+    /// List all labels in a repository
+    pub fn list_labels(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+    ) -> crate::runtime::AsyncStream<Result<octocrab::models::Label, GitHubError>> {
+        crate::github::labels::list_labels(self.inner.clone(), owner, repo)
+    }
+
+    /// Create a repository label
+    pub fn create_label(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        name: impl Into<String>,
+        color: impl Into<String>,
+        description: Option<String>,
+    ) -> crate::runtime::AsyncTask<Result<octocrab::models::Label, GitHubError>> {
+        crate::github::labels::create_label(self.inner.clone(), owner, repo, name, color, description)
+    }
+
+    /// Update or rename a repository label
+    pub fn update_label(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        name: impl Into<String>,
+        new_name: Option<String>,
+        color: Option<String>,
+        description: Option<String>,
+    ) -> crate::runtime::AsyncTask<Result<octocrab::models::Label, GitHubError>> {
+        crate::github::labels::update_label(
+            self.inner.clone(),
+            owner,
+            repo,
+            name,
+            new_name,
+            color,
+            description,
+        )
+    }
+
+    /// Delete a repository label
+    pub fn delete_label(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        name: impl Into<String>,
+    ) -> crate::runtime::AsyncTask<Result<(), GitHubError>> {
+        crate::github::labels::delete_label(self.inner.clone(), owner, repo, name)
+    }
+
+    /// Add labels to an issue or pull request
+    pub fn add_labels(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        issue_number: u64,
+        labels: Vec<String>,
+    ) -> crate::runtime::AsyncTask<Result<Vec<octocrab::models::Label>, GitHubError>> {
+        crate::github::labels::add_labels(self.inner.clone(), owner, repo, issue_number, labels)
+    }
+
+    /// Remove labels from an issue or pull request
+    pub fn remove_labels(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        issue_number: u64,
+        labels: Vec<String>,
+    ) -> crate::runtime::AsyncTask<Result<Vec<octocrab::models::Label>, GitHubError>> {
+        crate::github::labels::remove_labels(self.inner.clone(), owner, repo, issue_number, labels)
+    }
+
+    /// Reconcile repository labels with a label spec
+    pub fn sync_labels(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        spec: Vec<crate::github::LabelSpec>,
+        prune: bool,
+        dry_run: bool,
+    ) -> crate::runtime::AsyncTask<Result<Vec<crate::github::LabelChange>, GitHubError>> {
+        crate::github::labels::sync_labels(self.inner.clone(), owner, repo, spec, prune, dry_run)
+    }
+
+    /// List milestones in a repository
+    pub fn list_milestones(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        state: Option<String>,
+    ) -> crate::runtime::AsyncStream<Result<crate::github::Milestone, GitHubError>> {
+        crate::github::milestones::list_milestones(self.inner.clone(), owner, repo, state)
+    }
+
+    /// Get a single milestone
+    pub fn get_milestone(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        milestone_number: u64,
+    ) -> crate::runtime::AsyncTask<Result<crate::github::Milestone, GitHubError>> {
+        crate::github::milestones::get_milestone(self.inner.clone(), owner, repo, milestone_number)
+    }
+
+    /// Create a milestone
+    pub fn create_milestone(
+        &self,
+        request: crate::github::CreateMilestoneRequest,
+    ) -> crate::runtime::AsyncTask<Result<crate::github::Milestone, GitHubError>> {
+        crate::github::milestones::create_milestone(self.inner.clone(), request)
+    }
+
+    /// Update a milestone
+    pub fn update_milestone(
+        &self,
+        request: crate::github::UpdateMilestoneRequest,
+    ) -> crate::runtime::AsyncTask<Result<crate::github::Milestone, GitHubError>> {
+        crate::github::milestones::update_milestone(self.inner.clone(), request)
+    }
+
+    /// Delete a milestone
+    pub fn delete_milestone(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        milestone_number: u64,
+    ) -> crate::runtime::AsyncTask<Result<(), GitHubError>> {
+        crate::github::milestones::delete_milestone(self.inner.clone(), owner, repo, milestone_number)
+    }
+
+    /// Get milestone progress: counts, overdue items and burndown data
+    pub fn get_milestone_progress(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        milestone_number: u64,
+    ) -> crate::runtime::AsyncTask<Result<crate::github::MilestoneProgress, GitHubError>> {
+        crate::github::milestones::get_milestone_progress(
+            self.inner.clone(),
+            owner,
+            repo,
+            milestone_number,
+        )
+    }
+
+    /// Replace the body of an issue or PR conversation comment
+    pub fn update_issue_comment(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        comment_id: u64,
+        body: impl Into<String>,
+    ) -> crate::runtime::AsyncTask<Result<octocrab::models::issues::Comment, GitHubError>> {
+        crate::github::comments::update_issue_comment(self.inner.clone(), owner, repo, comment_id, body)
+    }
+
+    /// Replace the body of an inline PR review comment
+    pub fn update_review_comment(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        comment_id: u64,
+        body: impl Into<String>,
+    ) -> crate::runtime::AsyncTask<Result<octocrab::models::pulls::Comment, GitHubError>> {
+        crate::github::comments::update_review_comment(self.inner.clone(), owner, repo, comment_id, body)
+    }
+
+    /// Delete an issue or review comment
+    pub fn delete_comment(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        kind: crate::github::CommentKind,
+        comment_id: u64,
+    ) -> crate::runtime::AsyncTask<Result<(), GitHubError>> {
+        crate::github::comments::delete_comment(self.inner.clone(), owner, repo, kind, comment_id)
+    }
+
+    /// List reactions on an issue, pull request or comment
+    pub fn list_reactions(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        target: crate::github::ReactionTarget,
+        content: Option<octocrab::models::reactions::ReactionContent>,
+    ) -> crate::runtime::AsyncStream<Result<octocrab::models::reactions::Reaction, GitHubError>> {
+        crate::github::reactions::list_reactions(self.inner.clone(), owner, repo, target, content)
+    }
+
+    /// Add a reaction to an issue, pull request or comment
+    pub fn add_reaction(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        target: crate::github::ReactionTarget,
+        content: octocrab::models::reactions::ReactionContent,
+    ) -> crate::runtime::AsyncTask<Result<octocrab::models::reactions::Reaction, GitHubError>> {
+        crate::github::reactions::add_reaction(self.inner.clone(), owner, repo, target, content)
+    }
+
+    /// Remove the authenticated user's reaction from an issue, pull request or comment
+    pub fn remove_reaction(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        target: crate::github::ReactionTarget,
+        content: octocrab::models::reactions::ReactionContent,
+    ) -> crate::runtime::AsyncTask<Result<bool, GitHubError>> {
+        crate::github::reactions::remove_reaction(self.inner.clone(), owner, repo, target, content)
+    }
+
+    /// Create or update a sticky comment on an issue or pull request
+    pub fn upsert_sticky_comment(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        issue_number: u64,
+        key: impl Into<String>,
+        body: impl Into<String>,
+        author: Option<String>,
+    ) -> crate::runtime::AsyncTask<Result<crate::github::StickyCommentResult, GitHubError>> {
+        crate::github::sticky_comment::upsert_sticky_comment(
+            self.inner.clone(),
+            owner,
+            repo,
+            issue_number,
+            key,
+            body,
+            author,
+        )
+    }
+
+    /// Lock an issue or pull request conversation
+    pub fn lock_issue(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        issue_number: u64,
+        reason: Option<crate::github::LockReason>,
+    ) -> crate::runtime::AsyncTask<Result<(), GitHubError>> {
+        crate::github::issue_moderation::lock_issue(
+            self.inner.clone(),
+            owner,
+            repo,
+            issue_number,
+            reason,
+        )
+    }
+
+    /// Unlock an issue or pull request conversation
+    pub fn unlock_issue(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        issue_number: u64,
+    ) -> crate::runtime::AsyncTask<Result<(), GitHubError>> {
+        crate::github::issue_moderation::unlock_issue(self.inner.clone(), owner, repo, issue_number)
+    }
+
+    /// Transfer an issue to another repository
+    pub fn transfer_issue(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        issue_number: u64,
+        target_owner: impl Into<String>,
+        target_repo: impl Into<String>,
+        create_labels_if_missing: bool,
+    ) -> crate::runtime::AsyncTask<Result<crate::github::TransferredIssue, GitHubError>> {
+        crate::github::issue_moderation::transfer_issue(
+            self.inner.clone(),
+            owner,
+            repo,
+            issue_number,
+            target_owner,
+            target_repo,
+            create_labels_if_missing,
+        )
+    }
+
+    /// Pin or unpin an issue
+    pub fn set_issue_pinned(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        issue_number: u64,
+        pinned: bool,
+    ) -> crate::runtime::AsyncTask<Result<(), GitHubError>> {
+        crate::github::issue_moderation::set_issue_pinned(
+            self.inner.clone(),
+            owner,
+            repo,
+            issue_number,
+            pinned,
+        )
+    }
+
+    /// List the sub-issues of an issue
+    pub fn list_sub_issues(
+        &self,
+        issue: crate::github::IssueRef,
+    ) -> crate::runtime::AsyncStream<Result<octocrab::models::issues::Issue, GitHubError>> {
+        crate::github::sub_issues::list_sub_issues(self.inner.clone(), issue)
+    }
+
+    /// Add a sub-issue to an issue
+    pub fn add_sub_issue(
+        &self,
+        parent: crate::github::IssueRef,
+        sub_issue: crate::github::IssueRef,
+        replace_parent: bool,
+    ) -> crate::runtime::AsyncTask<Result<octocrab::models::issues::Issue, GitHubError>> {
+        crate::github::sub_issues::add_sub_issue(self.inner.clone(), parent, sub_issue, replace_parent)
+    }
+
+    /// Remove a sub-issue from an issue
+    pub fn remove_sub_issue(
+        &self,
+        parent: crate::github::IssueRef,
+        sub_issue: crate::github::IssueRef,
+    ) -> crate::runtime::AsyncTask<Result<octocrab::models::issues::Issue, GitHubError>> {
+        crate::github::sub_issues::remove_sub_issue(self.inner.clone(), parent, sub_issue)
+    }
+
+    /// Move a sub-issue within its parent's list
+    pub fn reprioritize_sub_issue(
+        &self,
+        parent: crate::github::IssueRef,
+        sub_issue: crate::github::IssueRef,
+        position: crate::github::SubIssuePosition,
+    ) -> crate::runtime::AsyncTask<Result<octocrab::models::issues::Issue, GitHubError>> {
+        crate::github::sub_issues::reprioritize_sub_issue(
+            self.inner.clone(),
+            parent,
+            sub_issue,
+            position,
+        )
+    }
+
+    /// Get the parent of a sub-issue
+    pub fn get_parent_issue(
+        &self,
+        issue: crate::github::IssueRef,
+    ) -> crate::runtime::AsyncTask<Result<Option<octocrab::models::issues::Issue>, GitHubError>>
+    {
+        crate::github::sub_issues::get_parent_issue(self.inner.clone(), issue)
+    }
+
+    /// Get the sub-issue tree below an issue, and its parent
+    pub fn get_issue_tree(
+        &self,
+        root: crate::github::IssueRef,
+        max_depth: usize,
+    ) -> crate::runtime::AsyncTask<
+        Result<(crate::github::IssueTreeNode, Option<crate::github::IssueRef>), GitHubError>,
+    > {
+        crate::github::sub_issues::get_issue_tree(self.inner.clone(), root, max_depth)
+    }
+
+    /// List the issues an issue is blocked by or blocking
+    pub fn list_issue_dependencies(
+        &self,
+        issue: crate::github::IssueRef,
+        kind: crate::github::DependencyKind,
+    ) -> crate::runtime::AsyncTask<Result<Vec<octocrab::models::issues::Issue>, GitHubError>> {
+        crate::github::sub_issues::list_issue_dependencies(self.inner.clone(), issue, kind)
+    }
+
+    /// Mark an issue as blocked by another
+    pub fn add_blocked_by(
+        &self,
+        issue: crate::github::IssueRef,
+        blocker: crate::github::IssueRef,
+    ) -> crate::runtime::AsyncTask<Result<octocrab::models::issues::Issue, GitHubError>> {
+        crate::github::sub_issues::add_blocked_by(self.inner.clone(), issue, blocker)
+    }
+
+    /// Remove a blocked-by relation between two issues
+    pub fn remove_blocked_by(
+        &self,
+        issue: crate::github::IssueRef,
+        blocker: crate::github::IssueRef,
+    ) -> crate::runtime::AsyncTask<Result<(), GitHubError>> {
+        crate::github::sub_issues::remove_blocked_by(self.inner.clone(), issue, blocker)
+    }
+
+    /// List the issue templates and template chooser config of a repository
+    pub fn list_issue_templates(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+    ) -> crate::runtime::AsyncTask<Result<crate::github::IssueTemplates, GitHubError>> {
+        crate::github::issue_templates::list_issue_templates(self.inner.clone(), owner, repo)
+    }
+
+    /// Create an issue from an issue template
+    pub fn create_issue_from_template(
+        &self,
+        request: crate::github::CreateIssueFromTemplateRequest,
+    ) -> crate::runtime::AsyncTask<Result<octocrab::models::issues::Issue, GitHubError>> {
+        crate::github::issue_templates::create_issue_from_template(self.inner.clone(), request)
+    }
+
+    /// Create an issue, optionally skipping it when likely duplicates exist
+    pub fn create_issue_checked(
+        &self,
+        request: crate::github::CreateIssueRequest,
+    ) -> crate::runtime::AsyncTask<Result<crate::github::CreateIssueOutcome, GitHubError>> {
+        crate::github::create_issue::create_issue_checked(self.inner.clone(), request)
+    }
+
+    /// Find existing issues similar to a candidate issue
+    pub fn find_similar_issues(
+        &self,
+        request: crate::github::FindSimilarIssuesRequest,
+    ) -> crate::runtime::AsyncTask<Result<Vec<crate::github::SimilarIssue>, GitHubError>> {
+        crate::github::similar_issues::find_similar_issues(self.inner.clone(), request)
+    }
+
+    /// Apply label, assignee, milestone and state changes to many issues
+    pub fn bulk_update_issues(
+        &self,
+        request: crate::github::BulkIssueRequest,
+    ) -> crate::runtime::AsyncTask<Result<crate::github::BulkIssueReport, GitHubError>> {
+        crate::github::bulk_issues::bulk_update_issues(self.inner.clone(), request)
+    }
+
+    /// Export issues and PRs with comments and timelines to a local file
+    pub fn export_issues(
+        &self,
+        request: crate::github::ExportIssuesRequest,
+    ) -> crate::runtime::AsyncTask<Result<crate::github::ExportSummary, GitHubError>> {
+        crate::github::issue_export::export_issues(self.inner.clone(), request)
+    }
+
+    /// Create issues in bulk, skipping ones an earlier import already created
+    pub fn import_issues(
+        &self,
+        request: crate::github::ImportIssuesRequest,
+    ) -> crate::runtime::AsyncTask<Result<crate::github::ImportReport, GitHubError>> {
+        crate::github::issue_import::import_issues(self.inner.clone(), request)
+    }
 }
@@ -13,6 +13,35 @@ impl GitHubClient {
         crate::github::create_pull_request::create_pull_request(self.inner.clone(), request)
     }
 
+    /// Generate a pull request title and body from commits and the repo template
+    #[must_use]
+    pub fn generate_pull_request_content(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        base: impl Into<String>,
+        head: impl Into<String>,
+        template: Option<String>,
+    ) -> crate::runtime::AsyncTask<Result<crate::github::PullRequestContent, GitHubError>> {
+        crate::github::pull_request_content::generate_pull_request_content(
+            self.inner.clone(),
+            owner,
+            repo,
+            base,
+            head,
+            template,
+        )
+    }
+
+    /// Create or retarget the pull requests of a branch stack
+    #[must_use]
+    pub fn sync_pull_request_stack(
+        &self,
+        request: crate::github::SyncPullRequestStackRequest,
+    ) -> crate::runtime::AsyncTask<Result<Vec<crate::github::StackEntry>, GitHubError>> {
+        crate::github::pull_request_stack::sync_pull_request_stack(self.inner.clone(), request)
+    }
+
     /// Get pull request status
     pub fn get_pull_request_status(
         &self,
@@ -58,6 +87,53 @@ impl GitHubClient {
         )
     }
 
+    /// Get pull request unified diff, parsed into files and hunks
+    pub fn get_pull_request_diff(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        pr_number: u64,
+    ) -> crate::runtime::AsyncTask<Result<crate::github::PullRequestDiff, GitHubError>> {
+        crate::github::get_pull_request_diff::get_pull_request_diff(
+            self.inner.clone(),
+            owner,
+            repo,
+            pr_number,
+        )
+    }
+
+    /// Get pull request as a `git format-patch` series
+    pub fn get_pull_request_patch(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        pr_number: u64,
+    ) -> crate::runtime::AsyncTask<Result<String, GitHubError>> {
+        crate::github::get_pull_request_diff::get_pull_request_patch(
+            self.inner.clone(),
+            owner,
+            repo,
+            pr_number,
+        )
+    }
+
+    /// List commits on a pull request
+    pub fn list_pull_request_commits(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        pr_number: u64,
+        include_stats: bool,
+    ) -> crate::runtime::AsyncStream<Result<octocrab::models::repos::RepoCommit, GitHubError>> {
+        crate::github::list_pull_request_commits::list_pull_request_commits(
+            self.inner.clone(),
+            owner,
+            repo,
+            pr_number,
+            include_stats,
+        )
+    }
+
     /// Get pull request reviews
     pub fn get_pull_request_reviews(
         &self,
@@ -73,6 +149,100 @@ impl GitHubClient {
         )
     }
 
+    /// Request reviews from users and teams
+    pub fn request_reviewers(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        pr_number: u64,
+        reviewers: Vec<String>,
+        team_reviewers: Vec<String>,
+    ) -> crate::runtime::AsyncTask<Result<octocrab::models::pulls::PullRequest, GitHubError>> {
+        crate::github::requested_reviewers::request_reviewers(
+            self.inner.clone(),
+            owner,
+            repo,
+            pr_number,
+            reviewers,
+            team_reviewers,
+        )
+    }
+
+    /// Remove requested user and team reviewers
+    pub fn remove_requested_reviewers(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        pr_number: u64,
+        reviewers: Vec<String>,
+        team_reviewers: Vec<String>,
+    ) -> crate::runtime::AsyncTask<Result<octocrab::models::pulls::PullRequest, GitHubError>> {
+        crate::github::requested_reviewers::remove_requested_reviewers(
+            self.inner.clone(),
+            owner,
+            repo,
+            pr_number,
+            reviewers,
+            team_reviewers,
+        )
+    }
+
+    /// Suggest (and optionally request) reviewers from CODEOWNERS
+    pub fn suggest_reviewers(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        pr_number: u64,
+        request: bool,
+    ) -> crate::runtime::AsyncTask<Result<crate::github::ReviewerSuggestion, GitHubError>> {
+        crate::github::codeowners::suggest_reviewers(
+            self.inner.clone(),
+            owner,
+            repo,
+            pr_number,
+            request,
+        )
+    }
+
+    /// List review threads on a pull request
+    pub fn list_review_threads(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        pr_number: u64,
+    ) -> crate::runtime::AsyncStream<Result<crate::github::ReviewThread, GitHubError>> {
+        crate::github::review_threads::list_review_threads(
+            self.inner.clone(),
+            owner,
+            repo,
+            pr_number,
+        )
+    }
+
+    /// Resolve a review thread by its node ID
+    pub fn resolve_review_thread(
+        &self,
+        thread_id: impl Into<String>,
+    ) -> crate::runtime::AsyncTask<Result<bool, GitHubError>> {
+        crate::github::review_threads::set_review_thread_resolved(
+            self.inner.clone(),
+            thread_id,
+            true,
+        )
+    }
+
+    /// Unresolve a review thread by its node ID
+    pub fn unresolve_review_thread(
+        &self,
+        thread_id: impl Into<String>,
+    ) -> crate::runtime::AsyncTask<Result<bool, GitHubError>> {
+        crate::github::review_threads::set_review_thread_resolved(
+            self.inner.clone(),
+            thread_id,
+            false,
+        )
+    }
+
     /// Create a pull request review
     pub fn create_pull_request_review(
         &self,
@@ -103,6 +273,117 @@ impl GitHubClient {
         )
     }
 
+    /// Start a pending review
+    pub fn create_pending_review(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        pr_number: u64,
+        body: Option<String>,
+        commit_id: Option<String>,
+    ) -> crate::runtime::AsyncTask<Result<octocrab::models::pulls::Review, GitHubError>> {
+        crate::github::pending_review::create_pending_review(
+            self.inner.clone(),
+            owner,
+            repo,
+            pr_number,
+            body,
+            commit_id,
+        )
+    }
+
+    /// Add an inline comment to a pending review
+    pub fn add_pending_review_comment(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        pr_number: u64,
+        review_id: u64,
+        comment: crate::github::PendingReviewComment,
+    ) -> crate::runtime::AsyncTask<Result<String, GitHubError>> {
+        crate::github::pending_review::add_pending_review_comment(
+            self.inner.clone(),
+            owner,
+            repo,
+            pr_number,
+            review_id,
+            comment,
+        )
+    }
+
+    /// Submit a pending review
+    pub fn submit_pending_review(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        pr_number: u64,
+        review_id: u64,
+        event: octocrab::models::pulls::ReviewAction,
+        body: Option<String>,
+    ) -> crate::runtime::AsyncTask<Result<octocrab::models::pulls::Review, GitHubError>> {
+        crate::github::pending_review::submit_pending_review(
+            self.inner.clone(),
+            owner,
+            repo,
+            pr_number,
+            review_id,
+            event,
+            body,
+        )
+    }
+
+    /// Delete a pending review
+    pub fn delete_pending_review(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        pr_number: u64,
+        review_id: u64,
+    ) -> crate::runtime::AsyncTask<Result<octocrab::models::pulls::Review, GitHubError>> {
+        crate::github::pending_review::delete_pending_review(
+            self.inner.clone(),
+            owner,
+            repo,
+            pr_number,
+            review_id,
+        )
+    }
+
+    /// Find the authenticated user's pending review
+    pub fn find_pending_review(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        pr_number: u64,
+    ) -> crate::runtime::AsyncTask<Result<Option<octocrab::models::pulls::Review>, GitHubError>>
+    {
+        crate::github::pending_review::find_pending_review(
+            self.inner.clone(),
+            owner,
+            repo,
+            pr_number,
+        )
+    }
+
+    /// Dismiss a submitted review
+    pub fn dismiss_pull_request_review(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        pr_number: u64,
+        review_id: u64,
+        message: impl Into<String>,
+    ) -> crate::runtime::AsyncTask<Result<octocrab::models::pulls::Review, GitHubError>> {
+        crate::github::pending_review::dismiss_pull_request_review(
+            self.inner.clone(),
+            owner,
+            repo,
+            pr_number,
+            review_id,
+            message,
+        )
+    }
+
     /// Update a pull request
     pub fn update_pull_request(
         &self,
@@ -120,6 +401,23 @@ impl GitHubClient {
         )
     }
 
+    /// Update a pull request branch with its base
+    pub fn update_pull_request_branch(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        pr_number: u64,
+        options: crate::github::UpdatePullRequestBranchOptions,
+    ) -> crate::runtime::AsyncTask<Result<crate::github::UpdateBranchOutcome, GitHubError>> {
+        crate::github::update_pull_request_branch::update_pull_request_branch(
+            self.inner.clone(),
+            owner,
+            repo,
+            pr_number,
+            options,
+        )
+    }
+
     /// Merge a pull request
     pub fn merge_pull_request(
         &self,
@@ -137,6 +435,23 @@ impl GitHubClient {
         )
     }
 
+    /// Check whether a pull request is ready to merge
+    pub fn check_merge_readiness(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        pr_number: u64,
+        expected_head_sha: Option<String>,
+    ) -> crate::runtime::AsyncTask<Result<crate::github::MergeReadiness, GitHubError>> {
+        crate::github::check_merge_readiness::check_merge_readiness(
+            self.inner.clone(),
+            owner,
+            repo,
+            pr_number,
+            expected_head_sha,
+        )
+    }
+
     /// List pull requests
     #[must_use]
     pub fn list_pull_requests(
@@ -21,6 +21,18 @@ impl GitHubClient {
         )
     }
 
+    /// Fetch and parse the CODEOWNERS file, returning where it was found
+    pub fn get_codeowners(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        ref_name: Option<String>,
+    ) -> crate::runtime::AsyncTask<
+        Result<Option<(String, crate::github::CodeOwners)>, GitHubError>,
+    > {
+        crate::github::codeowners::get_codeowners(self.inner.clone(), owner, repo, ref_name)
+    }
+
     /// Create or update a file
     #[must_use]
     pub fn create_or_update_file(
@@ -0,0 +1,325 @@
+//! CODEOWNERS parsing and pull request reviewer suggestion.
+
+use crate::github::requested_reviewers::send_reviewer_request;
+use crate::github::{error::GitHubError, util::spawn_task};
+use crate::runtime::AsyncTask;
+use octocrab::{Octocrab, Page, models::repos::DiffEntry};
+use regex::Regex;
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+/// Locations GitHub checks for a CODEOWNERS file, in precedence order.
+pub const CODEOWNERS_PATHS: [&str; 3] = [".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+/// A single CODEOWNERS rule.
+#[derive(Debug, Clone, Serialize)]
+pub struct CodeOwnerRule {
+    /// Pattern as written in the file.
+    pub pattern: String,
+    /// Owners (`@user`, `@org/team` or email). Empty means explicitly unowned.
+    pub owners: Vec<String>,
+    /// 1-based line number in the CODEOWNERS file.
+    pub line: usize,
+    #[serde(skip)]
+    regex: Regex,
+}
+
+impl CodeOwnerRule {
+    /// Whether this rule's pattern matches a repository-relative path.
+    #[must_use]
+    pub fn matches(&self, path: &str) -> bool {
+        self.regex.is_match(path.trim_start_matches('/'))
+    }
+}
+
+/// Parsed CODEOWNERS file.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CodeOwners {
+    /// Rules in file order.
+    pub rules: Vec<CodeOwnerRule>,
+}
+
+impl CodeOwners {
+    /// Parse CODEOWNERS content. Lines with unsupported patterns are skipped,
+    /// as GitHub does.
+    #[must_use]
+    pub fn parse(content: &str) -> Self {
+        let rules = content
+            .lines()
+            .enumerate()
+            .filter_map(|(idx, line)| parse_line(line, idx + 1))
+            .collect();
+        Self { rules }
+    }
+
+    /// The rule that applies to `path`: the last matching one in the file.
+    #[must_use]
+    pub fn rule_for(&self, path: &str) -> Option<&CodeOwnerRule> {
+        self.rules.iter().rev().find(|r| r.matches(path))
+    }
+
+    /// Owners of `path`, empty if no rule matches or the path is explicitly unowned.
+    #[must_use]
+    pub fn owners_for(&self, path: &str) -> &[String] {
+        self.rule_for(path)
+            .map(|r| r.owners.as_slice())
+            .unwrap_or(&[])
+    }
+}
+
+fn parse_line(line: &str, line_number: usize) -> Option<CodeOwnerRule> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+
+    // Split on unescaped whitespace; `\ ` and `\#` are literal in patterns.
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(next) = chars.next() {
+                    current.push('\\');
+                    current.push(next);
+                }
+            }
+            '#' if current.is_empty() => break,
+            c if c.is_whitespace() => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    let mut tokens = tokens.into_iter();
+    let pattern = tokens.next()?;
+    let owners: Vec<String> = tokens.collect();
+
+    // GitHub ignores negation and character ranges.
+    if pattern.starts_with('!') || pattern.contains('[') {
+        return None;
+    }
+
+    let regex = Regex::new(&pattern_to_regex(&pattern)).ok()?;
+    Some(CodeOwnerRule {
+        pattern,
+        owners,
+        line: line_number,
+        regex,
+    })
+}
+
+/// Translate a CODEOWNERS (gitignore-style) pattern into an anchored regex.
+fn pattern_to_regex(pattern: &str) -> String {
+    let dir_only = pattern.ends_with('/');
+    let trimmed = pattern.trim_end_matches('/');
+    // A slash anywhere but the end anchors the pattern to the repository root.
+    let anchored = trimmed.contains('/');
+    let body = trimmed.trim_start_matches('/');
+
+    let mut regex = String::from(if anchored { "^" } else { "^(?:.*/)?" });
+    let mut chars = body.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    regex.push_str("(?:.*/)?");
+                } else {
+                    regex.push_str(".*");
+                }
+            }
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            '\\' => {
+                if let Some(next) = chars.next() {
+                    regex.push_str(&regex::escape(&next.to_string()));
+                }
+            }
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+
+    // A pattern naming a directory owns everything beneath it, but a wildcard
+    // in the last segment only matches direct entries (`docs/*`).
+    let last_segment = body.rsplit('/').next().unwrap_or(body);
+    if dir_only {
+        regex.push_str("/.*");
+    } else if !last_segment.contains(['*', '?']) {
+        regex.push_str("(?:/.*)?");
+    }
+    regex.push('$');
+    regex
+}
+
+/// Owners for one changed file.
+#[derive(Debug, Clone, Serialize)]
+pub struct FileOwners {
+    /// Changed file path.
+    pub path: String,
+    /// Owners required to review this file.
+    pub owners: Vec<String>,
+    /// CODEOWNERS pattern that matched, if any.
+    pub pattern: Option<String>,
+}
+
+/// Reviewer suggestion for a pull request derived from CODEOWNERS.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ReviewerSuggestion {
+    /// CODEOWNERS file used, `None` if the repository has none.
+    pub codeowners_path: Option<String>,
+    /// Required owners per changed file.
+    pub files: Vec<FileOwners>,
+    /// Distinct user logins to request (without `@`), excluding the PR author.
+    pub users: Vec<String>,
+    /// Distinct team slugs to request (without the org prefix).
+    pub teams: Vec<String>,
+    /// Changed files no rule assigns an owner to.
+    pub unowned: Vec<String>,
+    /// Whether the suggested reviewers were requested on the PR.
+    pub requested: bool,
+}
+
+/// Fetch and parse the repository's CODEOWNERS file at `reference`.
+///
+/// Returns the path it was found at alongside the parsed rules.
+pub(crate) fn get_codeowners(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    reference: Option<String>,
+) -> AsyncTask<Result<Option<(String, CodeOwners)>, GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+    spawn_task(async move { fetch_codeowners(&inner, &owner, &repo, reference.as_deref()).await })
+}
+
+pub(crate) async fn fetch_codeowners(
+    inner: &Octocrab,
+    owner: &str,
+    repo: &str,
+    reference: Option<&str>,
+) -> Result<Option<(String, CodeOwners)>, GitHubError> {
+    for path in CODEOWNERS_PATHS {
+        let handler = inner.repos(owner, repo);
+        let mut req = handler.get_content().path(path);
+        if let Some(r) = reference {
+            req = req.r#ref(r);
+        }
+
+        match req.send().await {
+            Ok(contents) => {
+                let content = contents
+                    .items
+                    .into_iter()
+                    .next()
+                    .and_then(|c| c.decoded_content())
+                    .unwrap_or_default();
+                return Ok(Some((path.to_string(), CodeOwners::parse(&content))));
+            }
+            Err(octocrab::Error::GitHub { source, .. }) if source.status_code.as_u16() == 404 => {
+                continue;
+            }
+            Err(e) => return Err(GitHubError::from(e)),
+        }
+    }
+    Ok(None)
+}
+
+/// Map a PR's changed files to CODEOWNERS and optionally request the owners.
+pub(crate) fn suggest_reviewers(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    pr_number: u64,
+    request: bool,
+) -> AsyncTask<Result<ReviewerSuggestion, GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+
+    spawn_task(async move {
+        let pr = inner
+            .pulls(&owner, &repo)
+            .get(pr_number)
+            .await
+            .map_err(GitHubError::from)?;
+
+        // GitHub evaluates CODEOWNERS from the base branch.
+        let Some((codeowners_path, codeowners)) =
+            fetch_codeowners(&inner, &owner, &repo, Some(&pr.base.ref_field)).await?
+        else {
+            return Ok(ReviewerSuggestion::default());
+        };
+
+        let mut files = Vec::new();
+        let mut page: Page<DiffEntry> = inner
+            .pulls(&owner, &repo)
+            .list_files(pr_number)
+            .await
+            .map_err(GitHubError::from)?;
+        files.extend(page.items);
+        while let Some(next) = inner.get_page::<DiffEntry>(&page.next).await? {
+            page = next;
+            files.extend(page.items);
+        }
+
+        let author = pr.user.as_ref().map(|u| u.login.to_lowercase());
+        let mut users = BTreeSet::new();
+        let mut teams = BTreeSet::new();
+        let mut unowned = Vec::new();
+        let mut file_owners = Vec::new();
+
+        for file in files {
+            let rule = codeowners.rule_for(&file.filename);
+            let owners = rule.map(|r| r.owners.clone()).unwrap_or_default();
+
+            if owners.is_empty() {
+                unowned.push(file.filename.clone());
+            }
+            for entry in &owners {
+                let Some(handle) = entry.strip_prefix('@') else {
+                    continue; // Emails cannot be requested directly
+                };
+                match handle.split_once('/') {
+                    Some((_, team)) => {
+                        teams.insert(team.to_string());
+                    }
+                    None if author.as_deref() != Some(&handle.to_lowercase()) => {
+                        users.insert(handle.to_string());
+                    }
+                    None => {}
+                }
+            }
+
+            file_owners.push(FileOwners {
+                path: file.filename,
+                owners,
+                pattern: rule.map(|r| r.pattern.clone()),
+            });
+        }
+
+        let users: Vec<String> = users.into_iter().collect();
+        let teams: Vec<String> = teams.into_iter().collect();
+
+        let requested = request && !(users.is_empty() && teams.is_empty());
+        if requested {
+            send_reviewer_request(&inner, &owner, &repo, pr_number, &users, &teams).await?;
+        }
+
+        Ok(ReviewerSuggestion {
+            codeowners_path: Some(codeowners_path),
+            files: file_owners,
+            users,
+            teams,
+            unowned,
+            requested,
+        })
+    })
+}
@@ -0,0 +1,120 @@
+//! GitHub comment editing and deletion.
+//!
+//! Issue comments (which include PR conversation comments) and PR review
+//! comments live under different endpoints with different IDs.
+
+use crate::github::error::GitHubError;
+use crate::github::util::{delete_no_content, spawn_task};
+use crate::runtime::AsyncTask;
+use octocrab::{Octocrab, models::issues::Comment};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Which kind of comment an ID refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CommentKind {
+    /// Comment on an issue or on a PR's conversation tab.
+    #[default]
+    Issue,
+    /// Inline comment on a PR diff.
+    Review,
+}
+
+impl CommentKind {
+    /// API path segment for comments of this kind.
+    #[must_use]
+    pub fn path(self) -> &'static str {
+        match self {
+            Self::Issue => "issues/comments",
+            Self::Review => "pulls/comments",
+        }
+    }
+}
+
+impl FromStr for CommentKind {
+    type Err = GitHubError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "issue" | "issue_comment" => Ok(Self::Issue),
+            "review" | "review_comment" | "pull_request_review_comment" => Ok(Self::Review),
+            other => Err(GitHubError::InvalidInput(format!(
+                "Unknown comment type '{other}', expected issue or review"
+            ))),
+        }
+    }
+}
+
+/// Replace the body of an issue or PR conversation comment.
+pub(crate) fn update_issue_comment(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    comment_id: u64,
+    body: impl Into<String>,
+) -> AsyncTask<Result<Comment, GitHubError>> {
+    let (owner, repo, body) = (owner.into(), repo.into(), body.into());
+    spawn_task(async move {
+        send_update(&inner, &owner, &repo, CommentKind::Issue, comment_id, &body).await
+    })
+}
+
+/// Replace the body of an inline PR review comment.
+pub(crate) fn update_review_comment(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    comment_id: u64,
+    body: impl Into<String>,
+) -> AsyncTask<Result<octocrab::models::pulls::Comment, GitHubError>> {
+    let (owner, repo, body) = (owner.into(), repo.into(), body.into());
+    spawn_task(async move {
+        send_update(
+            &inner,
+            &owner,
+            &repo,
+            CommentKind::Review,
+            comment_id,
+            &body,
+        )
+        .await
+    })
+}
+
+/// Delete an issue or review comment.
+pub(crate) fn delete_comment(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    kind: CommentKind,
+    comment_id: u64,
+) -> AsyncTask<Result<(), GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+    spawn_task(async move {
+        let path = kind.path();
+        delete_no_content(&inner, format!("/repos/{owner}/{repo}/{path}/{comment_id}")).await
+    })
+}
+
+pub(crate) async fn send_update<T>(
+    inner: &Octocrab,
+    owner: &str,
+    repo: &str,
+    kind: CommentKind,
+    comment_id: u64,
+    body: &str,
+) -> Result<T, GitHubError>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let path = kind.path();
+    inner
+        .patch(
+            format!("/repos/{owner}/{repo}/{path}/{comment_id}"),
+            Some(&serde_json::json!({ "body": body })),
+        )
+        .await
+        .map_err(GitHubError::from)
+}
@@ -1,11 +1,16 @@
 //! GitHub Issue creation operation.
 
+use crate::github::similar_issues::{
+    DEFAULT_DUPLICATE_THRESHOLD, FindSimilarIssuesRequest, SimilarIssue, likely_duplicates,
+    similar_issues,
+};
 use crate::github::{error::GitHubError, util::spawn_task};
 use crate::runtime::AsyncTask;
 use octocrab::{Octocrab, models::issues::Issue};
 use std::sync::Arc;
 
 /// Create a new issue.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn create_issue(
     inner: Arc<Octocrab>,
     owner: impl Into<String>,
@@ -14,23 +19,135 @@ pub(crate) fn create_issue(
     body: Option<String>,
     assignees: Option<Vec<String>>,
     labels: Option<Vec<String>>,
+    milestone: Option<u64>,
 ) -> AsyncTask<Result<Issue, GitHubError>> {
     let owner = owner.into();
     let repo = repo.into();
     let title = title.into();
     spawn_task(async move {
-        let issues_handler = inner.issues(&owner, &repo);
-        let mut req = issues_handler.create(title);
+        send_create_issue(
+            &inner, &owner, &repo, title, body, assignees, labels, milestone,
+        )
+        .await
+    })
+}
 
-        req = req.body(body.unwrap_or_default());
+/// Most likely duplicates reported when the guard stops a creation.
+const MAX_REPORTED_DUPLICATES: usize = 5;
 
-        if let Some(asgs) = assignees {
-            req = req.assignees(asgs);
-        }
-        if let Some(lbs) = labels {
-            req = req.labels(lbs);
+/// Request parameters for creating an issue with an optional duplicate guard
+#[derive(Debug, Clone, Default)]
+pub struct CreateIssueRequest {
+    /// Repository owner (user or organization)
+    pub owner: String,
+    /// Repository name
+    pub repo: String,
+    /// Issue title
+    pub title: String,
+    /// Issue body
+    pub body: Option<String>,
+    /// Assignee logins
+    pub assignees: Option<Vec<String>>,
+    /// Label names
+    pub labels: Option<Vec<String>>,
+    /// Milestone number
+    pub milestone: Option<u64>,
+    /// Search for similar issues first and skip creation when any match
+    pub check_duplicates: bool,
+    /// Score at which an existing issue counts as a duplicate; defaults to
+    /// [`DEFAULT_DUPLICATE_THRESHOLD`]
+    pub duplicate_threshold: Option<f64>,
+}
+
+impl CreateIssueRequest {
+    /// Threshold the guard applies, or `None` when the guard is off.
+    #[must_use]
+    pub fn guard_threshold(&self) -> Option<f64> {
+        self.check_duplicates.then(|| {
+            self.duplicate_threshold
+                .unwrap_or(DEFAULT_DUPLICATE_THRESHOLD)
+        })
+    }
+}
+
+/// Result of a guarded issue creation
+#[derive(Debug, Clone)]
+pub enum CreateIssueOutcome {
+    /// The issue was created
+    Created(Box<Issue>),
+    /// Creation was skipped because these existing issues look like duplicates
+    Duplicates(Vec<SimilarIssue>),
+}
+
+/// Create an issue unless the duplicate guard finds likely duplicates.
+pub(crate) fn create_issue_checked(
+    inner: Arc<Octocrab>,
+    request: CreateIssueRequest,
+) -> AsyncTask<Result<CreateIssueOutcome, GitHubError>> {
+    spawn_task(async move {
+        if let Some(threshold) = request.guard_threshold() {
+            let search = FindSimilarIssuesRequest {
+                owner: request.owner.clone(),
+                repo: request.repo.clone(),
+                title: request.title.clone(),
+                body: request.body.clone(),
+                labels: request.labels.clone().unwrap_or_default(),
+                include_closed: false,
+                limit: MAX_REPORTED_DUPLICATES,
+                min_score: 0.0,
+            };
+            // A title without searchable keywords cannot match anything.
+            let similar = match similar_issues(&inner, &search).await {
+                Ok(similar) => similar,
+                Err(GitHubError::InvalidInput(_)) => Vec::new(),
+                Err(e) => return Err(e),
+            };
+            let duplicates = likely_duplicates(similar, threshold);
+            if !duplicates.is_empty() {
+                return Ok(CreateIssueOutcome::Duplicates(duplicates));
+            }
         }
 
-        req.send().await.map_err(GitHubError::from)
+        let issue = send_create_issue(
+            &inner,
+            &request.owner,
+            &request.repo,
+            request.title,
+            request.body,
+            request.assignees,
+            request.labels,
+            request.milestone,
+        )
+        .await?;
+        Ok(CreateIssueOutcome::Created(Box::new(issue)))
     })
 }
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn send_create_issue(
+    inner: &Octocrab,
+    owner: &str,
+    repo: &str,
+    title: String,
+    body: Option<String>,
+    assignees: Option<Vec<String>>,
+    labels: Option<Vec<String>>,
+    milestone: Option<u64>,
+) -> Result<Issue, GitHubError> {
+    let issues_handler = inner.issues(owner, repo);
+    let mut req = issues_handler.create(title);
+
+    req = req.body(body.unwrap_or_default());
+
+    if let Some(asgs) = assignees {
+        req = req.assignees(asgs);
+    }
+    if let Some(lbs) = labels {
+        req = req.labels(lbs);
+    }
+    if let Some(ms) = milestone {
+        req = req.milestone(ms);
+    }
+
+    req.send().await.map_err(GitHubError::from)
+}
@@ -1,5 +1,7 @@
 //! GitHub Pull Request creation operation.
 
+use crate::github::pull_request_content::build_pull_request_content;
+use crate::github::requested_reviewers::{ensure_collaborators, send_reviewer_request};
 use crate::github::{error::GitHubError, util::spawn_task};
 use crate::runtime::AsyncTask;
 use octocrab::{Octocrab, models::pulls::PullRequest};
@@ -12,7 +14,7 @@ pub struct CreatePullRequestRequest {
     pub owner: String,
     /// Repository name
     pub repo: String,
-    /// Pull request title
+    /// Pull request title (ignored when converting an issue)
     pub title: String,
     /// Pull request body/description
     pub body: Option<String>,
@@ -24,6 +26,18 @@ pub struct CreatePullRequestRequest {
     pub draft: Option<bool>,
     /// Whether maintainers can modify the pull request
     pub maintainer_can_modify: Option<bool>,
+    /// Users to request reviews from once the pull request exists
+    pub reviewers: Option<Vec<String>>,
+    /// Team slugs to request reviews from once the pull request exists
+    pub team_reviewers: Option<Vec<String>>,
+    /// Existing issue to convert into the pull request. The issue's title,
+    /// body and comments carry over and its number is kept.
+    pub issue: Option<u64>,
+    /// Fill the body from the repository's PR template, commit summaries,
+    /// linked issues and changed paths. An empty title is generated too.
+    pub generate_body: Option<bool>,
+    /// Named template from a `PULL_REQUEST_TEMPLATE/` directory
+    pub template: Option<String>,
 }
 
 /// Create a pull-request.
@@ -32,18 +46,89 @@ pub(crate) fn create_pull_request(
     request: CreatePullRequestRequest,
 ) -> AsyncTask<Result<PullRequest, GitHubError>> {
     spawn_task(async move {
-        let handler = inner.pulls(&request.owner, &request.repo);
-        let mut req = handler.create(&request.head, &request.base, &request.title);
+        let mut request = request;
+        if request.generate_body.unwrap_or(false) && request.issue.is_none() {
+            let content = build_pull_request_content(
+                &inner,
+                &request.owner,
+                &request.repo,
+                &request.base,
+                &request.head,
+                request.template.as_deref(),
+            )
+            .await?;
 
-        req = req.body(request.body.unwrap_or_default());
-
-        if let Some(d) = request.draft {
-            req = req.draft(d);
+            if request.title.trim().is_empty() {
+                request.title = content.title;
+            }
+            // Text the caller wrote goes above the generated sections
+            request.body = Some(match request.body.take() {
+                Some(body) if !body.trim().is_empty() => {
+                    format!("{}\n\n{}", body.trim_end(), content.body)
+                }
+                _ => content.body,
+            });
         }
-        if let Some(mcm) = request.maintainer_can_modify {
-            req = req.maintainer_can_modify(mcm);
+
+        let reviewers = request.reviewers.unwrap_or_default();
+        let team_reviewers = request.team_reviewers.unwrap_or_default();
+
+        // Validate before creating so a bad login doesn't leave a half-configured PR behind
+        ensure_collaborators(&inner, &request.owner, &request.repo, &reviewers).await?;
+
+        let pr: PullRequest = if let Some(issue) = request.issue {
+            // The builder always sends a title, which GitHub rejects alongside `issue`
+            let mut body = serde_json::json!({
+                "head": request.head,
+                "base": request.base,
+                "issue": issue,
+            });
+            if let Some(d) = request.draft {
+                body["draft"] = serde_json::json!(d);
+            }
+            if let Some(mcm) = request.maintainer_can_modify {
+                body["maintainer_can_modify"] = serde_json::json!(mcm);
+            }
+
+            let (owner, repo) = (&request.owner, &request.repo);
+            inner
+                .post(format!("/repos/{owner}/{repo}/pulls"), Some(&body))
+                .await
+                .map_err(GitHubError::from)?
+        } else {
+            let handler = inner.pulls(&request.owner, &request.repo);
+            let mut req = handler.create(&request.head, &request.base, &request.title);
+
+            req = req.body(request.body.unwrap_or_default());
+
+            if let Some(d) = request.draft {
+                req = req.draft(d);
+            }
+            if let Some(mcm) = request.maintainer_can_modify {
+                req = req.maintainer_can_modify(mcm);
+            }
+
+            req.send().await.map_err(GitHubError::from)?
+        };
+
+        if reviewers.is_empty() && team_reviewers.is_empty() {
+            return Ok(pr);
         }
 
-        req.send().await.map_err(GitHubError::from)
+        send_reviewer_request(
+            &inner,
+            &request.owner,
+            &request.repo,
+            pr.number,
+            &reviewers,
+            &team_reviewers,
+        )
+        .await
+        .map_err(|e| {
+            GitHubError::Api(format!(
+                "Pull request #{} was created but requesting reviewers failed: {e}",
+                pr.number
+            ))
+        })
     })
 }
@@ -1,5 +1,6 @@
 //! GitHub Pull Request review creation operation.
 
+use crate::github::suggested_change::{SuggestedChange, validate_suggestions};
 use crate::github::{error::GitHubError, util::spawn_task};
 use crate::runtime::AsyncTask;
 use octocrab::{
@@ -19,6 +20,9 @@ pub struct CreatePullRequestReviewOptions {
     pub commit_id: Option<String>,
     /// Optional inline review comments.
     pub comments: Option<Vec<ReviewComment>>,
+    /// Suggested changes posted as inline comments in the same review.
+    /// Each is validated against the PR diff before anything is submitted.
+    pub suggestions: Vec<SuggestedChange>,
 }
 
 impl CreatePullRequestReviewOptions {
@@ -30,6 +34,7 @@ impl CreatePullRequestReviewOptions {
             body: None,
             commit_id: None,
             comments: None,
+            suggestions: Vec::new(),
         }
     }
 }
@@ -45,6 +50,8 @@ pub(crate) fn create_pull_request_review(
     let (owner, repo) = (owner.into(), repo.into());
 
     spawn_task(async move {
+        validate_suggestions(&inner, &owner, &repo, pr_number, &options.suggestions).await?;
+
         let mut review_data = serde_json::json!({
             "event": options.event,
         });
@@ -55,8 +62,20 @@ pub(crate) fn create_pull_request_review(
         if let Some(cid) = options.commit_id {
             review_data["commit_id"] = serde_json::json!(cid);
         }
-        if let Some(cmnts) = options.comments {
-            review_data["comments"] = serde_json::json!(cmnts);
+        let mut comments: Vec<serde_json::Value> = options
+            .comments
+            .iter()
+            .flatten()
+            .map(|c| serde_json::json!(c))
+            .collect();
+        comments.extend(
+            options
+                .suggestions
+                .iter()
+                .map(SuggestedChange::to_review_comment),
+        );
+        if !comments.is_empty() {
+            review_data["comments"] = serde_json::json!(comments);
         }
 
         inner
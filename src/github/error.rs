@@ -1,5 +1,6 @@
 //! GitHub API error types
 
+use crate::github::check_merge_readiness::MergeBlocker;
 use thiserror::Error;
 
 /// Error types for GitHub API operations
@@ -29,6 +30,10 @@ pub enum GitHubError {
     #[error("Rate limit exceeded")]
     RateLimitExceeded,
 
+    /// Pre-merge safety checks found reasons not to merge
+    #[error("Merge blocked: {}", join_blockers(.0))]
+    MergeBlocked(Vec<MergeBlocker>),
+
     /// Client setup/configuration error
     #[error("Client setup failed: {0}")]
     ClientSetup(String),
@@ -45,6 +50,14 @@ pub enum GitHubError {
 /// Convenience result alias for GitHub operations
 pub type GitHubResult<T> = Result<T, GitHubError>;
 
+fn join_blockers(blockers: &[MergeBlocker]) -> String {
+    blockers
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
 // Convenience conversions
 impl From<String> for GitHubError {
     fn from(s: String) -> Self {
@@ -18,28 +18,38 @@ pub(crate) fn get_commit(
     let repo = repo.into();
     let sha = sha.into();
 
-    spawn_task(async move {
-        // Note: octocrab's get_commit returns detailed commit info
-        // Page/per_page parameters are for the files list in the commit
-        let mut url = format!("/repos/{owner}/{repo}/commits/{sha}");
+    spawn_task(async move { fetch_commit(&inner, &owner, &repo, &sha, page, per_page).await })
+}
+
+/// Fetch a commit with its stats and changed files. Shared with PR commit listing.
+pub(crate) async fn fetch_commit(
+    inner: &Octocrab,
+    owner: &str,
+    repo: &str,
+    sha: &str,
+    page: Option<u32>,
+    per_page: Option<u8>,
+) -> Result<RepoCommit, GitHubError> {
+    // Note: octocrab's get_commit returns detailed commit info
+    // Page/per_page parameters are for the files list in the commit
+    let mut url = format!("/repos/{owner}/{repo}/commits/{sha}");
 
-        // Add pagination parameters if provided
-        let mut params = vec![];
-        if let Some(p) = page {
-            params.push(format!("page={p}"));
-        }
-        if let Some(pp) = per_page {
-            params.push(format!("per_page={pp}"));
-        }
-        if !params.is_empty() {
-            url.push_str(&format!("?{}", params.join("&")));
-        }
+    // Add pagination parameters if provided
+    let mut params = vec![];
+    if let Some(p) = page {
+        params.push(format!("page={p}"));
+    }
+    if let Some(pp) = per_page {
+        params.push(format!("per_page={pp}"));
+    }
+    if !params.is_empty() {
+        url.push_str(&format!("?{}", params.join("&")));
+    }
 
-        let commit: RepoCommit = inner
-            .get(url, None::<&()>)
-            .await
-            .map_err(GitHubError::from)?;
+    let commit: RepoCommit = inner
+        .get(url, None::<&()>)
+        .await
+        .map_err(GitHubError::from)?;
 
-        Ok(commit)
-    })
+    Ok(commit)
 }
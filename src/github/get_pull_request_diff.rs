@@ -0,0 +1,350 @@
+//! GitHub Pull Request diff retrieval and unified diff parsing.
+
+use crate::github::{error::GitHubError, util::spawn_task};
+use crate::runtime::AsyncTask;
+use octocrab::Octocrab;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Side of a diff, as used by review comment `side` / `start_side`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DiffSide {
+    /// The base (old) version.
+    #[serde(rename = "LEFT")]
+    Left,
+    /// The head (new) version.
+    #[serde(rename = "RIGHT")]
+    Right,
+}
+
+impl DiffSide {
+    /// Returns the GitHub API string representation of this side.
+    #[inline]
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Left => "LEFT",
+            Self::Right => "RIGHT",
+        }
+    }
+}
+
+/// Kind of a line within a hunk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffLineKind {
+    /// Unchanged line present on both sides.
+    Context,
+    /// Line added on the right side.
+    Added,
+    /// Line removed from the left side.
+    Removed,
+}
+
+/// A single line in a hunk with its line numbers on each side.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DiffLine {
+    /// Whether the line was added, removed or is context.
+    pub kind: DiffLineKind,
+    /// Line number in the base file (`LEFT`), absent for added lines.
+    pub old_line: Option<u32>,
+    /// Line number in the head file (`RIGHT`), absent for removed lines.
+    pub new_line: Option<u32>,
+    /// Line content without the leading `+`/`-`/space marker.
+    pub content: String,
+}
+
+impl DiffLine {
+    /// Line number on `side`, if the line exists there.
+    #[must_use]
+    pub fn line_on(&self, side: DiffSide) -> Option<u32> {
+        match side {
+            DiffSide::Left => self.old_line,
+            DiffSide::Right => self.new_line,
+        }
+    }
+}
+
+/// A hunk of a file diff.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DiffHunk {
+    /// The full `@@ -a,b +c,d @@ ...` header.
+    pub header: String,
+    /// First line of the hunk in the base file.
+    pub old_start: u32,
+    /// Number of base lines covered.
+    pub old_lines: u32,
+    /// First line of the hunk in the head file.
+    pub new_start: u32,
+    /// Number of head lines covered.
+    pub new_lines: u32,
+    /// Lines in the hunk.
+    pub lines: Vec<DiffLine>,
+}
+
+impl DiffHunk {
+    /// Whether `line` on `side` is part of this hunk (and so can carry a review comment).
+    #[must_use]
+    pub fn contains(&self, side: DiffSide, line: u32) -> bool {
+        self.lines.iter().any(|l| l.line_on(side) == Some(line))
+    }
+}
+
+/// How a file changed in the diff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileDiffStatus {
+    Added,
+    Removed,
+    Modified,
+    Renamed,
+}
+
+/// Diff of a single file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FileDiff {
+    /// Path in the head revision (or base path for removed files).
+    pub path: String,
+    /// Previous path for renames.
+    pub old_path: Option<String>,
+    /// Kind of change.
+    pub status: FileDiffStatus,
+    /// Whether git reported the file as binary (no hunks).
+    pub binary: bool,
+    /// Hunks in file order.
+    pub hunks: Vec<DiffHunk>,
+}
+
+impl FileDiff {
+    /// The hunk containing `line` on `side`.
+    #[must_use]
+    pub fn hunk_for(&self, side: DiffSide, line: u32) -> Option<&DiffHunk> {
+        self.hunks.iter().find(|h| h.contains(side, line))
+    }
+
+    /// Whether `start..=end` on `side` lies within a single hunk, which is
+    /// what GitHub requires for a multi-line review comment.
+    #[must_use]
+    pub fn range_in_one_hunk(&self, side: DiffSide, start: u32, end: u32) -> bool {
+        start <= end
+            && self
+                .hunk_for(side, start)
+                .is_some_and(|h| h.contains(side, end))
+    }
+}
+
+/// Parsed pull request diff.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct PullRequestDiff {
+    /// Changed files in diff order.
+    pub files: Vec<FileDiff>,
+}
+
+impl PullRequestDiff {
+    /// Parse a git unified diff (`application/vnd.github.diff`).
+    #[must_use]
+    pub fn parse(diff: &str) -> Self {
+        let mut files: Vec<FileDiff> = Vec::new();
+        let mut lines = diff.lines().peekable();
+
+        while let Some(line) = lines.next() {
+            if let Some(rest) = line.strip_prefix("diff --git ") {
+                // Fallback paths from the header; `---`/`+++` and rename lines refine them.
+                let (a, b) = split_git_header(rest);
+                files.push(FileDiff {
+                    path: b,
+                    old_path: Some(a),
+                    status: FileDiffStatus::Modified,
+                    binary: false,
+                    hunks: Vec::new(),
+                });
+                continue;
+            }
+
+            let Some(file) = files.last_mut() else {
+                continue;
+            };
+
+            if line.starts_with("new file mode") {
+                file.status = FileDiffStatus::Added;
+            } else if line.starts_with("deleted file mode") {
+                file.status = FileDiffStatus::Removed;
+            } else if let Some(from) = line.strip_prefix("rename from ") {
+                file.status = FileDiffStatus::Renamed;
+                file.old_path = Some(from.to_string());
+            } else if let Some(to) = line.strip_prefix("rename to ") {
+                file.path = to.to_string();
+            } else if line.starts_with("Binary files ") || line == "GIT binary patch" {
+                file.binary = true;
+            } else if let Some(old) = line.strip_prefix("--- ") {
+                if old != "/dev/null" {
+                    file.old_path = Some(strip_prefix_dir(old, "a/"));
+                }
+            } else if let Some(new) = line.strip_prefix("+++ ") {
+                if new == "/dev/null" {
+                    if let Some(old) = &file.old_path {
+                        file.path = old.clone();
+                    }
+                } else {
+                    file.path = strip_prefix_dir(new, "b/");
+                }
+            } else if line.starts_with("@@ ")
+                && let Some(mut hunk) = parse_hunk_header(line)
+            {
+                let (mut old_left, mut new_left) = (hunk.old_lines, hunk.new_lines);
+                let (mut old_no, mut new_no) = (hunk.old_start, hunk.new_start);
+
+                while old_left > 0 || new_left > 0 {
+                    let Some(&body) = lines.peek() else {
+                        break;
+                    };
+                    let (kind, content) = match body.chars().next() {
+                        Some('+') => (DiffLineKind::Added, &body[1..]),
+                        Some('-') => (DiffLineKind::Removed, &body[1..]),
+                        Some(' ') => (DiffLineKind::Context, &body[1..]),
+                        Some('\\') => {
+                            lines.next(); // "\ No newline at end of file"
+                            continue;
+                        }
+                        // GitHub sometimes strips the space from empty context lines.
+                        None => (DiffLineKind::Context, ""),
+                        Some(_) => break,
+                    };
+                    lines.next();
+
+                    let (old_line, new_line) = match kind {
+                        DiffLineKind::Added => (None, Some(new_no)),
+                        DiffLineKind::Removed => (Some(old_no), None),
+                        DiffLineKind::Context => (Some(old_no), Some(new_no)),
+                    };
+                    if old_line.is_some() {
+                        old_no += 1;
+                        old_left = old_left.saturating_sub(1);
+                    }
+                    if new_line.is_some() {
+                        new_no += 1;
+                        new_left = new_left.saturating_sub(1);
+                    }
+
+                    hunk.lines.push(DiffLine {
+                        kind,
+                        old_line,
+                        new_line,
+                        content: content.to_string(),
+                    });
+                }
+
+                // Consume a trailing no-newline marker after the last line.
+                if lines.peek().is_some_and(|l| l.starts_with('\\')) {
+                    lines.next();
+                }
+                file.hunks.push(hunk);
+            }
+        }
+
+        // Only renames keep a distinct previous path.
+        for file in &mut files {
+            if file.status != FileDiffStatus::Renamed {
+                file.old_path = None;
+            }
+        }
+
+        Self { files }
+    }
+
+    /// Diff for the file at `path` (head-side path).
+    #[must_use]
+    pub fn file(&self, path: &str) -> Option<&FileDiff> {
+        self.files.iter().find(|f| f.path == path)
+    }
+}
+
+fn strip_prefix_dir(path: &str, prefix: &str) -> String {
+    // Tab-terminated timestamps may follow the path in non-git diffs.
+    let path = path.split('\t').next().unwrap_or(path);
+    path.strip_prefix(prefix).unwrap_or(path).to_string()
+}
+
+fn split_git_header(rest: &str) -> (String, String) {
+    // "a/<old> b/<new>": paths are equal length for non-renames, so split in the middle first.
+    let half = rest.len() / 2;
+    if rest.is_char_boundary(half) && rest.as_bytes().get(half) == Some(&b' ') {
+        let (a, b) = (&rest[..half], &rest[half + 1..]);
+        if a.strip_prefix("a/") == b.strip_prefix("b/") {
+            return (strip_prefix_dir(a, "a/"), strip_prefix_dir(b, "b/"));
+        }
+    }
+    match rest.find(" b/") {
+        Some(idx) => (
+            strip_prefix_dir(&rest[..idx], "a/"),
+            strip_prefix_dir(&rest[idx + 1..], "b/"),
+        ),
+        None => (rest.to_string(), rest.to_string()),
+    }
+}
+
+fn parse_hunk_header(line: &str) -> Option<DiffHunk> {
+    let inner = line.strip_prefix("@@ ")?;
+    let end = inner.find(" @@")?;
+    let mut ranges = inner[..end].split(' ');
+    let (old_start, old_lines) = parse_range(ranges.next()?.strip_prefix('-')?)?;
+    let (new_start, new_lines) = parse_range(ranges.next()?.strip_prefix('+')?)?;
+
+    Some(DiffHunk {
+        header: line.to_string(),
+        old_start,
+        old_lines,
+        new_start,
+        new_lines,
+        lines: Vec::new(),
+    })
+}
+
+fn parse_range(range: &str) -> Option<(u32, u32)> {
+    match range.split_once(',') {
+        Some((start, count)) => Some((start.parse().ok()?, count.parse().ok()?)),
+        None => Some((range.parse().ok()?, 1)),
+    }
+}
+
+/// Fetch a pull request's unified diff and parse it into files and hunks.
+pub(crate) fn get_pull_request_diff(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    pr_number: u64,
+) -> AsyncTask<Result<PullRequestDiff, GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+    spawn_task(async move { fetch_pull_request_diff(&inner, &owner, &repo, pr_number).await })
+}
+
+pub(crate) async fn fetch_pull_request_diff(
+    inner: &Octocrab,
+    owner: &str,
+    repo: &str,
+    pr_number: u64,
+) -> Result<PullRequestDiff, GitHubError> {
+    let raw = inner
+        .pulls(owner, repo)
+        .get_diff(pr_number)
+        .await
+        .map_err(GitHubError::from)?;
+    Ok(PullRequestDiff::parse(&raw))
+}
+
+/// Fetch a pull request as a `git format-patch` series.
+pub(crate) fn get_pull_request_patch(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    pr_number: u64,
+) -> AsyncTask<Result<String, GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+    spawn_task(async move {
+        inner
+            .pulls(&owner, &repo)
+            .get_patch(pr_number)
+            .await
+            .map_err(GitHubError::from)
+    })
+}
@@ -0,0 +1,633 @@
+//! Export a repository's issues and PRs, with comments and optionally their
+//! timelines, to JSONL, CSV or a Markdown digest on disk.
+//!
+//! Every export writes a checkpoint next to the output file recording the
+//! newest `updated_at` seen. An incremental export starts from that point and
+//! merges into the existing file: JSONL and CSV records are replaced by issue
+//! number, while the Markdown digest gets a new section appended.
+
+use crate::github::error::GitHubError;
+use crate::github::get_issue_comments::get_issue_comments;
+use crate::github::issue_timeline::{TimelineEvent, get_issue_timeline};
+use crate::github::list_issues::{ListIssuesRequest, list_issues};
+use crate::github::util::spawn_task;
+use crate::runtime::AsyncTask;
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use octocrab::{
+    Octocrab,
+    models::IssueState,
+    models::issues::{Comment, Issue},
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Columns of a CSV export, in order.
+pub const CSV_COLUMNS: &[&str] = &[
+    "number",
+    "kind",
+    "title",
+    "state",
+    "author",
+    "labels",
+    "assignees",
+    "milestone",
+    "comments",
+    "created_at",
+    "updated_at",
+    "closed_at",
+    "html_url",
+    "body",
+];
+
+/// Output format of an export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    /// One JSON object per line, with comments and timeline nested.
+    Jsonl,
+    /// One row per issue; comments are counted, not included.
+    Csv,
+    /// Human readable digest.
+    Markdown,
+}
+
+impl ExportFormat {
+    /// Guess the format from a file name's extension.
+    #[must_use]
+    pub fn from_path(path: &str) -> Option<Self> {
+        let ext = path.rsplit_once('.')?.1.to_lowercase();
+        match ext.as_str() {
+            "jsonl" | "ndjson" => Some(Self::Jsonl),
+            "csv" => Some(Self::Csv),
+            "md" | "markdown" => Some(Self::Markdown),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = GitHubError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "jsonl" | "ndjson" => Ok(Self::Jsonl),
+            "csv" => Ok(Self::Csv),
+            "md" | "markdown" => Ok(Self::Markdown),
+            _ => Err(GitHubError::InvalidInput(format!(
+                "Unknown export format '{s}', expected jsonl, csv or markdown"
+            ))),
+        }
+    }
+}
+
+/// A comment as exported.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ExportedComment {
+    /// Comment ID.
+    pub id: u64,
+    /// Author login.
+    pub author: String,
+    /// When it was posted.
+    pub created_at: DateTime<Utc>,
+    /// When it was last edited.
+    pub updated_at: Option<DateTime<Utc>>,
+    /// Markdown body.
+    pub body: String,
+}
+
+impl From<&Comment> for ExportedComment {
+    fn from(comment: &Comment) -> Self {
+        Self {
+            id: comment.id.into_inner(),
+            author: comment.user.login.clone(),
+            created_at: comment.created_at,
+            updated_at: comment.updated_at,
+            body: comment.body.clone().unwrap_or_default(),
+        }
+    }
+}
+
+/// An issue or PR as exported.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ExportedIssue {
+    /// Issue or PR number.
+    pub number: u64,
+    /// Whether this is a pull request.
+    pub is_pull_request: bool,
+    /// Title.
+    pub title: String,
+    /// `open` or `closed`.
+    pub state: String,
+    /// Author login.
+    pub author: String,
+    /// Label names.
+    pub labels: Vec<String>,
+    /// Assignee logins.
+    pub assignees: Vec<String>,
+    /// Milestone title.
+    pub milestone: Option<String>,
+    /// When it was opened.
+    pub created_at: DateTime<Utc>,
+    /// When it last changed.
+    pub updated_at: DateTime<Utc>,
+    /// When it was closed.
+    pub closed_at: Option<DateTime<Utc>>,
+    /// Web URL.
+    pub html_url: String,
+    /// Markdown body.
+    pub body: String,
+    /// Number of comments on GitHub, whether or not they were fetched.
+    pub comment_count: u32,
+    /// Comments, oldest first (empty unless requested).
+    pub comments: Vec<ExportedComment>,
+    /// Timeline events, oldest first (empty unless requested).
+    pub timeline: Vec<TimelineEvent>,
+}
+
+impl From<&Issue> for ExportedIssue {
+    fn from(issue: &Issue) -> Self {
+        Self {
+            number: issue.number,
+            is_pull_request: issue.pull_request.is_some(),
+            title: issue.title.clone(),
+            state: match issue.state {
+                IssueState::Closed => "closed".to_string(),
+                _ => "open".to_string(),
+            },
+            author: issue.user.login.clone(),
+            labels: issue.labels.iter().map(|l| l.name.clone()).collect(),
+            assignees: issue.assignees.iter().map(|a| a.login.clone()).collect(),
+            milestone: issue.milestone.as_ref().map(|m| m.title.clone()),
+            created_at: issue.created_at,
+            updated_at: issue.updated_at,
+            closed_at: issue.closed_at,
+            html_url: issue.html_url.to_string(),
+            body: issue.body.clone().unwrap_or_default(),
+            comment_count: issue.comments,
+            comments: Vec::new(),
+            timeline: Vec::new(),
+        }
+    }
+}
+
+/// Progress marker stored next to an export.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportCheckpoint {
+    /// Repository owner.
+    pub owner: String,
+    /// Repository name.
+    pub repo: String,
+    /// Format of the export file.
+    pub format: ExportFormat,
+    /// Newest `updated_at` exported so far; the next incremental export
+    /// starts here.
+    pub since: Option<DateTime<Utc>>,
+    /// When the export last ran.
+    pub exported_at: DateTime<Utc>,
+}
+
+/// Path of the checkpoint kept for an export file.
+#[must_use]
+pub fn checkpoint_path(path: &str) -> String {
+    format!("{path}.checkpoint.json")
+}
+
+/// Render issues as JSON Lines.
+pub fn render_jsonl(issues: &[ExportedIssue]) -> Result<String, GitHubError> {
+    let mut out = String::new();
+    for issue in issues {
+        out.push_str(&to_json_line(issue)?);
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+fn to_json_line(issue: &ExportedIssue) -> Result<String, GitHubError> {
+    serde_json::to_string(issue).map_err(|e| {
+        GitHubError::Other(format!("Failed to serialize issue #{}: {e}", issue.number))
+    })
+}
+
+/// Merge issues into an existing JSONL export, replacing records with the
+/// same number and keeping the result ordered by number.
+pub fn merge_jsonl(existing: &str, issues: &[ExportedIssue]) -> Result<String, GitHubError> {
+    let mut records: BTreeMap<u64, String> = BTreeMap::new();
+    for (index, line) in existing.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let value: serde_json::Value = serde_json::from_str(line).map_err(|e| {
+            GitHubError::InvalidInput(format!("Existing export line {}: {e}", index + 1))
+        })?;
+        let number = value["number"].as_u64().ok_or_else(|| {
+            GitHubError::InvalidInput(format!(
+                "Existing export line {} has no issue number",
+                index + 1
+            ))
+        })?;
+        records.insert(number, line.to_string());
+    }
+    for issue in issues {
+        records.insert(issue.number, to_json_line(issue)?);
+    }
+
+    let mut out = String::new();
+    for line in records.values() {
+        out.push_str(line);
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+fn csv_row(issue: &ExportedIssue) -> Vec<String> {
+    let date = |d: &DateTime<Utc>| d.to_rfc3339();
+    vec![
+        issue.number.to_string(),
+        if issue.is_pull_request {
+            "pull_request"
+        } else {
+            "issue"
+        }
+        .to_string(),
+        issue.title.clone(),
+        issue.state.clone(),
+        issue.author.clone(),
+        issue.labels.join("; "),
+        issue.assignees.join("; "),
+        issue.milestone.clone().unwrap_or_default(),
+        issue.comment_count.to_string(),
+        date(&issue.created_at),
+        date(&issue.updated_at),
+        issue.closed_at.as_ref().map(date).unwrap_or_default(),
+        issue.html_url.clone(),
+        issue.body.clone(),
+    ]
+}
+
+/// Render issues as CSV with a header row (see [`CSV_COLUMNS`]).
+pub fn render_csv(issues: &[ExportedIssue]) -> Result<String, GitHubError> {
+    merge_csv("", issues)
+}
+
+/// Merge issues into an existing CSV export, replacing rows with the same
+/// number and keeping the result ordered by number.
+pub fn merge_csv(existing: &str, issues: &[ExportedIssue]) -> Result<String, GitHubError> {
+    let mut rows: BTreeMap<u64, Vec<String>> = BTreeMap::new();
+
+    if !existing.trim().is_empty() {
+        let mut reader = csv::Reader::from_reader(existing.as_bytes());
+        let headers = reader
+            .headers()
+            .map_err(|e| GitHubError::InvalidInput(format!("Existing CSV export: {e}")))?;
+        if headers.iter().ne(CSV_COLUMNS.iter().copied()) {
+            return Err(GitHubError::InvalidInput(
+                "Existing CSV export has different columns".to_string(),
+            ));
+        }
+        for record in reader.records() {
+            let record = record
+                .map_err(|e| GitHubError::InvalidInput(format!("Existing CSV export: {e}")))?;
+            let number = record.get(0).and_then(|n| n.parse().ok()).ok_or_else(|| {
+                GitHubError::InvalidInput(format!(
+                    "Existing CSV export row without issue number: {record:?}"
+                ))
+            })?;
+            rows.insert(number, record.iter().map(str::to_string).collect());
+        }
+    }
+    for issue in issues {
+        rows.insert(issue.number, csv_row(issue));
+    }
+
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    let csv_error = |e: csv::Error| GitHubError::Other(format!("Failed to write CSV: {e}"));
+    writer.write_record(CSV_COLUMNS).map_err(csv_error)?;
+    for row in rows.values() {
+        writer.write_record(row).map_err(csv_error)?;
+    }
+    let bytes = writer
+        .into_inner()
+        .map_err(|e| GitHubError::Other(format!("Failed to write CSV: {e}")))?;
+    String::from_utf8(bytes).map_err(|e| GitHubError::Other(format!("Invalid UTF-8 in CSV: {e}")))
+}
+
+/// Render a Markdown digest section for `owner/repo`.
+///
+/// With `since`, the heading says the section covers changes after it, which
+/// is how incremental exports read when appended to an earlier digest.
+#[must_use]
+pub fn render_markdown_digest(
+    owner: &str,
+    repo: &str,
+    issues: &[ExportedIssue],
+    since: Option<DateTime<Utc>>,
+    exported_at: DateTime<Utc>,
+) -> String {
+    let date = |d: &DateTime<Utc>| d.format("%Y-%m-%d %H:%M UTC").to_string();
+    let mut out = String::new();
+
+    match since {
+        Some(since) => {
+            let _ = writeln!(out, "# {owner}/{repo}: updates since {}", date(&since));
+        }
+        None => {
+            let _ = writeln!(out, "# {owner}/{repo}: issues and pull requests");
+        }
+    }
+    let open = issues.iter().filter(|i| i.state == "open").count();
+    let _ = writeln!(
+        out,
+        "\n_Exported {}: {} items ({open} open, {} closed)_",
+        date(&exported_at),
+        issues.len(),
+        issues.len() - open
+    );
+
+    for issue in issues {
+        let kind = if issue.is_pull_request { "PR" } else { "Issue" };
+        let _ = writeln!(
+            out,
+            "\n## {kind} #{}: {} ({})\n",
+            issue.number, issue.title, issue.state
+        );
+
+        let mut meta = vec![
+            format!("**Author:** @{}", issue.author),
+            format!("**Opened:** {}", date(&issue.created_at)),
+            format!("**Updated:** {}", date(&issue.updated_at)),
+        ];
+        if let Some(closed_at) = &issue.closed_at {
+            meta.push(format!("**Closed:** {}", date(closed_at)));
+        }
+        if !issue.labels.is_empty() {
+            meta.push(format!("**Labels:** {}", issue.labels.join(", ")));
+        }
+        if !issue.assignees.is_empty() {
+            let assignees: Vec<String> = issue.assignees.iter().map(|a| format!("@{a}")).collect();
+            meta.push(format!("**Assignees:** {}", assignees.join(", ")));
+        }
+        if let Some(milestone) = &issue.milestone {
+            meta.push(format!("**Milestone:** {milestone}"));
+        }
+        let _ = writeln!(out, "{}  \n{}", meta.join(" · "), issue.html_url);
+
+        if !issue.body.trim().is_empty() {
+            let _ = writeln!(out, "\n{}", issue.body.trim());
+        }
+
+        if !issue.comments.is_empty() {
+            let _ = writeln!(out, "\n### Comments ({})", issue.comments.len());
+            for comment in &issue.comments {
+                let _ = writeln!(
+                    out,
+                    "\n**@{}** ({}):\n",
+                    comment.author,
+                    date(&comment.created_at)
+                );
+                for line in comment.body.trim().lines() {
+                    let _ = writeln!(out, "> {line}");
+                }
+            }
+        }
+
+        if !issue.timeline.is_empty() {
+            let _ = writeln!(out, "\n### Timeline\n");
+            for event in &issue.timeline {
+                let when = event.created_at.as_ref().map(date).unwrap_or_default();
+                let who = event
+                    .actor
+                    .as_ref()
+                    .map(|a| format!("@{a} "))
+                    .unwrap_or_default();
+                let _ = writeln!(out, "- {when} {who}{}", event.summary());
+            }
+        }
+    }
+    out
+}
+
+/// Request parameters for exporting issues
+#[derive(Debug, Clone)]
+pub struct ExportIssuesRequest {
+    /// Repository owner (user or organization)
+    pub owner: String,
+    /// Repository name
+    pub repo: String,
+    /// Local file to write
+    pub path: String,
+    /// Output format
+    pub format: ExportFormat,
+    /// Only open or closed items (default: both)
+    pub state: Option<IssueState>,
+    /// Only items with all of these labels
+    pub labels: Option<Vec<String>>,
+    /// Include pull requests as well as issues
+    pub include_pull_requests: bool,
+    /// Fetch each item's comments
+    pub include_comments: bool,
+    /// Fetch each item's timeline (one extra request per item)
+    pub include_timeline: bool,
+    /// Only items updated at or after this time; overrides the checkpoint
+    pub since: Option<DateTime<Utc>>,
+    /// Start from the checkpoint and merge into the existing file
+    pub incremental: bool,
+}
+
+/// Outcome of an export.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportSummary {
+    /// File written
+    pub path: String,
+    /// Checkpoint file written
+    pub checkpoint_path: String,
+    /// Output format
+    pub format: ExportFormat,
+    /// Items fetched in this run
+    pub exported: usize,
+    /// Lower bound on `updated_at` used for this run
+    pub since: Option<DateTime<Utc>>,
+    /// Where the next incremental export will start
+    pub next_since: Option<DateTime<Utc>>,
+    /// Whether the run merged into an existing file
+    pub merged: bool,
+}
+
+/// Export issues (and PRs) with their comments and timelines to a local file.
+pub(crate) fn export_issues(
+    inner: Arc<Octocrab>,
+    request: ExportIssuesRequest,
+) -> AsyncTask<Result<ExportSummary, GitHubError>> {
+    spawn_task(async move {
+        let checkpoint_file = checkpoint_path(&request.path);
+        let checkpoint = if request.incremental {
+            read_checkpoint(&checkpoint_file).await?
+        } else {
+            None
+        };
+        if let Some(cp) = &checkpoint
+            && (!cp.owner.eq_ignore_ascii_case(&request.owner)
+                || !cp.repo.eq_ignore_ascii_case(&request.repo)
+                || cp.format != request.format)
+        {
+            return Err(GitHubError::InvalidInput(format!(
+                "{checkpoint_file} belongs to a {:?} export of {}/{}",
+                cp.format, cp.owner, cp.repo
+            )));
+        }
+        let since = request
+            .since
+            .or_else(|| checkpoint.as_ref().and_then(|cp| cp.since));
+
+        let mut issues = fetch_issues(&inner, &request, since).await?;
+        for issue in &mut issues {
+            if request.include_comments {
+                let mut stream = get_issue_comments(
+                    inner.clone(),
+                    request.owner.clone(),
+                    request.repo.clone(),
+                    issue.number,
+                );
+                while let Some(comment) = stream.next().await {
+                    issue.comments.push(ExportedComment::from(&comment?));
+                }
+            }
+            if request.include_timeline {
+                let mut stream = get_issue_timeline(
+                    inner.clone(),
+                    request.owner.clone(),
+                    request.repo.clone(),
+                    issue.number,
+                );
+                while let Some(event) = stream.next().await {
+                    issue.timeline.push(event?);
+                }
+            }
+        }
+
+        let existing = if request.incremental {
+            match tokio::fs::read_to_string(&request.path).await {
+                Ok(content) => Some(content),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+                Err(e) => {
+                    return Err(GitHubError::Other(format!(
+                        "Failed to read {}: {e}",
+                        request.path
+                    )));
+                }
+            }
+        } else {
+            None
+        };
+        let merged = existing.is_some();
+
+        let exported_at = Utc::now();
+        let content = match (request.format, existing) {
+            (ExportFormat::Jsonl, Some(existing)) => merge_jsonl(&existing, &issues)?,
+            (ExportFormat::Jsonl, None) => render_jsonl(&issues)?,
+            (ExportFormat::Csv, Some(existing)) => merge_csv(&existing, &issues)?,
+            (ExportFormat::Csv, None) => render_csv(&issues)?,
+            (ExportFormat::Markdown, Some(existing)) => {
+                let section = render_markdown_digest(
+                    &request.owner,
+                    &request.repo,
+                    &issues,
+                    since,
+                    exported_at,
+                );
+                format!("{}\n\n---\n\n{section}", existing.trim_end())
+            }
+            (ExportFormat::Markdown, None) => {
+                render_markdown_digest(&request.owner, &request.repo, &issues, since, exported_at)
+            }
+        };
+        write_file(&request.path, content.as_bytes()).await?;
+
+        let next_since = issues.iter().map(|i| i.updated_at).max().max(since);
+        let checkpoint = ExportCheckpoint {
+            owner: request.owner.clone(),
+            repo: request.repo.clone(),
+            format: request.format,
+            since: next_since,
+            exported_at,
+        };
+        let checkpoint_json = serde_json::to_vec_pretty(&checkpoint)
+            .map_err(|e| GitHubError::Other(format!("Failed to serialize checkpoint: {e}")))?;
+        write_file(&checkpoint_file, &checkpoint_json).await?;
+
+        Ok(ExportSummary {
+            path: request.path,
+            checkpoint_path: checkpoint_file,
+            format: request.format,
+            exported: issues.len(),
+            since,
+            next_since,
+            merged,
+        })
+    })
+}
+
+/// Items matching the request, ordered by number. Without a state filter
+/// both open and closed items are listed, since the list request only takes
+/// one state at a time.
+async fn fetch_issues(
+    inner: &Arc<Octocrab>,
+    request: &ExportIssuesRequest,
+    since: Option<DateTime<Utc>>,
+) -> Result<Vec<ExportedIssue>, GitHubError> {
+    let states = match request.state.clone() {
+        Some(state) => vec![state],
+        None => vec![IssueState::Open, IssueState::Closed],
+    };
+
+    let mut found = BTreeMap::new();
+    for state in states {
+        let mut stream = list_issues(
+            inner.clone(),
+            ListIssuesRequest {
+                owner: request.owner.clone(),
+                repo: request.repo.clone(),
+                state: Some(state),
+                labels: request.labels.clone(),
+                sort: Some("updated".to_string()),
+                direction: Some("asc".to_string()),
+                since: since.map(|s| s.to_rfc3339()),
+                page: None,
+                per_page: Some(100),
+            },
+        );
+        while let Some(issue) = stream.next().await {
+            let issue = issue?;
+            if request.include_pull_requests || issue.pull_request.is_none() {
+                found.insert(issue.number, ExportedIssue::from(&issue));
+            }
+        }
+    }
+    Ok(found.into_values().collect())
+}
+
+async fn read_checkpoint(path: &str) -> Result<Option<ExportCheckpoint>, GitHubError> {
+    match tokio::fs::read_to_string(path).await {
+        Ok(content) => serde_json::from_str(&content)
+            .map(Some)
+            .map_err(|e| GitHubError::InvalidInput(format!("Invalid checkpoint {path}: {e}"))),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(GitHubError::Other(format!("Failed to read {path}: {e}"))),
+    }
+}
+
+async fn write_file(path: &str, content: &[u8]) -> Result<(), GitHubError> {
+    if let Some(parent) = std::path::Path::new(path).parent()
+        && !parent.as_os_str().is_empty()
+    {
+        tokio::fs::create_dir_all(parent).await.map_err(|e| {
+            GitHubError::Other(format!("Failed to create {}: {e}", parent.display()))
+        })?;
+    }
+    tokio::fs::write(path, content)
+        .await
+        .map_err(|e| GitHubError::Other(format!("Failed to write {path}: {e}")))
+}
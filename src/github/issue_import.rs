@@ -0,0 +1,565 @@
+//! Bulk issue import from a CSV file or a Markdown task list.
+//!
+//! Every imported issue carries a hidden idempotency marker in its body.
+//! Before creating anything the repository's issues are scanned for these
+//! markers, so re-running an import only creates what is still missing.
+
+use crate::github::create_issue::send_create_issue;
+use crate::github::error::GitHubError;
+use crate::github::list_issues::{ListIssuesRequest, list_issues};
+use crate::github::milestones::list_milestones;
+use crate::github::util::spawn_task;
+use crate::runtime::AsyncTask;
+use futures::StreamExt;
+use octocrab::{Octocrab, models::IssueState};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Longest key derived from a title.
+const MAX_DERIVED_KEY_LEN: usize = 80;
+
+/// Input format of an import.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportFormat {
+    /// CSV with a header row.
+    Csv,
+    /// Markdown task list (`- [ ] title`).
+    Markdown,
+}
+
+impl ImportFormat {
+    /// Guess the format from a file name's extension.
+    #[must_use]
+    pub fn from_path(path: &str) -> Option<Self> {
+        let ext = path.rsplit_once('.')?.1.to_lowercase();
+        match ext.as_str() {
+            "csv" => Some(Self::Csv),
+            "md" | "markdown" => Some(Self::Markdown),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for ImportFormat {
+    type Err = GitHubError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "csv" => Ok(Self::Csv),
+            "md" | "markdown" => Ok(Self::Markdown),
+            _ => Err(GitHubError::InvalidInput(format!(
+                "Unknown import format '{s}', expected csv or markdown"
+            ))),
+        }
+    }
+}
+
+/// CSV header names for each issue field, matched case-insensitively.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnMapping {
+    /// Title column (required).
+    pub title: String,
+    /// Body column.
+    pub body: String,
+    /// Labels column, values separated by `;` or `,`.
+    pub labels: String,
+    /// Assignees column, values separated by `;` or `,`.
+    pub assignees: String,
+    /// Milestone column, holding a milestone number or title.
+    pub milestone: String,
+    /// Idempotency key column.
+    pub key: String,
+}
+
+impl Default for ColumnMapping {
+    fn default() -> Self {
+        Self {
+            title: "title".to_string(),
+            body: "body".to_string(),
+            labels: "labels".to_string(),
+            assignees: "assignees".to_string(),
+            milestone: "milestone".to_string(),
+            key: "key".to_string(),
+        }
+    }
+}
+
+/// One issue to import.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct IssueDraft {
+    /// Idempotency key; re-imports skip issues already carrying it.
+    pub key: String,
+    /// Title.
+    pub title: String,
+    /// Markdown body.
+    pub body: String,
+    /// Label names.
+    pub labels: Vec<String>,
+    /// Assignee logins.
+    pub assignees: Vec<String>,
+    /// Milestone number or title.
+    pub milestone: Option<String>,
+}
+
+/// Hidden marker identifying an imported issue by its key.
+#[must_use]
+pub fn import_marker(key: &str) -> String {
+    format!("<!-- issue-import:{} -->", key.trim())
+}
+
+/// Key recorded in an issue body by [`import_marker`], if any.
+#[must_use]
+pub fn extract_import_key(body: &str) -> Option<String> {
+    let start = body.find("<!-- issue-import:")? + "<!-- issue-import:".len();
+    let end = body[start..].find("-->")?;
+    let key = body[start..start + end].trim();
+    (!key.is_empty()).then(|| key.to_string())
+}
+
+/// Key derived from a title: lowercase words joined by `-`.
+#[must_use]
+pub fn derive_import_key(title: &str) -> String {
+    let words: Vec<String> = title
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    let mut key = String::new();
+    for word in words {
+        if !key.is_empty() && key.len() + 1 + word.len() > MAX_DERIVED_KEY_LEN {
+            break;
+        }
+        if !key.is_empty() {
+            key.push('-');
+        }
+        key.push_str(&word);
+    }
+    key
+}
+
+/// Issue body with the idempotency marker appended.
+#[must_use]
+pub fn render_import_body(draft: &IssueDraft) -> String {
+    let marker = import_marker(&draft.key);
+    if draft.body.trim().is_empty() {
+        marker
+    } else {
+        format!("{}\n\n{marker}", draft.body.trim_end())
+    }
+}
+
+fn split_list(value: &str) -> Vec<String> {
+    value
+        .split([';', ','])
+        .map(|v| v.trim().trim_start_matches('@').to_string())
+        .filter(|v| !v.is_empty())
+        .collect()
+}
+
+/// Parse a CSV import; rows without a title are skipped.
+///
+/// Rows without a key column value are keyed by their title.
+pub fn parse_csv_import(
+    content: &str,
+    mapping: &ColumnMapping,
+) -> Result<Vec<IssueDraft>, GitHubError> {
+    let invalid = |e: csv::Error| GitHubError::InvalidInput(format!("Invalid CSV: {e}"));
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::Headers)
+        .from_reader(content.as_bytes());
+    let headers = reader.headers().map_err(invalid)?.clone();
+    let column = |name: &str| headers.iter().position(|h| h.eq_ignore_ascii_case(name));
+
+    let title_col = column(&mapping.title).ok_or_else(|| {
+        GitHubError::InvalidInput(format!("CSV has no '{}' column", mapping.title))
+    })?;
+    let (body_col, labels_col, assignees_col, milestone_col, key_col) = (
+        column(&mapping.body),
+        column(&mapping.labels),
+        column(&mapping.assignees),
+        column(&mapping.milestone),
+        column(&mapping.key),
+    );
+
+    let mut drafts = Vec::new();
+    for record in reader.records() {
+        let record = record.map_err(invalid)?;
+        let field = |col: Option<usize>| col.and_then(|c| record.get(c)).unwrap_or_default();
+
+        let title = field(Some(title_col)).trim().to_string();
+        if title.is_empty() {
+            continue;
+        }
+        let key = match field(key_col).trim() {
+            "" => derive_import_key(&title),
+            key => key.to_string(),
+        };
+        let milestone = field(milestone_col).trim();
+        drafts.push(IssueDraft {
+            key,
+            title,
+            body: field(body_col).to_string(),
+            labels: split_list(field(labels_col)),
+            assignees: split_list(field(assignees_col)),
+            milestone: (!milestone.is_empty()).then(|| milestone.to_string()),
+        });
+    }
+    check_keys(&drafts)?;
+    Ok(drafts)
+}
+
+/// Parse a Markdown task list.
+///
+/// Each unchecked `- [ ]` item becomes an issue; checked items are skipped.
+/// Trailing `@user` and `#label` words on the item line become assignees and
+/// labels (`#123` stays in the title as an issue reference). Lines indented
+/// under an item form its body. An item ending in `<!-- key: ... -->` uses
+/// that key instead of one derived from the title.
+pub fn parse_markdown_import(content: &str) -> Result<Vec<IssueDraft>, GitHubError> {
+    let mut drafts: Vec<IssueDraft> = Vec::new();
+    let mut body_lines: Vec<&str> = Vec::new();
+    let mut in_item = false;
+
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        let item = ["- [", "* [", "+ ["]
+            .iter()
+            .find_map(|p| trimmed.strip_prefix(p))
+            .and_then(|rest| rest.split_once(']'))
+            .filter(|(mark, _)| mark.len() <= 1);
+
+        if let Some((mark, text)) = item
+            && line.len() - trimmed.len() < 2
+        {
+            if in_item {
+                finish_item(&mut drafts, &mut body_lines);
+            }
+            in_item = mark.trim().is_empty();
+            if in_item {
+                drafts.push(parse_task_line(text));
+            }
+        } else if in_item && (line.starts_with([' ', '\t']) || line.trim().is_empty()) {
+            body_lines.push(line);
+        } else if in_item {
+            finish_item(&mut drafts, &mut body_lines);
+            in_item = false;
+        }
+    }
+    if in_item {
+        finish_item(&mut drafts, &mut body_lines);
+    }
+
+    drafts.retain(|d| !d.title.is_empty());
+    check_keys(&drafts)?;
+    Ok(drafts)
+}
+
+fn finish_item(drafts: &mut [IssueDraft], body_lines: &mut Vec<&str>) {
+    if let Some(draft) = drafts.last_mut() {
+        draft.body = dedent(body_lines).trim().to_string();
+    }
+    body_lines.clear();
+}
+
+fn parse_task_line(text: &str) -> IssueDraft {
+    let mut text = text.trim();
+    let mut key = None;
+    if let Some(start) = text.rfind("<!-- key:")
+        && text.ends_with("-->")
+    {
+        key = Some(text[start + 9..text.len() - 3].trim().to_string());
+        text = text[..start].trim_end();
+    }
+
+    let mut words: Vec<&str> = text.split_whitespace().collect();
+    let (mut labels, mut assignees) = (Vec::new(), Vec::new());
+    while let Some(last) = words.last() {
+        if let Some(user) = last.strip_prefix('@').filter(|u| !u.is_empty()) {
+            assignees.insert(0, user.to_string());
+        } else if let Some(label) = last
+            .strip_prefix('#')
+            .filter(|l| !l.is_empty() && !l.chars().all(|c| c.is_ascii_digit()))
+        {
+            labels.insert(0, label.to_string());
+        } else {
+            break;
+        }
+        words.pop();
+    }
+
+    let title = words.join(" ");
+    IssueDraft {
+        key: key
+            .filter(|k| !k.is_empty())
+            .unwrap_or_else(|| derive_import_key(&title)),
+        title,
+        body: String::new(),
+        labels,
+        assignees,
+        milestone: None,
+    }
+}
+
+fn dedent(lines: &[&str]) -> String {
+    let indent = lines
+        .iter()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| l.len() - l.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    lines
+        .iter()
+        .map(|l| l.get(indent..).unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn check_keys(drafts: &[IssueDraft]) -> Result<(), GitHubError> {
+    let mut seen = BTreeSet::new();
+    for draft in drafts {
+        if draft.key.is_empty() || draft.key.contains("-->") {
+            return Err(GitHubError::InvalidInput(format!(
+                "Issue '{}' has an invalid import key",
+                draft.title
+            )));
+        }
+        if !seen.insert(draft.key.as_str()) {
+            return Err(GitHubError::InvalidInput(format!(
+                "Import key '{}' appears more than once; add a key to tell the rows apart",
+                draft.key
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Request parameters for importing issues
+#[derive(Debug, Clone)]
+pub struct ImportIssuesRequest {
+    /// Repository owner (user or organization)
+    pub owner: String,
+    /// Repository name
+    pub repo: String,
+    /// Issues to create
+    pub drafts: Vec<IssueDraft>,
+    /// Labels added to every issue
+    pub default_labels: Vec<String>,
+    /// Milestone number or title for issues without one
+    pub default_milestone: Option<String>,
+    /// Only report what would be created
+    pub dry_run: bool,
+}
+
+/// What happened to one draft.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportStatus {
+    /// A new issue was created
+    Created,
+    /// The issue would be created (dry run)
+    WouldCreate,
+    /// An issue with this key already exists
+    Existing,
+    /// Creating the issue failed
+    Failed,
+}
+
+/// Per-draft result of an import.
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportedIssue {
+    /// Idempotency key
+    pub key: String,
+    /// Title
+    pub title: String,
+    /// Outcome
+    pub status: ImportStatus,
+    /// Issue number, when created or already existing
+    pub number: Option<u64>,
+    /// Issue URL, when created or already existing
+    pub html_url: Option<String>,
+    /// Error message for failures
+    pub error: Option<String>,
+}
+
+/// Outcome of an import, in input order.
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportReport {
+    /// Whether this was a dry run
+    pub dry_run: bool,
+    /// Per-draft results
+    pub results: Vec<ImportedIssue>,
+}
+
+impl ImportReport {
+    /// Number of results with the given status.
+    #[must_use]
+    pub fn count(&self, status: ImportStatus) -> usize {
+        self.results.iter().filter(|r| r.status == status).count()
+    }
+}
+
+/// Create the drafts that do not exist yet, one at a time and in order.
+///
+/// Fails outright only when existing issues or milestones cannot be listed
+/// or a milestone is unknown; failed creations are reported per draft.
+pub(crate) fn import_issues(
+    inner: Arc<Octocrab>,
+    request: ImportIssuesRequest,
+) -> AsyncTask<Result<ImportReport, GitHubError>> {
+    spawn_task(async move {
+        check_keys(&request.drafts)?;
+        let existing = existing_import_keys(&inner, &request.owner, &request.repo).await?;
+
+        let wanted: BTreeSet<&str> = request
+            .drafts
+            .iter()
+            .filter_map(|d| {
+                d.milestone
+                    .as_deref()
+                    .or(request.default_milestone.as_deref())
+            })
+            .collect();
+        let milestones = resolve_milestones(&inner, &request.owner, &request.repo, &wanted).await?;
+
+        let mut results = Vec::with_capacity(request.drafts.len());
+        for draft in &request.drafts {
+            let mut result = ImportedIssue {
+                key: draft.key.clone(),
+                title: draft.title.clone(),
+                status: ImportStatus::WouldCreate,
+                number: None,
+                html_url: None,
+                error: None,
+            };
+            if let Some((number, url)) = existing.get(&draft.key) {
+                result.status = ImportStatus::Existing;
+                result.number = Some(*number);
+                result.html_url = Some(url.clone());
+                results.push(result);
+                continue;
+            }
+            if request.dry_run {
+                results.push(result);
+                continue;
+            }
+
+            let mut labels = request.default_labels.clone();
+            for label in &draft.labels {
+                if !labels.iter().any(|l| l.eq_ignore_ascii_case(label)) {
+                    labels.push(label.clone());
+                }
+            }
+            let milestone = draft
+                .milestone
+                .as_deref()
+                .or(request.default_milestone.as_deref())
+                .and_then(|m| milestones.get(m).copied());
+
+            match send_create_issue(
+                &inner,
+                &request.owner,
+                &request.repo,
+                draft.title.clone(),
+                Some(render_import_body(draft)),
+                (!draft.assignees.is_empty()).then(|| draft.assignees.clone()),
+                (!labels.is_empty()).then_some(labels),
+                milestone,
+            )
+            .await
+            {
+                Ok(issue) => {
+                    result.status = ImportStatus::Created;
+                    result.number = Some(issue.number);
+                    result.html_url = Some(issue.html_url.to_string());
+                }
+                Err(e) => {
+                    result.status = ImportStatus::Failed;
+                    result.error = Some(e.to_string());
+                }
+            }
+            results.push(result);
+        }
+
+        Ok(ImportReport {
+            dry_run: request.dry_run,
+            results,
+        })
+    })
+}
+
+/// Import keys already present in the repository, with issue number and URL.
+async fn existing_import_keys(
+    inner: &Arc<Octocrab>,
+    owner: &str,
+    repo: &str,
+) -> Result<BTreeMap<String, (u64, String)>, GitHubError> {
+    let mut keys = BTreeMap::new();
+    // The list endpoint takes one state at a time
+    for state in [IssueState::Open, IssueState::Closed] {
+        let mut stream = list_issues(
+            inner.clone(),
+            ListIssuesRequest {
+                owner: owner.to_string(),
+                repo: repo.to_string(),
+                state: Some(state),
+                labels: None,
+                sort: None,
+                direction: None,
+                since: None,
+                page: None,
+                per_page: Some(100),
+            },
+        );
+        while let Some(issue) = stream.next().await {
+            let issue = issue?;
+            if let Some(key) = issue.body.as_deref().and_then(extract_import_key) {
+                keys.entry(key)
+                    .or_insert((issue.number, issue.html_url.to_string()));
+            }
+        }
+    }
+    Ok(keys)
+}
+
+/// Map each milestone reference (number or title) to a milestone number.
+async fn resolve_milestones(
+    inner: &Arc<Octocrab>,
+    owner: &str,
+    repo: &str,
+    wanted: &BTreeSet<&str>,
+) -> Result<BTreeMap<String, u64>, GitHubError> {
+    let mut resolved = BTreeMap::new();
+    let mut titles = Vec::new();
+    for reference in wanted {
+        match reference.trim_start_matches('#').parse::<u64>() {
+            Ok(number) => {
+                resolved.insert((*reference).to_string(), number);
+            }
+            Err(_) => titles.push(*reference),
+        }
+    }
+    if titles.is_empty() {
+        return Ok(resolved);
+    }
+
+    let mut stream = list_milestones(
+        inner.clone(),
+        owner.to_string(),
+        repo.to_string(),
+        Some("all".to_string()),
+    );
+    let mut milestones = Vec::new();
+    while let Some(milestone) = stream.next().await {
+        milestones.push(milestone?);
+    }
+    for title in titles {
+        let milestone = milestones
+            .iter()
+            .find(|m| m.title.eq_ignore_ascii_case(title.trim()))
+            .ok_or_else(|| GitHubError::NotFound(format!("Milestone '{title}'")))?;
+        resolved.insert(title.to_string(), milestone.number);
+    }
+    Ok(resolved)
+}
@@ -0,0 +1,247 @@
+//! Issue moderation: locking, transferring, pinning and close reasons.
+//!
+//! Locking goes through REST; transferring and pinning only exist as GraphQL
+//! mutations, which take node IDs rather than numbers.
+
+use crate::github::error::GitHubError;
+use crate::github::util::{delete_no_content, graphql, put_no_content, spawn_task};
+use crate::runtime::AsyncTask;
+use octocrab::Octocrab;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use std::sync::Arc;
+
+const TRANSFER_ISSUE_MUTATION: &str = r"
+mutation($issueId: ID!, $repositoryId: ID!, $createLabels: Boolean) {
+  transferIssue(input: { issueId: $issueId, repositoryId: $repositoryId, createLabelsIfMissing: $createLabels }) {
+    issue { number url repository { nameWithOwner } }
+  }
+}";
+
+const PIN_ISSUE_MUTATION: &str = r"
+mutation($issueId: ID!) {
+  pinIssue(input: { issueId: $issueId }) { issue { number } }
+}";
+
+const UNPIN_ISSUE_MUTATION: &str = r"
+mutation($issueId: ID!) {
+  unpinIssue(input: { issueId: $issueId }) { issue { number } }
+}";
+
+/// Reason shown when an issue or PR conversation is locked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockReason {
+    /// Discussion drifted away from the issue
+    OffTopic,
+    /// Discussion became uncivil
+    TooHeated,
+    /// Nothing left to discuss
+    Resolved,
+    /// Spam comments
+    Spam,
+}
+
+impl LockReason {
+    /// API value (`off-topic`, `too heated`, `resolved`, `spam`).
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::OffTopic => "off-topic",
+            Self::TooHeated => "too heated",
+            Self::Resolved => "resolved",
+            Self::Spam => "spam",
+        }
+    }
+}
+
+impl FromStr for LockReason {
+    type Err = GitHubError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().replace(['_', ' '], "-").as_str() {
+            "off-topic" | "offtopic" => Ok(Self::OffTopic),
+            "too-heated" => Ok(Self::TooHeated),
+            "resolved" => Ok(Self::Resolved),
+            "spam" => Ok(Self::Spam),
+            _ => Err(GitHubError::InvalidInput(format!(
+                "Unknown lock reason '{s}', expected off-topic, too heated, resolved or spam"
+            ))),
+        }
+    }
+}
+
+/// Why an issue was closed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CloseReason {
+    /// Done, fixed or answered
+    Completed,
+    /// Won't fix, stale or out of scope
+    NotPlanned,
+    /// Tracked by another issue
+    Duplicate,
+}
+
+impl CloseReason {
+    /// API value for `state_reason`.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Completed => "completed",
+            Self::NotPlanned => "not_planned",
+            Self::Duplicate => "duplicate",
+        }
+    }
+}
+
+impl FromStr for CloseReason {
+    type Err = GitHubError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().replace(['-', ' '], "_").as_str() {
+            "completed" | "complete" | "done" => Ok(Self::Completed),
+            "not_planned" | "wontfix" | "won't_fix" => Ok(Self::NotPlanned),
+            "duplicate" => Ok(Self::Duplicate),
+            _ => Err(GitHubError::InvalidInput(format!(
+                "Unknown close reason '{s}', expected completed, not_planned or duplicate"
+            ))),
+        }
+    }
+}
+
+/// Where an issue ended up after a transfer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferredIssue {
+    /// Issue number in the destination repository
+    pub number: u64,
+    /// Issue URL in the destination repository
+    pub url: String,
+    /// Destination repository as `owner/name`
+    pub repository: String,
+}
+
+/// Lock an issue or PR conversation so only collaborators can comment.
+pub(crate) fn lock_issue(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    issue_number: u64,
+    reason: Option<LockReason>,
+) -> AsyncTask<Result<(), GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+    spawn_task(async move {
+        let route = format!("/repos/{owner}/{repo}/issues/{issue_number}/lock");
+        match reason {
+            Some(r) => {
+                let body = serde_json::json!({ "lock_reason": r.as_str() });
+                put_no_content(&inner, route, Some(&body)).await
+            }
+            None => put_no_content(&inner, route, None::<&()>).await,
+        }
+    })
+}
+
+/// Unlock an issue or PR conversation.
+pub(crate) fn unlock_issue(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    issue_number: u64,
+) -> AsyncTask<Result<(), GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+    spawn_task(async move {
+        delete_no_content(
+            &inner,
+            format!("/repos/{owner}/{repo}/issues/{issue_number}/lock"),
+        )
+        .await
+    })
+}
+
+/// Move an issue to another repository owned by the same user or organization.
+pub(crate) fn transfer_issue(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    issue_number: u64,
+    target_owner: impl Into<String>,
+    target_repo: impl Into<String>,
+    create_labels_if_missing: bool,
+) -> AsyncTask<Result<TransferredIssue, GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+    let (target_owner, target_repo) = (target_owner.into(), target_repo.into());
+
+    spawn_task(async move {
+        let issue_id = issue_node_id(&inner, &owner, &repo, issue_number).await?;
+        let repository_id = inner
+            .repos(&target_owner, &target_repo)
+            .get()
+            .await
+            .map_err(GitHubError::from)?
+            .node_id
+            .ok_or_else(|| {
+                GitHubError::Api(format!("{target_owner}/{target_repo} has no node ID"))
+            })?;
+
+        let data: serde_json::Value = graphql(
+            &inner,
+            TRANSFER_ISSUE_MUTATION,
+            serde_json::json!({
+                "issueId": issue_id,
+                "repositoryId": repository_id,
+                "createLabels": create_labels_if_missing,
+            }),
+        )
+        .await?;
+
+        let issue = &data["transferIssue"]["issue"];
+        let (Some(number), Some(url)) = (issue["number"].as_u64(), issue["url"].as_str()) else {
+            return Err(GitHubError::Api(
+                "transferIssue returned no issue".to_string(),
+            ));
+        };
+        Ok(TransferredIssue {
+            number,
+            url: url.to_string(),
+            repository: issue["repository"]["nameWithOwner"]
+                .as_str()
+                .map_or_else(|| format!("{target_owner}/{target_repo}"), str::to_string),
+        })
+    })
+}
+
+/// Pin an issue to the top of the repository's issue list, or unpin it.
+pub(crate) fn set_issue_pinned(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    issue_number: u64,
+    pinned: bool,
+) -> AsyncTask<Result<(), GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+    spawn_task(async move {
+        let issue_id = issue_node_id(&inner, &owner, &repo, issue_number).await?;
+        let mutation = if pinned {
+            PIN_ISSUE_MUTATION
+        } else {
+            UNPIN_ISSUE_MUTATION
+        };
+        let _: serde_json::Value =
+            graphql(&inner, mutation, serde_json::json!({ "issueId": issue_id })).await?;
+        Ok(())
+    })
+}
+
+async fn issue_node_id(
+    inner: &Octocrab,
+    owner: &str,
+    repo: &str,
+    issue_number: u64,
+) -> Result<String, GitHubError> {
+    let issue = inner
+        .issues(owner, repo)
+        .get(issue_number)
+        .await
+        .map_err(GitHubError::from)?;
+    Ok(issue.node_id)
+}
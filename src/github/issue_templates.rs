@@ -0,0 +1,818 @@
+//! Issue templates: YAML issue forms, classic Markdown templates and the
+//! template chooser `config.yml`, all from `.github/ISSUE_TEMPLATE`.
+//!
+//! Issue forms are rendered the way GitHub's web UI does: one `### Label`
+//! section per field, with `_No response_` for skipped optional fields.
+
+use crate::github::create_issue::send_create_issue;
+use crate::github::error::GitHubError;
+use crate::github::util::spawn_task;
+use crate::runtime::AsyncTask;
+use octocrab::{Octocrab, models::issues::Issue};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// Directory GitHub reads issue templates from.
+pub const ISSUE_TEMPLATE_DIR: &str = ".github/ISSUE_TEMPLATE";
+
+/// Placeholder GitHub writes for optional fields left empty.
+pub const NO_RESPONSE: &str = "_No response_";
+
+/// Kind of input an issue form field takes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueFormFieldKind {
+    /// Single-line text
+    Input,
+    /// Multi-line text, optionally rendered as a code block
+    Textarea,
+    /// One (or with `multiple`, several) of a fixed list of options
+    Dropdown,
+    /// A list of checkboxes, some of which may be required
+    Checkboxes,
+}
+
+/// One user-fillable field of an issue form.
+#[derive(Debug, Clone, Serialize)]
+pub struct IssueFormField {
+    /// Field ID, if the form gives one
+    pub id: Option<String>,
+    /// Kind of input
+    pub kind: IssueFormFieldKind,
+    /// Label, used as the section heading in the issue body
+    pub label: String,
+    /// Help text shown under the label
+    pub description: Option<String>,
+    /// Dropdown or checkbox options
+    pub options: Vec<String>,
+    /// Checkbox options that must be ticked
+    pub required_options: Vec<String>,
+    /// Whether a dropdown accepts several options
+    pub multiple: bool,
+    /// Language to render a textarea as a code block in
+    pub render: Option<String>,
+    /// Pre-filled value
+    pub default: Option<String>,
+    /// Whether a value is required
+    pub required: bool,
+}
+
+impl IssueFormField {
+    /// Key to pass the field's value under: its ID, or its label without one.
+    #[must_use]
+    pub fn key(&self) -> &str {
+        self.id.as_deref().unwrap_or(&self.label)
+    }
+
+    fn matches_key(&self, key: &str) -> bool {
+        self.id.as_deref() == Some(key) || self.label.eq_ignore_ascii_case(key.trim())
+    }
+}
+
+/// An issue template from `.github/ISSUE_TEMPLATE`.
+#[derive(Debug, Clone, Serialize)]
+pub struct IssueTemplate {
+    /// File name without extension, e.g. `bug_report`
+    pub id: String,
+    /// Path in the repository
+    pub path: String,
+    /// Display name in the template chooser
+    pub name: String,
+    /// Description in the template chooser
+    pub description: String,
+    /// Title prefix, e.g. `[Bug]: `
+    pub title: Option<String>,
+    /// Labels applied to issues created from the template
+    pub labels: Vec<String>,
+    /// Users assigned to issues created from the template
+    pub assignees: Vec<String>,
+    /// Form fields; empty for Markdown templates
+    pub fields: Vec<IssueFormField>,
+    /// Body of a Markdown template
+    pub markdown: Option<String>,
+}
+
+/// A contact link shown in the template chooser.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContactLink {
+    /// Link name
+    pub name: String,
+    /// Link target
+    pub url: String,
+    /// Short description
+    #[serde(default)]
+    pub about: String,
+}
+
+/// Contents of `.github/ISSUE_TEMPLATE/config.yml`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueTemplateConfig {
+    /// Whether issues can be opened without a template
+    #[serde(default = "default_true")]
+    pub blank_issues_enabled: bool,
+    /// Links shown alongside the templates
+    #[serde(default)]
+    pub contact_links: Vec<ContactLink>,
+}
+
+impl Default for IssueTemplateConfig {
+    fn default() -> Self {
+        Self {
+            blank_issues_enabled: true,
+            contact_links: Vec::new(),
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// A template file that could not be parsed.
+#[derive(Debug, Clone, Serialize)]
+pub struct InvalidIssueTemplate {
+    /// Path in the repository
+    pub path: String,
+    /// Why it was rejected
+    pub error: String,
+}
+
+/// All issue templates of a repository.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct IssueTemplates {
+    /// Parsed templates, sorted by file name
+    pub templates: Vec<IssueTemplate>,
+    /// Chooser configuration (defaults when there is no `config.yml`)
+    pub config: IssueTemplateConfig,
+    /// Files that failed to parse
+    pub invalid: Vec<InvalidIssueTemplate>,
+}
+
+impl IssueTemplates {
+    /// Find a template by ID, file name or display name (case-insensitive).
+    #[must_use]
+    pub fn find(&self, name: &str) -> Option<&IssueTemplate> {
+        let name = name.trim();
+        self.templates.iter().find(|t| {
+            t.id.eq_ignore_ascii_case(name)
+                || t.path
+                    .rsplit('/')
+                    .next()
+                    .is_some_and(|f| f.eq_ignore_ascii_case(name))
+                || t.name.eq_ignore_ascii_case(name)
+        })
+    }
+}
+
+/// Value supplied for an issue form field.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum FieldValue {
+    /// Text, or a single dropdown/checkbox option
+    Text(String),
+    /// Several dropdown or checkbox options
+    List(Vec<String>),
+}
+
+impl FieldValue {
+    /// Convert a JSON value: strings, numbers and booleans become text,
+    /// arrays of those become lists.
+    #[must_use]
+    pub fn from_json(value: &serde_json::Value) -> Option<Self> {
+        fn scalar(value: &serde_json::Value) -> Option<String> {
+            match value {
+                serde_json::Value::String(s) => Some(s.clone()),
+                serde_json::Value::Number(n) => Some(n.to_string()),
+                serde_json::Value::Bool(b) => Some(b.to_string()),
+                _ => None,
+            }
+        }
+        match value {
+            serde_json::Value::Array(items) => items
+                .iter()
+                .map(scalar)
+                .collect::<Option<Vec<_>>>()
+                .map(Self::List),
+            other => scalar(other).map(Self::Text),
+        }
+    }
+
+    fn items(&self) -> Vec<&str> {
+        match self {
+            Self::Text(s) => vec![s.as_str()],
+            Self::List(items) => items.iter().map(String::as_str).collect(),
+        }
+    }
+}
+
+/// Title, body, labels and assignees for an issue created from a template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedIssue {
+    /// Title with the template's prefix
+    pub title: String,
+    /// Issue body
+    pub body: String,
+    /// Template labels
+    pub labels: Vec<String>,
+    /// Template assignees
+    pub assignees: Vec<String>,
+}
+
+impl IssueTemplate {
+    /// Parse a template file, picking the format from its extension.
+    pub fn parse(path: &str, content: &str) -> Result<Self, GitHubError> {
+        let lower = path.to_lowercase();
+        if lower.ends_with(".yml") || lower.ends_with(".yaml") {
+            Self::parse_form(path, content)
+        } else if lower.ends_with(".md") {
+            Self::parse_markdown(path, content)
+        } else {
+            Err(GitHubError::InvalidInput(format!(
+                "{path}: issue templates must be .yml, .yaml or .md files"
+            )))
+        }
+    }
+
+    /// Parse a YAML issue form.
+    pub fn parse_form(path: &str, content: &str) -> Result<Self, GitHubError> {
+        let raw: RawForm = serde_yaml::from_str(content)
+            .map_err(|e| GitHubError::InvalidInput(format!("{path}: {e}")))?;
+
+        let mut fields: Vec<IssueFormField> = Vec::new();
+        for (index, element) in raw.body.into_iter().enumerate() {
+            let Some(field) = element.into_field(path, index)? else {
+                continue;
+            };
+            if let Some(id) = &field.id
+                && fields.iter().any(|f| f.id.as_deref() == Some(id.as_str()))
+            {
+                return Err(GitHubError::InvalidInput(format!(
+                    "{path}: duplicate field id '{id}'"
+                )));
+            }
+            fields.push(field);
+        }
+        if fields.is_empty() {
+            return Err(GitHubError::InvalidInput(format!(
+                "{path}: issue form has no input fields"
+            )));
+        }
+
+        Ok(Self {
+            id: template_id(path),
+            path: path.to_string(),
+            name: raw.name,
+            description: raw.description,
+            title: raw.title.filter(|t| !t.is_empty()),
+            labels: raw.labels,
+            assignees: raw.assignees,
+            fields,
+            markdown: None,
+        })
+    }
+
+    /// Parse a Markdown template with YAML front matter.
+    pub fn parse_markdown(path: &str, content: &str) -> Result<Self, GitHubError> {
+        let content = content.trim_start_matches('\u{feff}');
+        let (front, body) = content
+            .strip_prefix("---")
+            .and_then(|rest| rest.split_once("\n---"))
+            .ok_or_else(|| {
+                GitHubError::InvalidInput(format!("{path}: missing YAML front matter"))
+            })?;
+        let raw: RawMarkdownFront = serde_yaml::from_str(front)
+            .map_err(|e| GitHubError::InvalidInput(format!("{path}: {e}")))?;
+        let body = body.split_once('\n').map_or("", |(_, b)| b).trim();
+
+        Ok(Self {
+            id: template_id(path),
+            path: path.to_string(),
+            name: raw.name,
+            description: raw.about,
+            title: raw.title.filter(|t| !t.is_empty()),
+            labels: raw.labels,
+            assignees: raw.assignees,
+            fields: Vec::new(),
+            markdown: Some(body.to_string()),
+        })
+    }
+
+    /// Whether this is a YAML issue form rather than a Markdown template.
+    #[must_use]
+    pub fn is_form(&self) -> bool {
+        !self.fields.is_empty()
+    }
+
+    /// Validate field values and render the issue.
+    ///
+    /// Values are keyed by field ID or label. Unknown keys, missing required
+    /// fields and options not offered by a dropdown or checkbox list are all
+    /// reported together.
+    pub fn render(
+        &self,
+        title: &str,
+        values: &BTreeMap<String, FieldValue>,
+    ) -> Result<RenderedIssue, GitHubError> {
+        let title = self.apply_title_prefix(title)?;
+
+        let body = if self.is_form() {
+            self.render_form(values)?
+        } else {
+            if let Some(key) = values.keys().next() {
+                return Err(GitHubError::InvalidInput(format!(
+                    "Template '{}' is a Markdown template and has no field '{key}'",
+                    self.id
+                )));
+            }
+            self.markdown.clone().unwrap_or_default()
+        };
+
+        Ok(RenderedIssue {
+            title,
+            body,
+            labels: self.labels.clone(),
+            assignees: self.assignees.clone(),
+        })
+    }
+
+    fn apply_title_prefix(&self, title: &str) -> Result<String, GitHubError> {
+        let title = title.trim();
+        let prefix = self.title.as_deref().unwrap_or_default();
+        if title.is_empty() || title == prefix.trim() {
+            return Err(GitHubError::InvalidInput(
+                "A title is required in addition to the template's title prefix".to_string(),
+            ));
+        }
+        if prefix.trim().is_empty() || title.starts_with(prefix.trim()) {
+            Ok(title.to_string())
+        } else {
+            Ok(format!("{prefix}{title}"))
+        }
+    }
+
+    fn render_form(&self, values: &BTreeMap<String, FieldValue>) -> Result<String, GitHubError> {
+        let mut errors = Vec::new();
+        let mut by_field: Vec<Option<&FieldValue>> = vec![None; self.fields.len()];
+
+        for (key, value) in values {
+            match self.fields.iter().position(|f| f.matches_key(key)) {
+                Some(index) => by_field[index] = Some(value),
+                None => errors.push(format!("unknown field '{key}'")),
+            }
+        }
+
+        let mut sections = Vec::new();
+        for (field, value) in self.fields.iter().zip(by_field) {
+            match render_field(field, value) {
+                Ok(content) => sections.push(format!("### {}\n\n{content}", field.label)),
+                Err(e) => errors.push(e),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(sections.join("\n\n"))
+        } else {
+            let expected = self
+                .fields
+                .iter()
+                .map(IssueFormField::key)
+                .collect::<Vec<_>>()
+                .join(", ");
+            Err(GitHubError::InvalidInput(format!(
+                "Template '{}': {} (fields: {expected})",
+                self.id,
+                errors.join("; ")
+            )))
+        }
+    }
+}
+
+fn render_field(field: &IssueFormField, value: Option<&FieldValue>) -> Result<String, String> {
+    let key = field.key();
+    let items: Vec<&str> = value
+        .map(FieldValue::items)
+        .unwrap_or_default()
+        .into_iter()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    match field.kind {
+        IssueFormFieldKind::Input | IssueFormFieldKind::Textarea => {
+            if items.len() > 1 {
+                return Err(format!("'{key}' takes a single text value"));
+            }
+            let text = items
+                .first()
+                .map(|s| (*s).to_string())
+                .or_else(|| field.default.clone().filter(|d| !d.trim().is_empty()));
+            match (text, &field.render) {
+                (Some(text), Some(lang)) => Ok(format!("```{lang}\n{text}\n```")),
+                (Some(text), None) => Ok(text),
+                (None, _) if field.required => Err(format!("'{key}' is required")),
+                (None, _) => Ok(NO_RESPONSE.to_string()),
+            }
+        }
+        IssueFormFieldKind::Dropdown => {
+            if items.len() > 1 && !field.multiple {
+                return Err(format!("'{key}' takes a single option"));
+            }
+            let mut chosen = Vec::new();
+            for item in &items {
+                chosen.push(canonical_option(field, item)?);
+            }
+            if chosen.is_empty()
+                && let Some(default) = &field.default
+            {
+                chosen.push(default.as_str());
+            }
+            if chosen.is_empty() {
+                return if field.required {
+                    Err(format!("'{key}' is required"))
+                } else {
+                    Ok(NO_RESPONSE.to_string())
+                };
+            }
+            Ok(chosen.join(", "))
+        }
+        IssueFormFieldKind::Checkboxes => {
+            let mut checked = Vec::new();
+            for item in &items {
+                checked.push(canonical_option(field, item)?);
+            }
+            let missing: Vec<&str> = field
+                .required_options
+                .iter()
+                .map(String::as_str)
+                .filter(|o| !checked.contains(o))
+                .collect();
+            if !missing.is_empty() {
+                return Err(format!("'{key}' requires checking: {}", missing.join(", ")));
+            }
+            Ok(field
+                .options
+                .iter()
+                .map(|o| {
+                    let mark = if checked.contains(&o.as_str()) {
+                        'X'
+                    } else {
+                        ' '
+                    };
+                    format!("- [{mark}] {o}")
+                })
+                .collect::<Vec<_>>()
+                .join("\n"))
+        }
+    }
+}
+
+fn canonical_option<'a>(field: &'a IssueFormField, item: &str) -> Result<&'a str, String> {
+    field
+        .options
+        .iter()
+        .find(|o| o.trim().eq_ignore_ascii_case(item))
+        .map(String::as_str)
+        .ok_or_else(|| {
+            format!(
+                "'{item}' is not an option of '{}' (options: {})",
+                field.key(),
+                field.options.join(", ")
+            )
+        })
+}
+
+fn template_id(path: &str) -> String {
+    let file = path.rsplit('/').next().unwrap_or(path);
+    file.rsplit_once('.')
+        .map_or(file, |(stem, _)| stem)
+        .to_string()
+}
+
+/// Parse `config.yml`. An empty file means the defaults.
+pub fn parse_issue_template_config(content: &str) -> Result<IssueTemplateConfig, GitHubError> {
+    if content.trim().is_empty() {
+        return Ok(IssueTemplateConfig::default());
+    }
+    serde_yaml::from_str(content)
+        .map_err(|e| GitHubError::InvalidInput(format!("Invalid issue template config: {e}")))
+}
+
+#[derive(Deserialize)]
+struct RawForm {
+    name: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default, deserialize_with = "string_list")]
+    labels: Vec<String>,
+    #[serde(default, deserialize_with = "string_list")]
+    assignees: Vec<String>,
+    #[serde(default)]
+    body: Vec<RawElement>,
+}
+
+#[derive(Deserialize)]
+struct RawMarkdownFront {
+    name: String,
+    #[serde(default)]
+    about: String,
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default, deserialize_with = "string_list")]
+    labels: Vec<String>,
+    #[serde(default, deserialize_with = "string_list")]
+    assignees: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct RawElement {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(default)]
+    attributes: RawAttributes,
+    #[serde(default)]
+    validations: RawValidations,
+}
+
+#[derive(Default, Deserialize)]
+struct RawAttributes {
+    #[serde(default)]
+    label: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    value: Option<String>,
+    #[serde(default)]
+    options: Vec<RawOption>,
+    #[serde(default)]
+    multiple: bool,
+    #[serde(default)]
+    render: Option<String>,
+    #[serde(default)]
+    default: Option<usize>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawOption {
+    Text(String),
+    Checkbox {
+        label: String,
+        #[serde(default)]
+        required: bool,
+    },
+}
+
+#[derive(Default, Deserialize)]
+struct RawValidations {
+    #[serde(default)]
+    required: bool,
+}
+
+impl RawElement {
+    fn into_field(self, path: &str, index: usize) -> Result<Option<IssueFormField>, GitHubError> {
+        let kind = match self.kind.as_str() {
+            "markdown" => return Ok(None),
+            "input" => IssueFormFieldKind::Input,
+            "textarea" => IssueFormFieldKind::Textarea,
+            "dropdown" => IssueFormFieldKind::Dropdown,
+            "checkboxes" => IssueFormFieldKind::Checkboxes,
+            other => {
+                return Err(GitHubError::InvalidInput(format!(
+                    "{path}: body[{index}] has unknown type '{other}'"
+                )));
+            }
+        };
+        let attrs = self.attributes;
+        let label = attrs
+            .label
+            .filter(|l| !l.trim().is_empty())
+            .ok_or_else(|| {
+                GitHubError::InvalidInput(format!("{path}: body[{index}] is missing a label"))
+            })?;
+
+        let mut options = Vec::new();
+        let mut required_options = Vec::new();
+        for option in attrs.options {
+            match option {
+                RawOption::Text(text) => options.push(text),
+                RawOption::Checkbox { label, required } => {
+                    if required {
+                        required_options.push(label.clone());
+                    }
+                    options.push(label);
+                }
+            }
+        }
+        if matches!(
+            kind,
+            IssueFormFieldKind::Dropdown | IssueFormFieldKind::Checkboxes
+        ) && options.is_empty()
+        {
+            return Err(GitHubError::InvalidInput(format!(
+                "{path}: '{label}' has no options"
+            )));
+        }
+
+        let default = match kind {
+            IssueFormFieldKind::Dropdown => attrs.default.and_then(|i| options.get(i).cloned()),
+            _ => attrs.value,
+        };
+
+        Ok(Some(IssueFormField {
+            id: self.id,
+            kind,
+            label,
+            description: attrs.description,
+            options,
+            required_options,
+            multiple: attrs.multiple,
+            render: attrs.render.filter(|r| !r.is_empty()),
+            default,
+            required: self.validations.required,
+        }))
+    }
+}
+
+/// Accept a YAML list or a comma-separated string.
+fn string_list<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match Option::<OneOrMany>::deserialize(deserializer)? {
+        None => Vec::new(),
+        Some(OneOrMany::One(s)) => s
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+            .collect(),
+        Some(OneOrMany::Many(items)) => items,
+    })
+}
+
+/// Request parameters for creating an issue from a template
+#[derive(Debug, Clone)]
+pub struct CreateIssueFromTemplateRequest {
+    /// Repository owner (user or organization)
+    pub owner: String,
+    /// Repository name
+    pub repo: String,
+    /// Template ID, file name or display name
+    pub template: String,
+    /// Issue title; the template's prefix is added when missing
+    pub title: String,
+    /// Form field values keyed by field ID or label
+    pub fields: BTreeMap<String, FieldValue>,
+    /// Body replacing a Markdown template's text (not allowed for forms)
+    pub body: Option<String>,
+    /// Labels added to the template's labels
+    pub labels: Option<Vec<String>>,
+    /// Assignees added to the template's assignees
+    pub assignees: Option<Vec<String>>,
+    /// Milestone number
+    pub milestone: Option<u64>,
+}
+
+/// Load every issue template and the chooser config of a repository.
+pub(crate) fn list_issue_templates(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+) -> AsyncTask<Result<IssueTemplates, GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+    spawn_task(async move { load_issue_templates(&inner, &owner, &repo).await })
+}
+
+/// Create an issue from a template, validating the field values.
+pub(crate) fn create_issue_from_template(
+    inner: Arc<Octocrab>,
+    request: CreateIssueFromTemplateRequest,
+) -> AsyncTask<Result<Issue, GitHubError>> {
+    spawn_task(async move {
+        let templates = load_issue_templates(&inner, &request.owner, &request.repo).await?;
+        let template = templates.find(&request.template).ok_or_else(|| {
+            let available = templates
+                .templates
+                .iter()
+                .map(|t| t.id.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            GitHubError::NotFound(format!(
+                "Issue template '{}' in {}/{} (available: {available})",
+                request.template, request.owner, request.repo
+            ))
+        })?;
+
+        let mut rendered = template.render(&request.title, &request.fields)?;
+        if let Some(body) = request.body {
+            if template.is_form() {
+                return Err(GitHubError::InvalidInput(format!(
+                    "Template '{}' is an issue form; pass field values instead of a body",
+                    template.id
+                )));
+            }
+            rendered.body = body;
+        }
+        for label in request.labels.unwrap_or_default() {
+            if !rendered.labels.contains(&label) {
+                rendered.labels.push(label);
+            }
+        }
+        for assignee in request.assignees.unwrap_or_default() {
+            if !rendered.assignees.contains(&assignee) {
+                rendered.assignees.push(assignee);
+            }
+        }
+
+        send_create_issue(
+            &inner,
+            &request.owner,
+            &request.repo,
+            rendered.title,
+            Some(rendered.body),
+            Some(rendered.assignees).filter(|a| !a.is_empty()),
+            Some(rendered.labels).filter(|l| !l.is_empty()),
+            request.milestone,
+        )
+        .await
+    })
+}
+
+async fn load_issue_templates(
+    inner: &Octocrab,
+    owner: &str,
+    repo: &str,
+) -> Result<IssueTemplates, GitHubError> {
+    let listing = match inner
+        .repos(owner, repo)
+        .get_content()
+        .path(ISSUE_TEMPLATE_DIR)
+        .send()
+        .await
+    {
+        Ok(listing) => listing,
+        Err(octocrab::Error::GitHub { source, .. }) if source.status_code.as_u16() == 404 => {
+            return Ok(IssueTemplates::default());
+        }
+        Err(e) => return Err(GitHubError::from(e)),
+    };
+
+    let mut paths: Vec<String> = listing
+        .items
+        .into_iter()
+        .filter(|c| c.r#type == "file")
+        .map(|c| c.path)
+        .collect();
+    paths.sort();
+
+    let mut result = IssueTemplates::default();
+    for path in paths {
+        let file = path.rsplit('/').next().unwrap_or(&path).to_lowercase();
+        let is_config = file == "config.yml" || file == "config.yaml";
+        if !is_config
+            && ![".yml", ".yaml", ".md"]
+                .iter()
+                .any(|ext| file.ends_with(ext))
+        {
+            continue;
+        }
+
+        let content = inner
+            .repos(owner, repo)
+            .get_content()
+            .path(&path)
+            .send()
+            .await
+            .map_err(GitHubError::from)?
+            .items
+            .into_iter()
+            .next()
+            .and_then(|c| c.decoded_content())
+            .unwrap_or_default();
+
+        let parsed = if is_config {
+            parse_issue_template_config(&content).map(|config| result.config = config)
+        } else {
+            IssueTemplate::parse(&path, &content).map(|t| result.templates.push(t))
+        };
+        if let Err(e) = parsed {
+            result.invalid.push(InvalidIssueTemplate {
+                path,
+                error: e.to_string(),
+            });
+        }
+    }
+    Ok(result)
+}
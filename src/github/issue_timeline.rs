@@ -0,0 +1,389 @@
+//! GitHub issue timeline retrieval.
+//!
+//! The timeline combines comments, label/assignee changes, cross-references
+//! and closing events in order, which is what is needed to reconstruct an
+//! issue's history and find the pull requests connected to it.
+
+use crate::github::error::GitHubError;
+use crate::runtime::{AsyncStream, EmitterBuilder};
+use chrono::{DateTime, Utc};
+use octocrab::{Octocrab, Page};
+use serde::Serialize;
+use serde_json::Value;
+use std::sync::Arc;
+
+/// What happened in a timeline event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TimelineEventKind {
+    /// A comment was posted.
+    Commented {
+        /// Comment ID.
+        comment_id: u64,
+        /// Comment body.
+        body: String,
+    },
+    /// A label was added.
+    Labeled {
+        /// Label name.
+        label: String,
+    },
+    /// A label was removed.
+    Unlabeled {
+        /// Label name.
+        label: String,
+    },
+    /// A user was assigned.
+    Assigned {
+        /// Assignee login.
+        assignee: String,
+    },
+    /// A user was unassigned.
+    Unassigned {
+        /// Assignee login.
+        assignee: String,
+    },
+    /// The issue was added to a milestone.
+    Milestoned {
+        /// Milestone title.
+        milestone: String,
+    },
+    /// The issue was removed from a milestone.
+    Demilestoned {
+        /// Milestone title.
+        milestone: String,
+    },
+    /// The title changed.
+    Renamed {
+        /// Previous title.
+        from: String,
+        /// New title.
+        to: String,
+    },
+    /// The issue was closed, by a commit when `commit_id` is set.
+    Closed {
+        /// Closing commit.
+        commit_id: Option<String>,
+        /// `completed`, `not_planned` or `duplicate`.
+        state_reason: Option<String>,
+    },
+    /// The issue was reopened.
+    Reopened,
+    /// Another issue or PR mentioned this one.
+    CrossReferenced {
+        /// Repository of the source, `owner/name`.
+        source_repo: Option<String>,
+        /// Number of the referencing issue or PR.
+        source_number: u64,
+        /// Title of the referencing issue or PR.
+        source_title: String,
+        /// Whether the source is a pull request.
+        is_pull_request: bool,
+        /// State of the source (`open` or `closed`).
+        source_state: Option<String>,
+        /// Whether the source was merged (pull requests only).
+        merged: bool,
+    },
+    /// A commit mentioned this issue.
+    Referenced {
+        /// Commit SHA.
+        commit_id: String,
+    },
+    /// A pull request was linked from the development sidebar.
+    Connected,
+    /// A linked pull request was unlinked.
+    Disconnected,
+    /// A commit was pushed (pull request timelines).
+    Committed {
+        /// Commit SHA.
+        sha: String,
+        /// Commit message.
+        message: String,
+    },
+    /// A review was submitted (pull request timelines).
+    Reviewed {
+        /// Review state.
+        state: String,
+    },
+    /// Conversation was locked.
+    Locked {
+        /// Lock reason.
+        reason: Option<String>,
+    },
+    /// Conversation was unlocked.
+    Unlocked,
+    /// Any other event, by its API name.
+    Other {
+        /// Event name as reported by GitHub.
+        event: String,
+    },
+}
+
+/// One entry of an issue timeline.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TimelineEvent {
+    /// Login of the user who caused the event.
+    pub actor: Option<String>,
+    /// When it happened.
+    pub created_at: Option<DateTime<Utc>>,
+    /// What happened.
+    #[serde(flatten)]
+    pub kind: TimelineEventKind,
+}
+
+impl TimelineEvent {
+    /// Parse a raw timeline entry from the REST API.
+    #[must_use]
+    pub fn from_value(value: &Value) -> Self {
+        let str_at = |pointer: &str| value.pointer(pointer).and_then(Value::as_str);
+        let string_at = |pointer: &str| str_at(pointer).unwrap_or_default().to_string();
+        let event = str_at("/event").unwrap_or_default();
+
+        let kind = match event {
+            "commented" => TimelineEventKind::Commented {
+                comment_id: value.get("id").and_then(Value::as_u64).unwrap_or_default(),
+                body: string_at("/body"),
+            },
+            "labeled" => TimelineEventKind::Labeled {
+                label: string_at("/label/name"),
+            },
+            "unlabeled" => TimelineEventKind::Unlabeled {
+                label: string_at("/label/name"),
+            },
+            "assigned" => TimelineEventKind::Assigned {
+                assignee: string_at("/assignee/login"),
+            },
+            "unassigned" => TimelineEventKind::Unassigned {
+                assignee: string_at("/assignee/login"),
+            },
+            "milestoned" => TimelineEventKind::Milestoned {
+                milestone: string_at("/milestone/title"),
+            },
+            "demilestoned" => TimelineEventKind::Demilestoned {
+                milestone: string_at("/milestone/title"),
+            },
+            "renamed" => TimelineEventKind::Renamed {
+                from: string_at("/rename/from"),
+                to: string_at("/rename/to"),
+            },
+            "closed" => TimelineEventKind::Closed {
+                commit_id: str_at("/commit_id").map(str::to_string),
+                state_reason: str_at("/state_reason").map(str::to_string),
+            },
+            "reopened" => TimelineEventKind::Reopened,
+            "cross-referenced" => TimelineEventKind::CrossReferenced {
+                source_repo: str_at("/source/issue/repository/full_name").map(str::to_string),
+                source_number: value
+                    .pointer("/source/issue/number")
+                    .and_then(Value::as_u64)
+                    .unwrap_or_default(),
+                source_title: string_at("/source/issue/title"),
+                is_pull_request: value
+                    .pointer("/source/issue/pull_request")
+                    .is_some_and(|pr| !pr.is_null()),
+                source_state: str_at("/source/issue/state").map(str::to_string),
+                merged: str_at("/source/issue/pull_request/merged_at").is_some(),
+            },
+            "referenced" => TimelineEventKind::Referenced {
+                commit_id: string_at("/commit_id"),
+            },
+            "connected" => TimelineEventKind::Connected,
+            "disconnected" => TimelineEventKind::Disconnected,
+            "committed" => TimelineEventKind::Committed {
+                sha: string_at("/sha"),
+                message: string_at("/message"),
+            },
+            "reviewed" => TimelineEventKind::Reviewed {
+                state: string_at("/state"),
+            },
+            "locked" => TimelineEventKind::Locked {
+                reason: str_at("/lock_reason").map(str::to_string),
+            },
+            "unlocked" => TimelineEventKind::Unlocked,
+            other => TimelineEventKind::Other {
+                event: other.to_string(),
+            },
+        };
+
+        // Comments and reviews carry `user`; commits carry a git author instead
+        let actor = str_at("/actor/login")
+            .or_else(|| str_at("/user/login"))
+            .or_else(|| str_at("/author/name"))
+            .map(str::to_string);
+        let created_at = str_at("/created_at")
+            .or_else(|| str_at("/submitted_at"))
+            .or_else(|| str_at("/committer/date"))
+            .and_then(|t| t.parse().ok());
+
+        Self {
+            actor,
+            created_at,
+            kind,
+        }
+    }
+
+    /// Snake-case name of the event kind, as used for filtering.
+    #[must_use]
+    pub fn kind_name(&self) -> &str {
+        match &self.kind {
+            TimelineEventKind::Commented { .. } => "commented",
+            TimelineEventKind::Labeled { .. } => "labeled",
+            TimelineEventKind::Unlabeled { .. } => "unlabeled",
+            TimelineEventKind::Assigned { .. } => "assigned",
+            TimelineEventKind::Unassigned { .. } => "unassigned",
+            TimelineEventKind::Milestoned { .. } => "milestoned",
+            TimelineEventKind::Demilestoned { .. } => "demilestoned",
+            TimelineEventKind::Renamed { .. } => "renamed",
+            TimelineEventKind::Closed { .. } => "closed",
+            TimelineEventKind::Reopened => "reopened",
+            TimelineEventKind::CrossReferenced { .. } => "cross_referenced",
+            TimelineEventKind::Referenced { .. } => "referenced",
+            TimelineEventKind::Connected => "connected",
+            TimelineEventKind::Disconnected => "disconnected",
+            TimelineEventKind::Committed { .. } => "committed",
+            TimelineEventKind::Reviewed { .. } => "reviewed",
+            TimelineEventKind::Locked { .. } => "locked",
+            TimelineEventKind::Unlocked => "unlocked",
+            TimelineEventKind::Other { event } => event,
+        }
+    }
+
+    /// One-line human readable description.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        let short = |sha: &str| sha[..7.min(sha.len())].to_string();
+        match &self.kind {
+            TimelineEventKind::Commented { body, .. } => {
+                format!("commented: {}", body.lines().next().unwrap_or_default())
+            }
+            TimelineEventKind::Labeled { label } => format!("added label '{label}'"),
+            TimelineEventKind::Unlabeled { label } => format!("removed label '{label}'"),
+            TimelineEventKind::Assigned { assignee } => format!("assigned {assignee}"),
+            TimelineEventKind::Unassigned { assignee } => format!("unassigned {assignee}"),
+            TimelineEventKind::Milestoned { milestone } => {
+                format!("added to milestone '{milestone}'")
+            }
+            TimelineEventKind::Demilestoned { milestone } => {
+                format!("removed from milestone '{milestone}'")
+            }
+            TimelineEventKind::Renamed { from, to } => format!("renamed '{from}' to '{to}'"),
+            TimelineEventKind::Closed {
+                commit_id,
+                state_reason,
+            } => {
+                let reason = state_reason
+                    .as_ref()
+                    .map(|r| format!(" as {r}"))
+                    .unwrap_or_default();
+                match commit_id {
+                    Some(sha) => format!("closed{reason} by commit {}", short(sha)),
+                    None => format!("closed{reason}"),
+                }
+            }
+            TimelineEventKind::Reopened => "reopened".to_string(),
+            TimelineEventKind::CrossReferenced {
+                source_repo,
+                source_number,
+                source_title,
+                is_pull_request,
+                ..
+            } => {
+                let kind = if *is_pull_request { "PR" } else { "issue" };
+                let repo = source_repo.as_deref().unwrap_or_default();
+                format!("referenced from {kind} {repo}#{source_number} '{source_title}'")
+            }
+            TimelineEventKind::Referenced { commit_id } => {
+                format!("referenced in commit {}", short(commit_id))
+            }
+            TimelineEventKind::Connected => "linked a pull request".to_string(),
+            TimelineEventKind::Disconnected => "unlinked a pull request".to_string(),
+            TimelineEventKind::Committed { sha, message } => format!(
+                "committed {} {}",
+                short(sha),
+                message.lines().next().unwrap_or_default()
+            ),
+            TimelineEventKind::Reviewed { state } => format!("reviewed ({state})"),
+            TimelineEventKind::Locked { reason } => match reason {
+                Some(r) => format!("locked as {r}"),
+                None => "locked".to_string(),
+            },
+            TimelineEventKind::Unlocked => "unlocked".to_string(),
+            TimelineEventKind::Other { event } => event.clone(),
+        }
+    }
+}
+
+/// A pull request that references an issue.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LinkedPullRequest {
+    /// Repository, `owner/name`.
+    pub repo: Option<String>,
+    /// PR number.
+    pub number: u64,
+    /// PR title.
+    pub title: String,
+    /// `open` or `closed`.
+    pub state: Option<String>,
+    /// Whether it was merged.
+    pub merged: bool,
+}
+
+/// Pull requests that cross-reference the issue, deduplicated, in timeline order.
+#[must_use]
+pub fn linked_pull_requests(events: &[TimelineEvent]) -> Vec<LinkedPullRequest> {
+    let mut linked: Vec<LinkedPullRequest> = Vec::new();
+    for event in events {
+        if let TimelineEventKind::CrossReferenced {
+            source_repo,
+            source_number,
+            source_title,
+            is_pull_request: true,
+            source_state,
+            merged,
+        } = &event.kind
+            && !linked
+                .iter()
+                .any(|pr| pr.number == *source_number && &pr.repo == source_repo)
+        {
+            linked.push(LinkedPullRequest {
+                repo: source_repo.clone(),
+                number: *source_number,
+                title: source_title.clone(),
+                state: source_state.clone(),
+                merged: *merged,
+            });
+        }
+    }
+    linked
+}
+
+/// Stream the timeline of an issue or pull request, oldest first.
+pub(crate) fn get_issue_timeline(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    issue_number: u64,
+) -> AsyncStream<Result<TimelineEvent, GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+
+    let builder = EmitterBuilder::new(Box::new(move || {
+        Box::pin(async move {
+            let mut page: Page<Value> = inner
+                .get(
+                    format!("/repos/{owner}/{repo}/issues/{issue_number}/timeline?per_page=100"),
+                    None::<&()>,
+                )
+                .await
+                .map_err(GitHubError::from)?;
+
+            let mut events: Vec<TimelineEvent> =
+                page.items.iter().map(TimelineEvent::from_value).collect();
+            while let Some(next) = inner.get_page::<Value>(&page.next).await? {
+                page = next;
+                events.extend(page.items.iter().map(TimelineEvent::from_value));
+            }
+            Ok(events)
+        })
+    }));
+    builder.emit(|v| v, |_| {})
+}
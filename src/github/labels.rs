@@ -0,0 +1,457 @@
+//! GitHub label management: repository label CRUD, per-issue labels and
+//! declarative label sync.
+
+use crate::github::error::GitHubError;
+use crate::github::util::{delete_no_content, spawn_task};
+use crate::runtime::{AsyncStream, AsyncTask, EmitterBuilder};
+use octocrab::{Octocrab, Page, models::Label};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Desired state of one label in a label spec file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LabelSpec {
+    /// Label name.
+    pub name: String,
+    /// Hex color, with or without a leading `#`.
+    pub color: String,
+    /// Optional description.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Former names. An existing label with one of these names is renamed,
+    /// which keeps it on the issues and PRs that already carry it.
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+/// Label spec file format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LabelSpecFormat {
+    /// YAML: a list of labels, or a mapping with a `labels` list.
+    Yaml,
+    /// TOML: a `[[labels]]` array of tables.
+    Toml,
+}
+
+impl LabelSpecFormat {
+    /// Guess the format from a file name's extension.
+    #[must_use]
+    pub fn from_path(path: &str) -> Option<Self> {
+        let ext = path.rsplit_once('.')?.1.to_lowercase();
+        match ext.as_str() {
+            "yml" | "yaml" => Some(Self::Yaml),
+            "toml" => Some(Self::Toml),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum LabelSpecDocument {
+    List(Vec<LabelSpec>),
+    Table { labels: Vec<LabelSpec> },
+}
+
+/// Parse and validate a label spec file.
+pub fn parse_label_spec(
+    content: &str,
+    format: LabelSpecFormat,
+) -> Result<Vec<LabelSpec>, GitHubError> {
+    let document: LabelSpecDocument = match format {
+        LabelSpecFormat::Yaml => serde_yaml::from_str(content)
+            .map_err(|e| GitHubError::InvalidInput(format!("Invalid label spec YAML: {e}")))?,
+        LabelSpecFormat::Toml => toml::from_str(content)
+            .map_err(|e| GitHubError::InvalidInput(format!("Invalid label spec TOML: {e}")))?,
+    };
+    let mut labels = match document {
+        LabelSpecDocument::List(labels) | LabelSpecDocument::Table { labels } => labels,
+    };
+
+    let mut seen: Vec<String> = Vec::new();
+    for label in &mut labels {
+        label.color = normalize_color(&label.color)?;
+        for name in std::iter::once(&label.name).chain(&label.aliases) {
+            let key = name.to_lowercase();
+            if seen.contains(&key) {
+                return Err(GitHubError::InvalidInput(format!(
+                    "Label name or alias '{name}' appears more than once in the spec"
+                )));
+            }
+            seen.push(key);
+        }
+    }
+    Ok(labels)
+}
+
+/// Strip a leading `#` and lowercase a 6-digit hex color.
+pub fn normalize_color(color: &str) -> Result<String, GitHubError> {
+    let hex = color.trim().trim_start_matches('#');
+    if hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        Ok(hex.to_lowercase())
+    } else {
+        Err(GitHubError::InvalidInput(format!(
+            "Invalid label color '{color}': expected 6 hex digits"
+        )))
+    }
+}
+
+/// Current state of a repository label, as far as syncing is concerned.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExistingLabel {
+    /// Label name.
+    pub name: String,
+    /// Hex color without `#`.
+    pub color: String,
+    /// Description, if set.
+    pub description: Option<String>,
+}
+
+impl From<&Label> for ExistingLabel {
+    fn from(label: &Label) -> Self {
+        Self {
+            name: label.name.clone(),
+            color: label.color.to_lowercase(),
+            description: label.description.clone().filter(|d| !d.is_empty()),
+        }
+    }
+}
+
+/// One step of a label sync.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum LabelChange {
+    /// Create a label that does not exist yet.
+    Create {
+        /// Label to create.
+        label: LabelSpec,
+    },
+    /// Update color/description, and rename when `current_name` differs.
+    Update {
+        /// Name the label has now.
+        current_name: String,
+        /// Desired state.
+        label: LabelSpec,
+    },
+    /// Delete a label missing from the spec (only when pruning).
+    Delete {
+        /// Label to delete.
+        name: String,
+    },
+}
+
+/// Compute the changes that bring `existing` labels in line with `spec`.
+///
+/// Names match case-insensitively, as on GitHub. A spec label whose name is
+/// missing but whose alias exists renames that label. With `prune`, labels
+/// neither named nor aliased in the spec are deleted.
+#[must_use]
+pub fn plan_label_sync(
+    existing: &[ExistingLabel],
+    spec: &[LabelSpec],
+    prune: bool,
+) -> Vec<LabelChange> {
+    let find = |name: &str| {
+        existing
+            .iter()
+            .position(|l| l.name.eq_ignore_ascii_case(name))
+    };
+    let mut claimed = vec![false; existing.len()];
+    let mut changes = Vec::new();
+
+    for label in spec {
+        let matched = find(&label.name)
+            .or_else(|| label.aliases.iter().find_map(|alias| find(alias)))
+            .filter(|&idx| !claimed[idx]);
+
+        let Some(idx) = matched else {
+            changes.push(LabelChange::Create {
+                label: label.clone(),
+            });
+            continue;
+        };
+        claimed[idx] = true;
+
+        let current = &existing[idx];
+        let description = label.description.clone().filter(|d| !d.is_empty());
+        if current.name != label.name
+            || current.color != label.color
+            || current.description != description
+        {
+            changes.push(LabelChange::Update {
+                current_name: current.name.clone(),
+                label: label.clone(),
+            });
+        }
+    }
+
+    if prune {
+        for (label, _) in existing.iter().zip(&claimed).filter(|(_, c)| !**c) {
+            changes.push(LabelChange::Delete {
+                name: label.name.clone(),
+            });
+        }
+    }
+    changes
+}
+
+/// Stream all labels of a repository.
+pub(crate) fn list_labels(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+) -> AsyncStream<Result<Label, GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+
+    let builder = EmitterBuilder::new(Box::new(move || {
+        Box::pin(async move { fetch_all_labels(&inner, &owner, &repo).await })
+    }));
+    builder.emit(|v| v, |_| {})
+}
+
+/// Create a repository label.
+pub(crate) fn create_label(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    name: impl Into<String>,
+    color: impl Into<String>,
+    description: Option<String>,
+) -> AsyncTask<Result<Label, GitHubError>> {
+    let (owner, repo, name, color) = (owner.into(), repo.into(), name.into(), color.into());
+
+    spawn_task(async move {
+        let spec = LabelSpec {
+            name,
+            color: normalize_color(&color)?,
+            description,
+            aliases: Vec::new(),
+        };
+        send_create(&inner, &owner, &repo, &spec).await
+    })
+}
+
+/// Update a repository label. `new_name` renames it in place.
+pub(crate) fn update_label(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    name: impl Into<String>,
+    new_name: Option<String>,
+    color: Option<String>,
+    description: Option<String>,
+) -> AsyncTask<Result<Label, GitHubError>> {
+    let (owner, repo, name) = (owner.into(), repo.into(), name.into());
+
+    spawn_task(async move {
+        let mut body = serde_json::json!({});
+        if let Some(n) = new_name {
+            body["new_name"] = serde_json::json!(n);
+        }
+        if let Some(c) = color {
+            body["color"] = serde_json::json!(normalize_color(&c)?);
+        }
+        if let Some(d) = description {
+            body["description"] = serde_json::json!(d);
+        }
+        send_update(&inner, &owner, &repo, &name, &body).await
+    })
+}
+
+/// Delete a repository label. It is removed from all issues and PRs.
+pub(crate) fn delete_label(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    name: impl Into<String>,
+) -> AsyncTask<Result<(), GitHubError>> {
+    let (owner, repo, name) = (owner.into(), repo.into(), name.into());
+    spawn_task(async move { send_delete(&inner, &owner, &repo, &name).await })
+}
+
+/// Add labels to an issue or PR, keeping the ones it already has.
+///
+/// Returns the full label set afterwards. Unknown labels are created by GitHub
+/// with a default color.
+pub(crate) fn add_labels(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    issue_number: u64,
+    labels: Vec<String>,
+) -> AsyncTask<Result<Vec<Label>, GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+
+    spawn_task(async move {
+        if labels.is_empty() {
+            return Err(GitHubError::InvalidInput(
+                "At least one label is required".to_string(),
+            ));
+        }
+        let body = serde_json::json!({ "labels": labels });
+        inner
+            .post(
+                format!("/repos/{owner}/{repo}/issues/{issue_number}/labels"),
+                Some(&body),
+            )
+            .await
+            .map_err(GitHubError::from)
+    })
+}
+
+/// Remove labels from an issue or PR, leaving the others in place.
+///
+/// Labels the issue does not carry are ignored. Returns the remaining labels.
+pub(crate) fn remove_labels(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    issue_number: u64,
+    labels: Vec<String>,
+) -> AsyncTask<Result<Vec<Label>, GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+
+    spawn_task(async move {
+        let mut remaining: Vec<Label> = inner
+            .get(
+                format!("/repos/{owner}/{repo}/issues/{issue_number}/labels?per_page=100"),
+                None::<&()>,
+            )
+            .await
+            .map_err(GitHubError::from)?;
+
+        for name in &labels {
+            let Some(current) = remaining.iter().find(|l| l.name.eq_ignore_ascii_case(name)) else {
+                continue;
+            };
+            let encoded = urlencoding::encode(&current.name);
+            remaining = inner
+                .delete(
+                    format!("/repos/{owner}/{repo}/issues/{issue_number}/labels/{encoded}"),
+                    None::<&()>,
+                )
+                .await
+                .map_err(GitHubError::from)?;
+        }
+        Ok(remaining)
+    })
+}
+
+/// Reconcile repository labels with `spec`.
+///
+/// With `dry_run` the planned changes are returned without applying them.
+pub(crate) fn sync_labels(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    spec: Vec<LabelSpec>,
+    prune: bool,
+    dry_run: bool,
+) -> AsyncTask<Result<Vec<LabelChange>, GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+
+    spawn_task(async move {
+        let existing: Vec<ExistingLabel> = fetch_all_labels(&inner, &owner, &repo)
+            .await?
+            .iter()
+            .map(ExistingLabel::from)
+            .collect();
+
+        let changes = plan_label_sync(&existing, &spec, prune);
+        if dry_run {
+            return Ok(changes);
+        }
+
+        for change in &changes {
+            match change {
+                LabelChange::Create { label } => {
+                    send_create(&inner, &owner, &repo, label).await?;
+                }
+                LabelChange::Update {
+                    current_name,
+                    label,
+                } => {
+                    let mut body = serde_json::json!({
+                        "color": label.color,
+                        "description": label.description.clone().unwrap_or_default(),
+                    });
+                    if current_name != &label.name {
+                        body["new_name"] = serde_json::json!(label.name);
+                    }
+                    send_update(&inner, &owner, &repo, current_name, &body).await?;
+                }
+                LabelChange::Delete { name } => {
+                    send_delete(&inner, &owner, &repo, name).await?;
+                }
+            }
+        }
+        Ok(changes)
+    })
+}
+
+async fn fetch_all_labels(
+    inner: &Octocrab,
+    owner: &str,
+    repo: &str,
+) -> Result<Vec<Label>, GitHubError> {
+    let mut page: Page<Label> = inner
+        .get(
+            format!("/repos/{owner}/{repo}/labels?per_page=100"),
+            None::<&()>,
+        )
+        .await
+        .map_err(GitHubError::from)?;
+
+    let mut labels = std::mem::take(&mut page.items);
+    while let Some(next) = inner.get_page::<Label>(&page.next).await? {
+        page = next;
+        labels.append(&mut page.items);
+    }
+    Ok(labels)
+}
+
+async fn send_create(
+    inner: &Octocrab,
+    owner: &str,
+    repo: &str,
+    label: &LabelSpec,
+) -> Result<Label, GitHubError> {
+    let mut body = serde_json::json!({
+        "name": label.name,
+        "color": label.color,
+    });
+    if let Some(d) = &label.description {
+        body["description"] = serde_json::json!(d);
+    }
+    inner
+        .post(format!("/repos/{owner}/{repo}/labels"), Some(&body))
+        .await
+        .map_err(GitHubError::from)
+}
+
+async fn send_update(
+    inner: &Octocrab,
+    owner: &str,
+    repo: &str,
+    name: &str,
+    body: &serde_json::Value,
+) -> Result<Label, GitHubError> {
+    let encoded = urlencoding::encode(name);
+    inner
+        .patch(
+            format!("/repos/{owner}/{repo}/labels/{encoded}"),
+            Some(body),
+        )
+        .await
+        .map_err(GitHubError::from)
+}
+
+async fn send_delete(
+    inner: &Octocrab,
+    owner: &str,
+    repo: &str,
+    name: &str,
+) -> Result<(), GitHubError> {
+    let encoded = urlencoding::encode(name);
+    delete_no_content(inner, format!("/repos/{owner}/{repo}/labels/{encoded}")).await
+}
@@ -0,0 +1,106 @@
+//! GitHub Pull Request commits listing operation.
+
+use crate::github::error::GitHubError;
+use crate::github::get_commit::fetch_commit;
+use crate::runtime::{AsyncStream, EmitterBuilder};
+use octocrab::{Octocrab, Page, models::repos::RepoCommit};
+use serde::Serialize;
+use std::sync::Arc;
+
+/// Most commits GitHub lists for a single pull request.
+pub const MAX_PULL_REQUEST_COMMITS: usize = 250;
+
+/// Summary of a pull request commit.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PullRequestCommit {
+    /// Commit SHA.
+    pub sha: String,
+    /// Full commit message.
+    pub message: String,
+    /// Git author name.
+    pub author_name: String,
+    /// Git author email.
+    pub author_email: String,
+    /// GitHub login of the author, if the email maps to an account.
+    pub author_login: Option<String>,
+    /// Author date (ISO 8601), empty if unknown.
+    pub date: String,
+    /// Link to the commit on GitHub.
+    pub html_url: String,
+    /// Lines added, only when stats were requested.
+    pub additions: Option<u64>,
+    /// Lines removed, only when stats were requested.
+    pub deletions: Option<u64>,
+    /// Changed paths, only when stats were requested.
+    pub files: Option<Vec<String>>,
+}
+
+impl From<&RepoCommit> for PullRequestCommit {
+    fn from(commit: &RepoCommit) -> Self {
+        let author = commit.commit.author.as_ref();
+        Self {
+            sha: commit.sha.clone(),
+            message: commit.commit.message.clone(),
+            author_name: author.map(|a| a.name.clone()).unwrap_or_default(),
+            author_email: author.and_then(|a| a.email.clone()).unwrap_or_default(),
+            author_login: commit.author.as_ref().map(|a| a.login.clone()),
+            date: author
+                .and_then(|a| a.date.as_ref())
+                .map(|d| d.to_rfc3339())
+                .unwrap_or_default(),
+            html_url: commit.html_url.clone(),
+            additions: commit.stats.as_ref().and_then(|s| s.additions),
+            deletions: commit.stats.as_ref().and_then(|s| s.deletions),
+            files: commit
+                .files
+                .as_ref()
+                .map(|files| files.iter().map(|f| f.filename.clone()).collect()),
+        }
+    }
+}
+
+/// Stream the commits on a PR, oldest first.
+///
+/// GitHub returns at most [`MAX_PULL_REQUEST_COMMITS`] commits for a pull request.
+/// With `include_stats` each commit is fetched like `get_commit` so `stats` and
+/// `files` are populated.
+pub(crate) fn list_pull_request_commits(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    pr_number: u64,
+    include_stats: bool,
+) -> AsyncStream<Result<RepoCommit, GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+
+    let builder = EmitterBuilder::new(Box::new(move || {
+        Box::pin(async move {
+            let mut commits = Vec::new();
+            let mut page: Page<RepoCommit> = inner
+                .get(
+                    format!("/repos/{owner}/{repo}/pulls/{pr_number}/commits?per_page=100"),
+                    None::<&()>,
+                )
+                .await
+                .map_err(GitHubError::from)?;
+
+            commits.extend(page.items);
+
+            while let Some(next) = inner.get_page::<RepoCommit>(&page.next).await? {
+                page = next;
+                commits.extend(page.items);
+            }
+
+            if include_stats {
+                let mut detailed = Vec::with_capacity(commits.len());
+                for commit in commits {
+                    detailed
+                        .push(fetch_commit(&inner, &owner, &repo, &commit.sha, None, None).await?);
+                }
+                commits = detailed;
+            }
+            Ok(commits)
+        })
+    }));
+    builder.emit(|v| v, |_| {})
+}
@@ -1,5 +1,6 @@
 //! GitHub Pull Request merge operation.
 
+use crate::github::check_merge_readiness::evaluate_merge_readiness;
 use crate::github::{error::GitHubError, util::spawn_task};
 use crate::runtime::AsyncTask;
 use octocrab::Octocrab;
@@ -16,6 +17,11 @@ pub struct MergePullRequestOptions {
     pub sha: Option<String>,
     /// Merge method to use: "merge", "squash", or "rebase".
     pub merge_method: Option<String>,
+    /// Verify mergeability, checks and reviews before merging.
+    ///
+    /// When enabled, `sha` is also compared against the current head locally so
+    /// a stale view fails with `GitHubError::MergeBlocked` rather than a 409.
+    pub safety_checks: bool,
 }
 
 /// Merge a pull request.
@@ -30,6 +36,20 @@ pub(crate) fn merge_pull_request(
     let repo = repo.into();
 
     spawn_task(async move {
+        if options.safety_checks {
+            let readiness = evaluate_merge_readiness(
+                &inner,
+                &owner,
+                &repo,
+                pull_number,
+                options.sha.as_deref(),
+            )
+            .await?;
+            if !readiness.is_ready() {
+                return Err(GitHubError::MergeBlocked(readiness.blockers));
+            }
+        }
+
         // Build the request body
         let mut body = serde_json::json!({});
 
@@ -0,0 +1,377 @@
+//! GitHub milestone management and progress reporting.
+
+use crate::github::error::GitHubError;
+use crate::github::util::{delete_no_content, spawn_task};
+use crate::runtime::{AsyncStream, AsyncTask, EmitterBuilder};
+use chrono::{DateTime, Days, NaiveDate, Utc};
+use octocrab::{
+    Octocrab, Page,
+    models::{IssueState, issues::Issue},
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Longest burndown series returned, in days.
+pub const MAX_BURNDOWN_DAYS: u64 = 366;
+
+/// A repository milestone.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Milestone {
+    /// Milestone number, used to assign issues.
+    pub number: u64,
+    /// Title.
+    pub title: String,
+    /// Description.
+    pub description: Option<String>,
+    /// `open` or `closed`.
+    pub state: String,
+    /// Open issues and PRs in the milestone.
+    #[serde(default)]
+    pub open_issues: u64,
+    /// Closed issues and PRs in the milestone.
+    #[serde(default)]
+    pub closed_issues: u64,
+    /// Due date.
+    pub due_on: Option<DateTime<Utc>>,
+    /// Creation time.
+    pub created_at: DateTime<Utc>,
+    /// When the milestone was closed.
+    pub closed_at: Option<DateTime<Utc>>,
+    /// Link to the milestone.
+    pub html_url: String,
+}
+
+/// Request parameters for creating a milestone
+#[derive(Debug, Clone)]
+pub struct CreateMilestoneRequest {
+    /// Repository owner (user or organization)
+    pub owner: String,
+    /// Repository name
+    pub repo: String,
+    /// Milestone title
+    pub title: String,
+    /// Milestone description
+    pub description: Option<String>,
+    /// Due date (ISO 8601, e.g. `2025-06-30T00:00:00Z`)
+    pub due_on: Option<String>,
+    /// Initial state (`open` or `closed`)
+    pub state: Option<String>,
+}
+
+/// Request parameters for updating a milestone
+#[derive(Debug, Clone)]
+pub struct UpdateMilestoneRequest {
+    /// Repository owner (user or organization)
+    pub owner: String,
+    /// Repository name
+    pub repo: String,
+    /// Milestone number
+    pub milestone_number: u64,
+    /// New title
+    pub title: Option<String>,
+    /// New description
+    pub description: Option<String>,
+    /// New due date (ISO 8601)
+    pub due_on: Option<String>,
+    /// New state (`open` or `closed`)
+    pub state: Option<String>,
+}
+
+/// An issue or PR assigned to a milestone.
+#[derive(Debug, Clone, Serialize)]
+pub struct MilestoneItem {
+    /// Issue or PR number.
+    pub number: u64,
+    /// Title.
+    pub title: String,
+    /// Whether this is a pull request.
+    pub is_pull_request: bool,
+    /// Whether it is still open.
+    pub open: bool,
+    /// Creation time.
+    pub created_at: DateTime<Utc>,
+    /// Close time, if closed.
+    pub closed_at: Option<DateTime<Utc>>,
+    /// Link to the issue or PR.
+    pub html_url: String,
+}
+
+impl From<Issue> for MilestoneItem {
+    fn from(issue: Issue) -> Self {
+        Self {
+            number: issue.number,
+            title: issue.title,
+            is_pull_request: issue.pull_request.is_some(),
+            open: issue.state == IssueState::Open,
+            created_at: issue.created_at,
+            closed_at: issue.closed_at,
+            html_url: issue.html_url.to_string(),
+        }
+    }
+}
+
+/// Open and closed item counts at the end of one day.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BurndownPoint {
+    /// Day (UTC).
+    pub date: NaiveDate,
+    /// Items open at the end of the day.
+    pub open: usize,
+    /// Items closed by the end of the day.
+    pub closed: usize,
+}
+
+/// Progress report for a milestone.
+#[derive(Debug, Clone, Serialize)]
+pub struct MilestoneProgress {
+    /// The milestone.
+    pub milestone: Milestone,
+    /// Open items.
+    pub open_count: usize,
+    /// Closed items.
+    pub closed_count: usize,
+    /// Share of closed items, 0-100.
+    pub percent_complete: f64,
+    /// Open items past the milestone's due date.
+    pub overdue: Vec<MilestoneItem>,
+    /// Daily open/closed counts from milestone creation to today.
+    pub burndown: Vec<BurndownPoint>,
+}
+
+impl MilestoneProgress {
+    /// Build a report from a milestone and all of its items as of `now`.
+    #[must_use]
+    pub fn build(milestone: Milestone, items: &[MilestoneItem], now: DateTime<Utc>) -> Self {
+        let open_count = items.iter().filter(|i| i.open).count();
+        let closed_count = items.len() - open_count;
+        let percent_complete = if items.is_empty() {
+            0.0
+        } else {
+            closed_count as f64 * 100.0 / items.len() as f64
+        };
+
+        let overdue = match milestone.due_on {
+            Some(due) if due < now => items.iter().filter(|i| i.open).cloned().collect(),
+            _ => Vec::new(),
+        };
+
+        let end = now.date_naive();
+        let start = milestone
+            .created_at
+            .date_naive()
+            .max(end - Days::new(MAX_BURNDOWN_DAYS - 1));
+        let burndown = burndown(items, start, end);
+
+        Self {
+            milestone,
+            open_count,
+            closed_count,
+            percent_complete,
+            overdue,
+            burndown,
+        }
+    }
+}
+
+/// Daily open/closed counts for `items` over `start..=end`.
+///
+/// An item counts from the day it was created; the API does not report when
+/// it was added to the milestone.
+#[must_use]
+pub fn burndown(items: &[MilestoneItem], start: NaiveDate, end: NaiveDate) -> Vec<BurndownPoint> {
+    start
+        .iter_days()
+        .take_while(|day| *day <= end)
+        .map(|date| {
+            let existing = items.iter().filter(|i| i.created_at.date_naive() <= date);
+            let (closed, open): (Vec<_>, Vec<_>) = existing.partition(|i| {
+                i.closed_at
+                    .is_some_and(|closed| !i.open && closed.date_naive() <= date)
+            });
+            BurndownPoint {
+                date,
+                open: open.len(),
+                closed: closed.len(),
+            }
+        })
+        .collect()
+}
+
+/// Stream the milestones of a repository. `state` is `open` (default),
+/// `closed` or `all`.
+pub(crate) fn list_milestones(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    state: Option<String>,
+) -> AsyncStream<Result<Milestone, GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+    let state = state.unwrap_or_else(|| "open".to_string());
+
+    let builder = EmitterBuilder::new(Box::new(move || {
+        Box::pin(async move {
+            let mut page: Page<Milestone> = inner
+                .get(
+                    format!("/repos/{owner}/{repo}/milestones?state={state}&per_page=100"),
+                    None::<&()>,
+                )
+                .await
+                .map_err(GitHubError::from)?;
+
+            let mut milestones = std::mem::take(&mut page.items);
+            while let Some(next) = inner.get_page::<Milestone>(&page.next).await? {
+                page = next;
+                milestones.append(&mut page.items);
+            }
+            Ok(milestones)
+        })
+    }));
+    builder.emit(|v| v, |_| {})
+}
+
+/// Get a single milestone.
+pub(crate) fn get_milestone(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    milestone_number: u64,
+) -> AsyncTask<Result<Milestone, GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+    spawn_task(async move { fetch_milestone(&inner, &owner, &repo, milestone_number).await })
+}
+
+/// Create a milestone.
+pub(crate) fn create_milestone(
+    inner: Arc<Octocrab>,
+    request: CreateMilestoneRequest,
+) -> AsyncTask<Result<Milestone, GitHubError>> {
+    spawn_task(async move {
+        let mut body = serde_json::json!({ "title": request.title });
+        if let Some(d) = request.description {
+            body["description"] = serde_json::json!(d);
+        }
+        if let Some(due) = request.due_on {
+            body["due_on"] = serde_json::json!(parse_due_on(&due)?);
+        }
+        if let Some(s) = request.state {
+            body["state"] = serde_json::json!(s);
+        }
+
+        let (owner, repo) = (&request.owner, &request.repo);
+        inner
+            .post(format!("/repos/{owner}/{repo}/milestones"), Some(&body))
+            .await
+            .map_err(GitHubError::from)
+    })
+}
+
+/// Update a milestone.
+pub(crate) fn update_milestone(
+    inner: Arc<Octocrab>,
+    request: UpdateMilestoneRequest,
+) -> AsyncTask<Result<Milestone, GitHubError>> {
+    spawn_task(async move {
+        let mut body = serde_json::json!({});
+        if let Some(t) = request.title {
+            body["title"] = serde_json::json!(t);
+        }
+        if let Some(d) = request.description {
+            body["description"] = serde_json::json!(d);
+        }
+        if let Some(due) = request.due_on {
+            body["due_on"] = serde_json::json!(parse_due_on(&due)?);
+        }
+        if let Some(s) = request.state {
+            body["state"] = serde_json::json!(s);
+        }
+
+        let (owner, repo, number) = (&request.owner, &request.repo, request.milestone_number);
+        inner
+            .patch(
+                format!("/repos/{owner}/{repo}/milestones/{number}"),
+                Some(&body),
+            )
+            .await
+            .map_err(GitHubError::from)
+    })
+}
+
+/// Delete a milestone. Its issues and PRs stay, without a milestone.
+pub(crate) fn delete_milestone(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    milestone_number: u64,
+) -> AsyncTask<Result<(), GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+    spawn_task(async move {
+        delete_no_content(
+            &inner,
+            format!("/repos/{owner}/{repo}/milestones/{milestone_number}"),
+        )
+        .await
+    })
+}
+
+/// Report progress of a milestone: counts, overdue items and burndown data.
+pub(crate) fn get_milestone_progress(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    milestone_number: u64,
+) -> AsyncTask<Result<MilestoneProgress, GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+
+    spawn_task(async move {
+        let milestone = fetch_milestone(&inner, &owner, &repo, milestone_number).await?;
+
+        // The issues endpoint includes pull requests
+        let mut page: Page<Issue> = inner
+            .get(
+                format!(
+                    "/repos/{owner}/{repo}/issues?milestone={milestone_number}&state=all&per_page=100"
+                ),
+                None::<&()>,
+            )
+            .await
+            .map_err(GitHubError::from)?;
+
+        let mut items: Vec<MilestoneItem> = Vec::new();
+        items.extend(page.items.drain(..).map(MilestoneItem::from));
+        while let Some(next) = inner.get_page::<Issue>(&page.next).await? {
+            page = next;
+            items.extend(page.items.drain(..).map(MilestoneItem::from));
+        }
+
+        Ok(MilestoneProgress::build(milestone, &items, Utc::now()))
+    })
+}
+
+async fn fetch_milestone(
+    inner: &Octocrab,
+    owner: &str,
+    repo: &str,
+    milestone_number: u64,
+) -> Result<Milestone, GitHubError> {
+    inner
+        .get(
+            format!("/repos/{owner}/{repo}/milestones/{milestone_number}"),
+            None::<&()>,
+        )
+        .await
+        .map_err(GitHubError::from)
+}
+
+/// Accept a full timestamp or a plain `YYYY-MM-DD` date.
+fn parse_due_on(due_on: &str) -> Result<String, GitHubError> {
+    if let Ok(ts) = DateTime::parse_from_rfc3339(due_on) {
+        return Ok(ts.with_timezone(&Utc).to_rfc3339());
+    }
+    NaiveDate::parse_from_str(due_on, "%Y-%m-%d")
+        .map(|d| format!("{d}T00:00:00Z"))
+        .map_err(|_| {
+            GitHubError::InvalidInput(format!(
+                "Invalid due date '{due_on}': expected YYYY-MM-DD or an ISO 8601 timestamp"
+            ))
+        })
+}
@@ -15,6 +15,15 @@ pub use util::spawn_task;
 
 // Re-export options types
 pub use add_pull_request_review_comment::AddPullRequestReviewCommentRequest;
+pub use bulk_issues::{
+    BulkIssueChange, BulkIssueReport, BulkIssueRequest, BulkIssueResult, BulkIssueStatus,
+    BulkIssueTarget, DEFAULT_BULK_CONCURRENCY, IssuePatch, IssueSnapshot, MAX_BULK_CONCURRENCY,
+    MAX_BULK_ISSUES, plan_issue_patch, scope_bulk_query,
+};
+pub use check_merge_readiness::{MergeBlocker, MergeReadiness};
+pub use codeowners::{CodeOwnerRule, CodeOwners, FileOwners, ReviewerSuggestion};
+pub use comments::CommentKind;
+pub use create_issue::{CreateIssueOutcome, CreateIssueRequest};
 pub use create_or_update_file::CreateOrUpdateFileRequest;
 pub use create_pull_request::CreatePullRequestRequest;
 pub use create_pull_request_review::CreatePullRequestReviewOptions;
@@ -22,35 +31,114 @@ pub use create_release::{
     CreateReleaseOptions, ReleaseResult, create_release, delete_release, get_release_by_tag,
     update_release,
 };
+pub use get_pull_request_diff::{
+    DiffHunk, DiffLine, DiffLineKind, DiffSide, FileDiff, FileDiffStatus, PullRequestDiff,
+};
 pub use get_pull_request_status::PullRequestStatus;
+pub use issue_export::{
+    CSV_COLUMNS, ExportCheckpoint, ExportFormat, ExportIssuesRequest, ExportSummary,
+    ExportedComment, ExportedIssue, checkpoint_path, merge_csv, merge_jsonl, render_csv,
+    render_jsonl, render_markdown_digest,
+};
+pub use issue_import::{
+    ColumnMapping, ImportFormat, ImportIssuesRequest, ImportReport, ImportStatus, ImportedIssue,
+    IssueDraft, derive_import_key, extract_import_key, import_marker, parse_csv_import,
+    parse_markdown_import, render_import_body,
+};
+pub use issue_moderation::{CloseReason, LockReason, TransferredIssue};
+pub use issue_templates::{
+    ContactLink, CreateIssueFromTemplateRequest, FieldValue, ISSUE_TEMPLATE_DIR,
+    InvalidIssueTemplate, IssueFormField, IssueFormFieldKind, IssueTemplate, IssueTemplateConfig,
+    IssueTemplates, NO_RESPONSE, RenderedIssue, parse_issue_template_config,
+};
+pub use issue_timeline::{
+    LinkedPullRequest, TimelineEvent, TimelineEventKind, linked_pull_requests,
+};
+pub use labels::{
+    ExistingLabel, LabelChange, LabelSpec, LabelSpecFormat, normalize_color, parse_label_spec,
+    plan_label_sync,
+};
 pub use list_commits::ListCommitsOptions;
 pub use list_issues::ListIssuesRequest;
+pub use list_pull_request_commits::{MAX_PULL_REQUEST_COMMITS, PullRequestCommit};
 pub use list_pull_requests::ListPullRequestsRequest;
 pub use merge_pull_request::MergePullRequestOptions;
+pub use milestones::{
+    BurndownPoint, CreateMilestoneRequest, MAX_BURNDOWN_DAYS, Milestone, MilestoneItem,
+    MilestoneProgress, UpdateMilestoneRequest, burndown,
+};
+pub use pending_review::PendingReviewComment;
+pub use pull_request_content::{
+    CommitSummary, LinkedIssue, PULL_REQUEST_TEMPLATE_DIRS, PULL_REQUEST_TEMPLATE_PATHS,
+    PullRequestContent, parse_linked_issues,
+};
+pub use pull_request_stack::{
+    STACK_SECTION_END, STACK_SECTION_START, StackEntry, StackEntryStatus,
+    SyncPullRequestStackRequest, render_stack_section, upsert_stack_section,
+};
+pub use reactions::{ReactionTarget, parse_reaction_content, reaction_name};
+pub use requested_reviewers::{missing_collaborators, team_slug, validate_login};
+pub use review_threads::{ReviewThread, ReviewThreadComment};
+pub use similar_issues::{
+    DEFAULT_DUPLICATE_THRESHOLD, FindSimilarIssuesRequest, IssueText, SimilarIssue,
+    SimilarityScore, build_search_queries, likely_duplicates, score_similarity, tokenize,
+};
+pub use sticky_comment::{
+    StickyCommentAction, StickyCommentResult, has_sticky_marker, render_sticky_body, sticky_marker,
+};
+pub use sub_issues::{
+    DependencyKind, IssueRef, IssueTreeNode, MAX_ISSUE_TREE_DEPTH, SubIssuePosition,
+};
+pub use suggested_change::SuggestedChange;
 pub use update_issue::UpdateIssueRequest;
 pub use update_pull_request::UpdatePullRequestOptions;
+pub use update_pull_request_branch::{UpdateBranchOutcome, UpdatePullRequestBranchOptions};
 
 // GitHub API operations - Issues (internal)
 pub(crate) mod add_issue_comment;
+pub(crate) mod bulk_issues;
+pub(crate) mod comments;
 pub(crate) mod create_issue;
 pub(crate) mod get_issue;
 pub(crate) mod get_issue_comments;
+pub(crate) mod issue_export;
+pub(crate) mod issue_import;
+pub(crate) mod issue_moderation;
+pub(crate) mod issue_templates;
+pub(crate) mod issue_timeline;
+pub(crate) mod labels;
 pub(crate) mod list_issues;
+pub(crate) mod milestones;
+pub(crate) mod reactions;
 pub(crate) mod search_issues;
+pub(crate) mod similar_issues;
+pub(crate) mod sticky_comment;
+pub(crate) mod sub_issues;
 pub(crate) mod update_issue;
 
 // GitHub API operations - Pull Requests (internal)
 pub(crate) mod add_pull_request_review_comment;
+pub(crate) mod check_merge_readiness;
+pub(crate) mod codeowners;
 pub(crate) mod create_pull_request;
 pub(crate) mod create_pull_request_review;
 pub(crate) mod get_pull_request_comments;
+pub(crate) mod get_pull_request_diff;
 pub(crate) mod get_pull_request_files;
 pub(crate) mod get_pull_request_reviews;
 pub(crate) mod get_pull_request_status;
+pub(crate) mod list_pull_request_commits;
 pub(crate) mod list_pull_requests;
 pub(crate) mod merge_pull_request;
+pub(crate) mod pending_review;
+pub(crate) mod pull_request_content;
+pub(crate) mod pull_request_stack;
 pub(crate) mod request_copilot_review;
+pub(crate) mod requested_reviewers;
+pub(crate) mod review_threads;
+pub(crate) mod suggested_change;
 pub(crate) mod update_pull_request;
+pub(crate) mod update_pull_request_branch;
 
 // GitHub API operations - Repositories (internal)
 pub(crate) mod create_branch;
@@ -65,6 +153,7 @@ pub(crate) mod list_branches;
 pub(crate) mod list_commits;
 pub(crate) mod push_files;
 pub(crate) mod search_code;
+pub(crate) mod search_query;
 pub mod search_repositories;
 pub(crate) mod upload_release_asset;
 
@@ -76,6 +165,12 @@ pub mod search_users;
 pub(crate) mod code_scanning_alerts;
 pub(crate) mod secret_scanning_alerts;
 
+// Re-export search query builder types
+pub use search_query::{
+    CodeSearchQuery, CodeSearchSort, DateRange, IssueKind, IssueSearchQuery, IssueSearchSort,
+    IssueSearchState, MAX_QUERY_LENGTH, QueryRange, quote_search_value,
+};
+
 // Re-export search_users types for public API
 pub use search_users::{SearchOrder, UserSearchSort};
 
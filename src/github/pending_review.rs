@@ -0,0 +1,257 @@
+//! GitHub Pull Request pending (draft) review workflow.
+//!
+//! A pending review collects inline comments over several calls and is only
+//! visible to its author until submitted with an event.
+
+use crate::github::error::GitHubError;
+use crate::github::get_pull_request_diff::DiffSide;
+use crate::github::util::{graphql, spawn_task};
+use crate::runtime::AsyncTask;
+use octocrab::{
+    Octocrab,
+    models::pulls::{Review, ReviewAction, ReviewState},
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Inline comment added to a pending review.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingReviewComment {
+    /// File path in the pull request.
+    pub path: String,
+    /// Comment body (markdown).
+    pub body: String,
+    /// Line (or last line of a range) the comment applies to.
+    pub line: u32,
+    /// Side of the diff `line` refers to.
+    pub side: DiffSide,
+    /// First line of a multi-line comment.
+    pub start_line: Option<u32>,
+    /// Side of `start_line`, defaults to `side`.
+    pub start_side: Option<DiffSide>,
+}
+
+/// Start a pending review, optionally seeded with a body.
+///
+/// Omitting the event is what keeps the review pending.
+pub(crate) fn create_pending_review(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    pr_number: u64,
+    body: Option<String>,
+    commit_id: Option<String>,
+) -> AsyncTask<Result<Review, GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+
+    spawn_task(async move {
+        let mut review_data = serde_json::json!({});
+        if let Some(b) = body {
+            review_data["body"] = serde_json::json!(b);
+        }
+        if let Some(cid) = commit_id {
+            review_data["commit_id"] = serde_json::json!(cid);
+        }
+
+        inner
+            .post(
+                format!("/repos/{owner}/{repo}/pulls/{pr_number}/reviews"),
+                Some(&review_data),
+            )
+            .await
+            .map_err(GitHubError::from)
+    })
+}
+
+const ADD_THREAD_MUTATION: &str = r"
+mutation($reviewId: ID!, $path: String!, $body: String!, $line: Int!, $side: DiffSide,
+         $startLine: Int, $startSide: DiffSide) {
+  addPullRequestReviewThread(input: {
+    pullRequestReviewId: $reviewId, path: $path, body: $body, line: $line, side: $side,
+    startLine: $startLine, startSide: $startSide
+  }) { thread { id } }
+}";
+
+/// Add an inline comment to a pending review. Returns the new thread's node ID.
+///
+/// REST cannot attach comments to an existing pending review, so this goes
+/// through GraphQL using the review's node ID.
+pub(crate) fn add_pending_review_comment(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    pr_number: u64,
+    review_id: u64,
+    comment: PendingReviewComment,
+) -> AsyncTask<Result<String, GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+
+    spawn_task(async move {
+        let review = get_review(&inner, &owner, &repo, pr_number, review_id).await?;
+        if review.state != Some(ReviewState::Pending) {
+            return Err(GitHubError::InvalidInput(format!(
+                "Review {review_id} on {owner}/{repo}#{pr_number} is not pending"
+            )));
+        }
+
+        let start_line = comment.start_line.filter(|&s| s != comment.line);
+        let data: serde_json::Value = graphql(
+            &inner,
+            ADD_THREAD_MUTATION,
+            serde_json::json!({
+                "reviewId": review.node_id,
+                "path": comment.path,
+                "body": comment.body,
+                "line": comment.line,
+                "side": comment.side.as_str(),
+                "startLine": start_line,
+                "startSide": start_line
+                    .map(|_| comment.start_side.unwrap_or(comment.side).as_str()),
+            }),
+        )
+        .await?;
+
+        data.pointer("/addPullRequestReviewThread/thread/id")
+            .and_then(serde_json::Value::as_str)
+            .map(str::to_string)
+            .ok_or_else(|| {
+                GitHubError::Api(format!(
+                    "GitHub did not create a thread at {}:{}",
+                    comment.path, comment.line
+                ))
+            })
+    })
+}
+
+/// Submit a pending review with an event (APPROVE, `REQUEST_CHANGES`, COMMENT).
+pub(crate) fn submit_pending_review(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    pr_number: u64,
+    review_id: u64,
+    event: ReviewAction,
+    body: Option<String>,
+) -> AsyncTask<Result<Review, GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+
+    spawn_task(async move {
+        let mut review_data = serde_json::json!({
+            "event": event,
+        });
+        if let Some(b) = body {
+            review_data["body"] = serde_json::json!(b);
+        }
+
+        inner
+            .post(
+                format!("/repos/{owner}/{repo}/pulls/{pr_number}/reviews/{review_id}/events"),
+                Some(&review_data),
+            )
+            .await
+            .map_err(GitHubError::from)
+    })
+}
+
+/// Discard a pending review and all of its comments.
+pub(crate) fn delete_pending_review(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    pr_number: u64,
+    review_id: u64,
+) -> AsyncTask<Result<Review, GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+
+    spawn_task(async move {
+        inner
+            .delete(
+                format!("/repos/{owner}/{repo}/pulls/{pr_number}/reviews/{review_id}"),
+                None::<&()>,
+            )
+            .await
+            .map_err(GitHubError::from)
+    })
+}
+
+/// Dismiss a submitted review. Requires admin or maintain access when the
+/// branch is protected.
+pub(crate) fn dismiss_pull_request_review(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    pr_number: u64,
+    review_id: u64,
+    message: impl Into<String>,
+) -> AsyncTask<Result<Review, GitHubError>> {
+    let (owner, repo, message) = (owner.into(), repo.into(), message.into());
+
+    spawn_task(async move {
+        let body = serde_json::json!({
+            "message": message,
+            "event": "DISMISS",
+        });
+
+        inner
+            .put(
+                format!("/repos/{owner}/{repo}/pulls/{pr_number}/reviews/{review_id}/dismissals"),
+                Some(&body),
+            )
+            .await
+            .map_err(GitHubError::from)
+    })
+}
+
+/// Find the authenticated user's pending review on a PR, if any.
+///
+/// GitHub allows at most one pending review per user and pull request.
+pub(crate) fn find_pending_review(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    pr_number: u64,
+) -> AsyncTask<Result<Option<Review>, GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+
+    spawn_task(async move {
+        let viewer = inner.current().user().await.map_err(GitHubError::from)?;
+
+        let mut page = inner
+            .pulls(&owner, &repo)
+            .list_reviews(pr_number)
+            .per_page(100)
+            .send()
+            .await
+            .map_err(GitHubError::from)?;
+
+        loop {
+            let pending = page.items.into_iter().find(|r| {
+                r.state == Some(ReviewState::Pending)
+                    && r.user.as_ref().is_some_and(|u| u.id == viewer.id)
+            });
+            if pending.is_some() {
+                return Ok(pending);
+            }
+            match inner.get_page::<Review>(&page.next).await? {
+                Some(next) => page = next,
+                None => return Ok(None),
+            }
+        }
+    })
+}
+
+async fn get_review(
+    inner: &Octocrab,
+    owner: &str,
+    repo: &str,
+    pr_number: u64,
+    review_id: u64,
+) -> Result<Review, GitHubError> {
+    inner
+        .get(
+            format!("/repos/{owner}/{repo}/pulls/{pr_number}/reviews/{review_id}"),
+            None::<&()>,
+        )
+        .await
+        .map_err(GitHubError::from)
+}
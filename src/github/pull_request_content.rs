@@ -0,0 +1,458 @@
+//! Pull request title/body generation from commits and the repository's template.
+
+use crate::github::{error::GitHubError, util::spawn_task};
+use crate::runtime::AsyncTask;
+use lazy_static::lazy_static;
+use octocrab::{
+    Octocrab,
+    models::repos::{DiffEntry, RepoCommit},
+};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// Single-file template locations GitHub recognizes, in precedence order.
+pub const PULL_REQUEST_TEMPLATE_PATHS: [&str; 6] = [
+    ".github/pull_request_template.md",
+    ".github/PULL_REQUEST_TEMPLATE.md",
+    "pull_request_template.md",
+    "PULL_REQUEST_TEMPLATE.md",
+    "docs/pull_request_template.md",
+    "docs/PULL_REQUEST_TEMPLATE.md",
+];
+
+/// Directories holding multiple named templates, in precedence order.
+pub const PULL_REQUEST_TEMPLATE_DIRS: [&str; 3] = [
+    ".github/PULL_REQUEST_TEMPLATE",
+    "PULL_REQUEST_TEMPLATE",
+    "docs/PULL_REQUEST_TEMPLATE",
+];
+
+lazy_static! {
+    static ref CLOSING_REF_RE: Result<Regex, regex::Error> = Regex::new(
+        r"(?i)\b(close[sd]?|fix(?:e[sd])?|resolve[sd]?)\b:?\s+((?:[\w.-]+/[\w.-]+)?#\d+)"
+    );
+}
+
+/// An issue a commit message says it closes (`Fixes #123`, `closes org/repo#4`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LinkedIssue {
+    /// Closing keyword as written, lowercased (`fixes`, `closes`, ...).
+    pub keyword: String,
+    /// Issue reference: `#123` or `owner/repo#123`.
+    pub reference: String,
+}
+
+/// Parse closing issue references from a commit message, in order, without duplicates.
+#[must_use]
+pub fn parse_linked_issues(message: &str) -> Vec<LinkedIssue> {
+    let Ok(re) = CLOSING_REF_RE.as_ref() else {
+        return Vec::new();
+    };
+
+    let mut issues: Vec<LinkedIssue> = Vec::new();
+    for caps in re.captures_iter(message) {
+        let reference = caps[2].to_string();
+        if !issues.iter().any(|i| i.reference == reference) {
+            issues.push(LinkedIssue {
+                keyword: caps[1].to_lowercase(),
+                reference,
+            });
+        }
+    }
+    issues
+}
+
+/// Short description of one commit in the compare range.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitSummary {
+    /// Full commit SHA.
+    pub sha: String,
+    /// First line of the commit message.
+    pub subject: String,
+}
+
+/// Generated pull request content.
+#[derive(Debug, Clone, Serialize)]
+pub struct PullRequestContent {
+    /// Suggested title.
+    pub title: String,
+    /// Rendered body, based on the template when one was found.
+    pub body: String,
+    /// Template file used, if any.
+    pub template_path: Option<String>,
+    /// Commits in the compare range, oldest first.
+    pub commits: Vec<CommitSummary>,
+    /// Issues the commits close.
+    pub linked_issues: Vec<LinkedIssue>,
+    /// Changed paths grouped by top-level directory (`""` for root files).
+    pub path_groups: BTreeMap<String, Vec<String>>,
+}
+
+impl PullRequestContent {
+    /// Build title and body from commits, changed paths and an optional template.
+    #[must_use]
+    pub fn build(
+        head_branch: &str,
+        commits: Vec<CommitSummary>,
+        messages: &[String],
+        paths: Vec<String>,
+        template: Option<(String, String)>,
+    ) -> Self {
+        let mut linked_issues: Vec<LinkedIssue> = Vec::new();
+        for issue in messages.iter().flat_map(|m| parse_linked_issues(m)) {
+            if !linked_issues.iter().any(|i| i.reference == issue.reference) {
+                linked_issues.push(issue);
+            }
+        }
+
+        let mut path_groups: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for path in paths {
+            let group = match path.split_once('/') {
+                Some((dir, _)) => dir.to_string(),
+                None => String::new(),
+            };
+            path_groups.entry(group).or_default().push(path);
+        }
+
+        let title = match commits.as_slice() {
+            [only] => only.subject.clone(),
+            _ => title_from_branch(head_branch),
+        };
+
+        let sections = [
+            (Section::Commits, render_commits(&commits)),
+            (Section::Issues, render_issues(&linked_issues)),
+            (Section::Paths, render_paths(&path_groups)),
+        ];
+        let (template_path, body) = match template {
+            Some((path, content)) => (Some(path), fill_template(&content, &sections)),
+            None => (None, fill_template("", &sections)),
+        };
+
+        Self {
+            title,
+            body,
+            template_path,
+            commits,
+            linked_issues,
+            path_groups,
+        }
+    }
+}
+
+/// Turn `feature/add-login_form` into `Add login form`.
+fn title_from_branch(branch: &str) -> String {
+    let name = branch.rsplit(['/', ':']).next().unwrap_or(branch);
+    let words = name.replace(['-', '_'], " ");
+    let mut chars = words.trim().chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => branch.to_string(),
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Section {
+    Commits,
+    Issues,
+    Paths,
+}
+
+impl Section {
+    /// `{{placeholder}}` a template can use to position the section explicitly.
+    fn placeholder(self) -> &'static str {
+        match self {
+            Self::Commits => "{{commits}}",
+            Self::Issues => "{{linked_issues}}",
+            Self::Paths => "{{changed_paths}}",
+        }
+    }
+
+    /// Heading words that mark where the section belongs in a template.
+    fn heading_keywords(self) -> &'static [&'static str] {
+        match self {
+            Self::Commits => &["description", "summary", "what"],
+            Self::Issues => &["issue", "related", "fixes", "closes"],
+            Self::Paths => &["changes", "changed", "files"],
+        }
+    }
+
+    /// Heading used when the template has no matching spot.
+    fn default_heading(self) -> &'static str {
+        match self {
+            Self::Commits => "## Commits",
+            Self::Issues => "## Linked issues",
+            Self::Paths => "## Changed paths",
+        }
+    }
+}
+
+fn render_commits(commits: &[CommitSummary]) -> String {
+    commits
+        .iter()
+        .map(|c| format!("- {} {}", &c.sha[..7.min(c.sha.len())], c.subject))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn render_issues(issues: &[LinkedIssue]) -> String {
+    issues
+        .iter()
+        .map(|i| {
+            format!(
+                "{}{} {}",
+                i.keyword[..1].to_uppercase(),
+                &i.keyword[1..],
+                i.reference
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn render_paths(groups: &BTreeMap<String, Vec<String>>) -> String {
+    groups
+        .iter()
+        .map(|(group, paths)| {
+            let label = if group.is_empty() {
+                "(root)".to_string()
+            } else {
+                format!("{group}/")
+            };
+            let files = paths
+                .iter()
+                .map(|p| format!("`{p}`"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("- **{label}** ({}): {files}", paths.len())
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Place each non-empty section at its placeholder, else under the first
+/// matching template heading, else append it under a default heading.
+fn fill_template(template: &str, sections: &[(Section, String)]) -> String {
+    let mut lines: Vec<String> = template.lines().map(str::to_string).collect();
+    let mut used_headings = Vec::new();
+    let mut appended = Vec::new();
+
+    for (section, content) in sections {
+        let placeholder = section.placeholder();
+        if lines.iter().any(|l| l.contains(placeholder)) {
+            for line in &mut lines {
+                *line = line.replace(placeholder, content);
+            }
+            continue;
+        }
+        if content.is_empty() {
+            continue;
+        }
+
+        let heading = lines.iter().enumerate().position(|(idx, line)| {
+            let text = line.trim_start();
+            text.starts_with('#')
+                && !used_headings.contains(&idx)
+                && section
+                    .heading_keywords()
+                    .iter()
+                    .any(|k| text.to_lowercase().contains(k))
+        });
+
+        match heading {
+            Some(idx) => {
+                used_headings.push(idx);
+                let at = skip_comment_block(&lines, idx + 1);
+                lines.insert(at, content.clone());
+                // Keep indices of later headings valid for the next sections
+                for used in &mut used_headings {
+                    if *used >= at {
+                        *used += 1;
+                    }
+                }
+            }
+            None => appended.push(format!("{}\n\n{content}", section.default_heading())),
+        }
+    }
+
+    let mut body = lines.join("\n").trim_end().to_string();
+    for block in appended {
+        if !body.is_empty() {
+            body.push_str("\n\n");
+        }
+        body.push_str(&block);
+    }
+    body
+}
+
+/// Index after any blank lines and an HTML comment directly below a heading.
+fn skip_comment_block(lines: &[String], mut idx: usize) -> usize {
+    while idx < lines.len() && lines[idx].trim().is_empty() {
+        idx += 1;
+    }
+    if idx < lines.len() && lines[idx].trim_start().starts_with("<!--") {
+        while idx < lines.len() && !lines[idx].contains("-->") {
+            idx += 1;
+        }
+        idx += 1;
+    }
+    idx.min(lines.len())
+}
+
+#[derive(Deserialize)]
+struct Comparison {
+    commits: Vec<RepoCommit>,
+    #[serde(default)]
+    files: Vec<DiffEntry>,
+}
+
+/// Generate a PR title and body for merging `head` into `base`.
+///
+/// `template` picks a named file from a `PULL_REQUEST_TEMPLATE/` directory;
+/// otherwise the single-file template is used, falling back to the first file
+/// in a template directory.
+pub(crate) fn generate_pull_request_content(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    base: impl Into<String>,
+    head: impl Into<String>,
+    template: Option<String>,
+) -> AsyncTask<Result<PullRequestContent, GitHubError>> {
+    let (owner, repo, base, head) = (owner.into(), repo.into(), base.into(), head.into());
+    spawn_task(async move {
+        build_pull_request_content(&inner, &owner, &repo, &base, &head, template.as_deref()).await
+    })
+}
+
+pub(crate) async fn build_pull_request_content(
+    inner: &Octocrab,
+    owner: &str,
+    repo: &str,
+    base: &str,
+    head: &str,
+    template: Option<&str>,
+) -> Result<PullRequestContent, GitHubError> {
+    let comparison: Comparison = inner
+        .get(
+            format!("/repos/{owner}/{repo}/compare/{base}...{head}"),
+            None::<&()>,
+        )
+        .await
+        .map_err(GitHubError::from)?;
+
+    let messages: Vec<String> = comparison
+        .commits
+        .iter()
+        .map(|c| c.commit.message.clone())
+        .collect();
+    let commits = comparison
+        .commits
+        .iter()
+        .map(|c| CommitSummary {
+            sha: c.sha.clone(),
+            subject: c.commit.message.lines().next().unwrap_or("").to_string(),
+        })
+        .collect();
+    let paths = comparison.files.into_iter().map(|f| f.filename).collect();
+
+    let template = fetch_pull_request_template(inner, owner, repo, base, template).await?;
+
+    Ok(PullRequestContent::build(
+        head, commits, &messages, paths, template,
+    ))
+}
+
+/// Find and download the PR template at `reference`. Returns `(path, content)`.
+async fn fetch_pull_request_template(
+    inner: &Octocrab,
+    owner: &str,
+    repo: &str,
+    reference: &str,
+    name: Option<&str>,
+) -> Result<Option<(String, String)>, GitHubError> {
+    if let Some(name) = name {
+        let file = if name.contains('.') {
+            name.to_string()
+        } else {
+            format!("{name}.md")
+        };
+        for dir in PULL_REQUEST_TEMPLATE_DIRS {
+            let path = format!("{dir}/{file}");
+            if let Some(content) = fetch_file(inner, owner, repo, reference, &path).await? {
+                return Ok(Some((path, content)));
+            }
+        }
+        return Err(GitHubError::NotFound(format!(
+            "pull request template '{name}' in {owner}/{repo}"
+        )));
+    }
+
+    for path in PULL_REQUEST_TEMPLATE_PATHS {
+        if let Some(content) = fetch_file(inner, owner, repo, reference, path).await? {
+            return Ok(Some((path.to_string(), content)));
+        }
+    }
+
+    for dir in PULL_REQUEST_TEMPLATE_DIRS {
+        let listing = match inner
+            .repos(owner, repo)
+            .get_content()
+            .path(dir)
+            .r#ref(reference)
+            .send()
+            .await
+        {
+            Ok(listing) => listing,
+            Err(octocrab::Error::GitHub { source, .. }) if source.status_code.as_u16() == 404 => {
+                continue;
+            }
+            Err(e) => return Err(GitHubError::from(e)),
+        };
+
+        let mut files: Vec<String> = listing
+            .items
+            .into_iter()
+            .filter(|c| c.r#type == "file" && c.name.to_lowercase().ends_with(".md"))
+            .map(|c| c.path)
+            .collect();
+        files.sort();
+
+        if let Some(path) = files.into_iter().next()
+            && let Some(content) = fetch_file(inner, owner, repo, reference, &path).await?
+        {
+            return Ok(Some((path, content)));
+        }
+    }
+
+    Ok(None)
+}
+
+async fn fetch_file(
+    inner: &Octocrab,
+    owner: &str,
+    repo: &str,
+    reference: &str,
+    path: &str,
+) -> Result<Option<String>, GitHubError> {
+    match inner
+        .repos(owner, repo)
+        .get_content()
+        .path(path)
+        .r#ref(reference)
+        .send()
+        .await
+    {
+        Ok(contents) => Ok(contents
+            .items
+            .into_iter()
+            .next()
+            .and_then(|c| c.decoded_content())),
+        Err(octocrab::Error::GitHub { source, .. }) if source.status_code.as_u16() == 404 => {
+            Ok(None)
+        }
+        Err(e) => Err(GitHubError::from(e)),
+    }
+}
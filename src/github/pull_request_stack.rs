@@ -0,0 +1,260 @@
+//! Stacked pull request management.
+//!
+//! A stack is a chain of branches where each one builds on the previous:
+//! `trunk <- feature-1 <- feature-2 <- ...`. Each branch gets a PR whose base is
+//! the nearest lower branch that has not been merged yet.
+
+use crate::github::{error::GitHubError, get_commit::fetch_commit, util::spawn_task};
+use crate::runtime::AsyncTask;
+use octocrab::{
+    Octocrab,
+    models::{IssueState, pulls::PullRequest},
+    params,
+};
+use serde::Serialize;
+use std::sync::Arc;
+
+/// Start marker of the generated stack navigation section in PR bodies.
+pub const STACK_SECTION_START: &str = "<!-- stack:start -->";
+/// End marker of the generated stack navigation section in PR bodies.
+pub const STACK_SECTION_END: &str = "<!-- stack:end -->";
+
+/// Request parameters for syncing a PR stack
+#[derive(Debug, Clone)]
+pub struct SyncPullRequestStackRequest {
+    /// Repository owner (user or organization)
+    pub owner: String,
+    /// Repository name
+    pub repo: String,
+    /// Branch the bottom of the stack merges into
+    pub trunk: String,
+    /// Stack branches, bottom first. Branches must live in this repository.
+    pub branches: Vec<String>,
+    /// Create missing PRs as drafts
+    pub draft: bool,
+    /// Render the stack navigation section into each open PR body
+    pub update_bodies: bool,
+}
+
+/// What syncing did to one branch of the stack.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum StackEntryStatus {
+    /// A new PR was opened for the branch.
+    Created,
+    /// The PR's base was changed, typically because a lower PR merged.
+    Retargeted {
+        /// Previous base branch.
+        from: String,
+    },
+    /// The PR already had the right base.
+    UpToDate,
+    /// The PR was merged; branches above it now build on its base.
+    Merged,
+    /// The PR was closed without merging and is skipped.
+    Closed,
+}
+
+/// One branch of a synced stack.
+#[derive(Debug, Clone, Serialize)]
+pub struct StackEntry {
+    /// Head branch.
+    pub branch: String,
+    /// PR number.
+    pub pr_number: u64,
+    /// PR title.
+    pub title: String,
+    /// Base branch after syncing.
+    pub base: String,
+    /// Link to the PR.
+    pub html_url: Option<String>,
+    /// What syncing did.
+    #[serde(flatten)]
+    pub status: StackEntryStatus,
+}
+
+impl StackEntry {
+    /// Whether the PR is still open (created, retargeted or up to date).
+    #[must_use]
+    pub fn is_open(&self) -> bool {
+        !matches!(
+            self.status,
+            StackEntryStatus::Merged | StackEntryStatus::Closed
+        )
+    }
+}
+
+/// Render the navigation section for the PR `current` within `entries`.
+///
+/// The stack is listed top first, as on GitHub's own stacked PR tooling.
+#[must_use]
+pub fn render_stack_section(entries: &[StackEntry], current: u64) -> String {
+    let mut section = format!("{STACK_SECTION_START}\n**Stack** (top to bottom):\n\n");
+    for entry in entries.iter().rev() {
+        let marker = if entry.pr_number == current {
+            "👉 "
+        } else {
+            ""
+        };
+        let suffix = match entry.status {
+            StackEntryStatus::Merged => " ✅ merged",
+            StackEntryStatus::Closed => " ❌ closed",
+            _ => "",
+        };
+        section.push_str(&format!("- {marker}#{}{suffix}\n", entry.pr_number));
+    }
+    section.push_str(STACK_SECTION_END);
+    section
+}
+
+/// Replace the stack section in `body`, or append it if there is none.
+#[must_use]
+pub fn upsert_stack_section(body: &str, section: &str) -> String {
+    if let Some(start) = body.find(STACK_SECTION_START)
+        && let Some(end_rel) = body[start..].find(STACK_SECTION_END)
+    {
+        let end = start + end_rel + STACK_SECTION_END.len();
+        return format!("{}{section}{}", &body[..start], &body[end..]);
+    }
+
+    let trimmed = body.trim_end();
+    if trimmed.is_empty() {
+        section.to_string()
+    } else {
+        format!("{trimmed}\n\n{section}")
+    }
+}
+
+/// Create or retarget PRs so each branch in the stack targets the one below it,
+/// then optionally render stack navigation into every open PR.
+pub(crate) fn sync_pull_request_stack(
+    inner: Arc<Octocrab>,
+    request: SyncPullRequestStackRequest,
+) -> AsyncTask<Result<Vec<StackEntry>, GitHubError>> {
+    spawn_task(async move {
+        if request.branches.is_empty() {
+            return Err(GitHubError::InvalidInput(
+                "A stack needs at least one branch".to_string(),
+            ));
+        }
+
+        let (owner, repo) = (&request.owner, &request.repo);
+        let pulls = inner.pulls(owner, repo);
+        let mut entries = Vec::with_capacity(request.branches.len());
+        let mut prs = Vec::with_capacity(request.branches.len());
+        let mut base = request.trunk.clone();
+
+        for branch in &request.branches {
+            let existing = find_branch_pull_request(&inner, owner, repo, branch).await?;
+
+            let (pr, status) = match existing {
+                Some(pr) if pr.merged_at.is_some() => (pr, StackEntryStatus::Merged),
+                Some(pr) if pr.state != Some(IssueState::Open) => (pr, StackEntryStatus::Closed),
+                Some(pr) if pr.base.ref_field != base => {
+                    let from = pr.base.ref_field.clone();
+                    let pr = pulls
+                        .update(pr.number)
+                        .base(&base)
+                        .send()
+                        .await
+                        .map_err(GitHubError::from)?;
+                    (pr, StackEntryStatus::Retargeted { from })
+                }
+                Some(pr) => (pr, StackEntryStatus::UpToDate),
+                None => {
+                    let title = head_commit_title(&inner, owner, repo, branch)
+                        .await?
+                        .unwrap_or_else(|| branch.clone());
+                    let pr = pulls
+                        .create(&title, branch, &base)
+                        .draft(request.draft)
+                        .send()
+                        .await
+                        .map_err(GitHubError::from)?;
+                    (pr, StackEntryStatus::Created)
+                }
+            };
+
+            let entry = StackEntry {
+                branch: branch.clone(),
+                pr_number: pr.number,
+                title: pr.title.clone().unwrap_or_default(),
+                base: pr.base.ref_field.clone(),
+                html_url: pr.html_url.as_ref().map(ToString::to_string),
+                status,
+            };
+
+            // Merged or closed branches drop out; the next PR builds on what they targeted
+            if entry.is_open() {
+                base = branch.clone();
+            }
+            entries.push(entry);
+            prs.push(pr);
+        }
+
+        if request.update_bodies {
+            for (entry, pr) in entries.iter().zip(&prs) {
+                if !entry.is_open() {
+                    continue;
+                }
+                let body = pr.body.clone().unwrap_or_default();
+                let section = render_stack_section(&entries, pr.number);
+                let updated = upsert_stack_section(&body, &section);
+                if updated != body {
+                    pulls
+                        .update(pr.number)
+                        .body(updated)
+                        .send()
+                        .await
+                        .map_err(GitHubError::from)?;
+                }
+            }
+        }
+
+        Ok(entries)
+    })
+}
+
+/// Most relevant PR for a head branch: an open one if any, else the newest.
+async fn find_branch_pull_request(
+    inner: &Octocrab,
+    owner: &str,
+    repo: &str,
+    branch: &str,
+) -> Result<Option<PullRequest>, GitHubError> {
+    let page = inner
+        .pulls(owner, repo)
+        .list()
+        .head(format!("{owner}:{branch}"))
+        .state(params::State::All)
+        .per_page(20)
+        .send()
+        .await
+        .map_err(GitHubError::from)?;
+
+    let mut prs = page.items;
+    if let Some(idx) = prs.iter().position(|pr| pr.state == Some(IssueState::Open)) {
+        return Ok(Some(prs.swap_remove(idx)));
+    }
+    Ok(prs.into_iter().max_by_key(|pr| pr.number))
+}
+
+/// First line of the branch head commit message, used as a default PR title.
+async fn head_commit_title(
+    inner: &Octocrab,
+    owner: &str,
+    repo: &str,
+    branch: &str,
+) -> Result<Option<String>, GitHubError> {
+    // Branch names may contain `#`, `?`, `%` or spaces, so encode them as a path segment.
+    let branch = urlencoding::encode(branch);
+    let commit = fetch_commit(inner, owner, repo, &branch, None, None).await?;
+    Ok(commit
+        .commit
+        .message
+        .lines()
+        .next()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(str::to_string))
+}
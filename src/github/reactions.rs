@@ -0,0 +1,172 @@
+//! GitHub reactions on issues, pull requests and comments.
+
+use crate::github::comments::CommentKind;
+use crate::github::error::GitHubError;
+use crate::github::util::{delete_no_content, spawn_task};
+use crate::runtime::{AsyncStream, AsyncTask, EmitterBuilder};
+use octocrab::{
+    Octocrab, Page,
+    models::reactions::{Reaction, ReactionContent},
+};
+use std::sync::Arc;
+
+/// What a reaction is attached to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReactionTarget {
+    /// An issue or pull request (PRs share the issue endpoint).
+    Issue(u64),
+    /// An issue or PR conversation comment.
+    IssueComment(u64),
+    /// An inline PR review comment.
+    ReviewComment(u64),
+}
+
+impl ReactionTarget {
+    /// Pick the target from tool-style arguments: a comment ID wins over an
+    /// issue number.
+    pub fn from_parts(
+        issue_number: Option<u64>,
+        comment_id: Option<u64>,
+        comment_kind: CommentKind,
+    ) -> Result<Self, GitHubError> {
+        match (comment_id, issue_number) {
+            (Some(id), _) => Ok(match comment_kind {
+                CommentKind::Issue => Self::IssueComment(id),
+                CommentKind::Review => Self::ReviewComment(id),
+            }),
+            (None, Some(number)) => Ok(Self::Issue(number)),
+            (None, None) => Err(GitHubError::InvalidInput(
+                "Either issue_number or comment_id is required".to_string(),
+            )),
+        }
+    }
+
+    /// Reactions endpoint path below `/repos/{owner}/{repo}/`.
+    #[must_use]
+    pub fn path(self) -> String {
+        match self {
+            Self::Issue(number) => format!("issues/{number}/reactions"),
+            Self::IssueComment(id) => format!("issues/comments/{id}/reactions"),
+            Self::ReviewComment(id) => format!("pulls/comments/{id}/reactions"),
+        }
+    }
+}
+
+/// Parse a reaction name: `+1`, `-1`, `laugh`, `confused`, `heart`, `hooray`,
+/// `rocket`, `eyes`, or the `thumbs_up`/`thumbs_down` spellings.
+pub fn parse_reaction_content(content: &str) -> Result<ReactionContent, GitHubError> {
+    let normalized = match content.trim().to_lowercase().as_str() {
+        "thumbs_up" | "thumbsup" | "👍" => "+1".to_string(),
+        "thumbs_down" | "thumbsdown" | "👎" => "-1".to_string(),
+        "tada" | "🎉" => "hooray".to_string(),
+        other => other.to_string(),
+    };
+    serde_json::from_value(serde_json::Value::String(normalized)).map_err(|_| {
+        GitHubError::InvalidInput(format!(
+            "Unknown reaction '{content}', expected one of +1, -1, laugh, confused, heart, \
+             hooray, rocket, eyes"
+        ))
+    })
+}
+
+/// API name of a reaction (`+1`, `heart`, ...).
+#[must_use]
+pub fn reaction_name(content: &ReactionContent) -> String {
+    serde_json::to_value(content)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+/// Stream reactions on a target, optionally only of one kind.
+pub(crate) fn list_reactions(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    target: ReactionTarget,
+    content: Option<ReactionContent>,
+) -> AsyncStream<Result<Reaction, GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+
+    let builder = EmitterBuilder::new(Box::new(move || {
+        Box::pin(async move { fetch_reactions(&inner, &owner, &repo, target, content).await })
+    }));
+    builder.emit(|v| v, |_| {})
+}
+
+/// Add a reaction. Adding one the user already left returns the existing one.
+pub(crate) fn add_reaction(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    target: ReactionTarget,
+    content: ReactionContent,
+) -> AsyncTask<Result<Reaction, GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+
+    spawn_task(async move {
+        let path = target.path();
+        inner
+            .post(
+                format!("/repos/{owner}/{repo}/{path}"),
+                Some(&serde_json::json!({ "content": content })),
+            )
+            .await
+            .map_err(GitHubError::from)
+    })
+}
+
+/// Remove the authenticated user's reaction of the given kind.
+///
+/// Returns `false` when the user had not reacted that way.
+pub(crate) fn remove_reaction(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    target: ReactionTarget,
+    content: ReactionContent,
+) -> AsyncTask<Result<bool, GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+
+    spawn_task(async move {
+        let viewer = inner.current().user().await.map_err(GitHubError::from)?;
+        let reactions = fetch_reactions(&inner, &owner, &repo, target, Some(content)).await?;
+
+        let Some(own) = reactions.into_iter().find(|r| r.user.id == viewer.id) else {
+            return Ok(false);
+        };
+
+        let path = target.path();
+        delete_no_content(&inner, format!("/repos/{owner}/{repo}/{path}/{}", own.id)).await?;
+        Ok(true)
+    })
+}
+
+async fn fetch_reactions(
+    inner: &Octocrab,
+    owner: &str,
+    repo: &str,
+    target: ReactionTarget,
+    content: Option<ReactionContent>,
+) -> Result<Vec<Reaction>, GitHubError> {
+    let path = target.path();
+    let filter = match content {
+        Some(c) => format!("&content={}", urlencoding::encode(&reaction_name(&c))),
+        None => String::new(),
+    };
+
+    let mut page: Page<Reaction> = inner
+        .get(
+            format!("/repos/{owner}/{repo}/{path}?per_page=100{filter}"),
+            None::<&()>,
+        )
+        .await
+        .map_err(GitHubError::from)?;
+
+    let mut reactions = std::mem::take(&mut page.items);
+    while let Some(next) = inner.get_page::<Reaction>(&page.next).await? {
+        page = next;
+        reactions.append(&mut page.items);
+    }
+    Ok(reactions)
+}
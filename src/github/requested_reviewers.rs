@@ -0,0 +1,199 @@
+//! GitHub Pull Request requested reviewers operations.
+
+use crate::github::{error::GitHubError, util::spawn_task};
+use crate::runtime::AsyncTask;
+use octocrab::{Octocrab, models::pulls::PullRequest};
+use std::sync::Arc;
+
+/// Request reviews from users and/or teams on a pull request.
+///
+/// Requested users are checked to be repository collaborators first, so a
+/// typo fails with the offending logins instead of GitHub's generic 422.
+pub(crate) fn request_reviewers(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    pr_number: u64,
+    reviewers: Vec<String>,
+    team_reviewers: Vec<String>,
+) -> AsyncTask<Result<PullRequest, GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+
+    spawn_task(async move {
+        ensure_collaborators(&inner, &owner, &repo, &reviewers).await?;
+        send_reviewer_request(
+            &inner,
+            &owner,
+            &repo,
+            pr_number,
+            &reviewers,
+            &team_reviewers,
+        )
+        .await
+    })
+}
+
+/// Remove previously requested user and/or team reviewers from a pull request.
+pub(crate) fn remove_requested_reviewers(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    pr_number: u64,
+    reviewers: Vec<String>,
+    team_reviewers: Vec<String>,
+) -> AsyncTask<Result<PullRequest, GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+
+    spawn_task(async move {
+        if reviewers.is_empty() && team_reviewers.is_empty() {
+            return Err(GitHubError::InvalidInput(
+                "At least one reviewer or team reviewer is required".to_string(),
+            ));
+        }
+        let team_reviewers = team_slugs(&owner, &team_reviewers)?;
+
+        let body = serde_json::json!({
+            "reviewers": reviewers,
+            "team_reviewers": team_reviewers,
+        });
+
+        inner
+            .delete(
+                format!("/repos/{owner}/{repo}/pulls/{pr_number}/requested_reviewers"),
+                Some(&body),
+            )
+            .await
+            .map_err(GitHubError::from)
+    })
+}
+
+/// POST the reviewer request. Shared with pull request creation.
+pub(crate) async fn send_reviewer_request(
+    inner: &Octocrab,
+    owner: &str,
+    repo: &str,
+    pr_number: u64,
+    reviewers: &[String],
+    team_reviewers: &[String],
+) -> Result<PullRequest, GitHubError> {
+    if reviewers.is_empty() && team_reviewers.is_empty() {
+        return Err(GitHubError::InvalidInput(
+            "At least one reviewer or team reviewer is required".to_string(),
+        ));
+    }
+    let team_reviewers = team_slugs(owner, team_reviewers)?;
+
+    let body = serde_json::json!({
+        "reviewers": reviewers,
+        "team_reviewers": team_reviewers,
+    });
+
+    inner
+        .post(
+            format!("/repos/{owner}/{repo}/pulls/{pr_number}/requested_reviewers"),
+            Some(&body),
+        )
+        .await
+        .map_err(GitHubError::from)
+}
+
+/// Fail with `InvalidInput` listing any users that are not collaborators.
+///
+/// Collaborator lookups need push access; if the token lacks it the check is
+/// skipped and GitHub's own validation applies.
+pub(crate) async fn ensure_collaborators(
+    inner: &Octocrab,
+    owner: &str,
+    repo: &str,
+    users: &[String],
+) -> Result<(), GitHubError> {
+    for user in users {
+        validate_login(user)?;
+    }
+
+    let mut lookups = Vec::with_capacity(users.len());
+    for user in users {
+        let response = inner
+            ._get(format!("/repos/{owner}/{repo}/collaborators/{user}"))
+            .await
+            .map_err(GitHubError::from)?;
+        let status = response.status().as_u16();
+        lookups.push((user.clone(), status));
+        if !matches!(status, 204 | 404) {
+            break;
+        }
+    }
+
+    match missing_collaborators(&lookups) {
+        None => {
+            log::warn!("Skipping collaborator validation for {owner}/{repo}: no push access");
+            Ok(())
+        }
+        Some(missing) if missing.is_empty() => Ok(()),
+        Some(missing) => Err(GitHubError::InvalidInput(format!(
+            "Not collaborators on {owner}/{repo}: {}",
+            missing.join(", ")
+        ))),
+    }
+}
+
+/// Logins whose collaborator lookup returned 404, given `(login, HTTP status)` pairs.
+///
+/// Returns `None` if any lookup got another status (typically 403 without push
+/// access), meaning the check cannot be trusted and should be skipped.
+pub fn missing_collaborators(lookups: &[(String, u16)]) -> Option<Vec<String>> {
+    let mut missing = Vec::new();
+    for (login, status) in lookups {
+        match status {
+            204 => {}
+            404 => missing.push(login.clone()),
+            _ => return None,
+        }
+    }
+    Some(missing)
+}
+
+/// Check that `login` is a plausible GitHub login before it goes into a URL path.
+pub fn validate_login(login: &str) -> Result<(), GitHubError> {
+    if !login.is_empty() && login.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+        Ok(())
+    } else {
+        Err(GitHubError::InvalidInput(format!(
+            "Invalid GitHub login: {login:?}"
+        )))
+    }
+}
+
+/// Team slug for the requested reviewers API from `team`, `org/team` or `@org/team`.
+///
+/// The API only takes bare slugs, and a team from another organization cannot
+/// review pull requests in `owner`'s repositories.
+pub fn team_slug<'a>(owner: &str, team: &'a str) -> Result<&'a str, GitHubError> {
+    let team = team.strip_prefix('@').unwrap_or(team);
+    let slug = match team.split_once('/') {
+        Some((org, slug)) if org.eq_ignore_ascii_case(owner) => slug,
+        Some((org, _)) => {
+            return Err(GitHubError::InvalidInput(format!(
+                "Team {team} belongs to {org}, not {owner}"
+            )));
+        }
+        None => team,
+    };
+    if slug.is_empty()
+        || !slug
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(GitHubError::InvalidInput(format!(
+            "Invalid team slug: {team:?}"
+        )));
+    }
+    Ok(slug)
+}
+
+fn team_slugs(owner: &str, teams: &[String]) -> Result<Vec<String>, GitHubError> {
+    teams
+        .iter()
+        .map(|team| team_slug(owner, team).map(str::to_string))
+        .collect()
+}
@@ -0,0 +1,304 @@
+//! GitHub Pull Request review thread operations (GraphQL).
+
+use crate::github::error::GitHubError;
+use crate::github::util::{graphql, spawn_task};
+use crate::runtime::{AsyncStream, AsyncTask, EmitterBuilder};
+use octocrab::Octocrab;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// A review thread: a group of review comments anchored to one location.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all(deserialize = "camelCase"))]
+pub struct ReviewThread {
+    /// GraphQL node ID, used to resolve or unresolve the thread.
+    pub id: String,
+    /// Whether the thread has been marked resolved.
+    pub is_resolved: bool,
+    /// Whether the code the thread points at has since changed.
+    pub is_outdated: bool,
+    /// File path the thread is attached to.
+    pub path: String,
+    /// Line in the current diff, `None` when outdated.
+    pub line: Option<u32>,
+    /// Line in the diff the thread was originally created on.
+    pub original_line: Option<u32>,
+    /// First line of a multi-line thread.
+    pub start_line: Option<u32>,
+    /// Side of the diff (`LEFT` or `RIGHT`).
+    pub diff_side: String,
+    /// Login of whoever resolved the thread.
+    #[serde(deserialize_with = "login_of")]
+    pub resolved_by: Option<String>,
+    /// Comments in the thread, oldest first.
+    #[serde(deserialize_with = "comment_nodes")]
+    pub comments: Vec<ReviewThreadComment>,
+}
+
+/// A single comment within a review thread.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all(deserialize = "camelCase"))]
+pub struct ReviewThreadComment {
+    /// GraphQL node ID.
+    pub id: String,
+    /// REST comment ID, usable as `in_reply_to` for review comment replies.
+    pub database_id: Option<u64>,
+    /// Comment author login.
+    #[serde(deserialize_with = "login_of")]
+    pub author: Option<String>,
+    /// Comment body (markdown).
+    pub body: String,
+    /// Creation timestamp (ISO 8601).
+    pub created_at: String,
+    /// Link to the comment on GitHub.
+    pub url: String,
+}
+
+#[derive(Deserialize)]
+struct Actor {
+    login: String,
+}
+
+fn login_of<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(Option::<Actor>::deserialize(deserializer)?.map(|a| a.login))
+}
+
+fn comment_nodes<'de, D>(deserializer: D) -> Result<Vec<ReviewThreadComment>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Connection {
+        nodes: Vec<ReviewThreadComment>,
+    }
+    Ok(Connection::deserialize(deserializer)?.nodes)
+}
+
+const LIST_THREADS_QUERY: &str = r"
+query($owner: String!, $repo: String!, $number: Int!, $cursor: String) {
+  repository(owner: $owner, name: $repo) {
+    pullRequest(number: $number) {
+      reviewThreads(first: 50, after: $cursor) {
+        pageInfo { hasNextPage endCursor }
+        nodes {
+          id isResolved isOutdated path line originalLine startLine diffSide
+          resolvedBy { login }
+          comments(first: 100) {
+            pageInfo { hasNextPage endCursor }
+            nodes { id databaseId author { login } body createdAt url }
+          }
+        }
+      }
+    }
+  }
+}";
+
+const THREAD_COMMENTS_QUERY: &str = r"
+query($threadId: ID!, $cursor: String) {
+  node(id: $threadId) {
+    ... on PullRequestReviewThread {
+      comments(first: 100, after: $cursor) {
+        pageInfo { hasNextPage endCursor }
+        nodes { id databaseId author { login } body createdAt url }
+      }
+    }
+  }
+}";
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PageInfo {
+    has_next_page: bool,
+    end_cursor: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ThreadsData {
+    repository: Option<RepositoryNode>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RepositoryNode {
+    pull_request: Option<PullRequestNode>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PullRequestNode {
+    review_threads: ThreadConnection,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ThreadConnection {
+    page_info: PageInfo,
+    nodes: Vec<ThreadNode>,
+}
+
+/// A thread as returned by the list query, keeping the comment page info
+/// that `ReviewThread` itself drops.
+struct ThreadNode {
+    thread: ReviewThread,
+    comments_page: Option<PageInfo>,
+}
+
+impl<'de> Deserialize<'de> for ThreadNode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let comments_page = value
+            .pointer("/comments/pageInfo")
+            .cloned()
+            .map(serde_json::from_value)
+            .transpose()
+            .map_err(serde::de::Error::custom)?;
+        let thread = ReviewThread::deserialize(value).map_err(serde::de::Error::custom)?;
+        Ok(Self {
+            thread,
+            comments_page,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+struct ThreadCommentsData {
+    node: Option<ThreadCommentsNode>,
+}
+
+#[derive(Deserialize)]
+struct ThreadCommentsNode {
+    comments: CommentConnection,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CommentConnection {
+    page_info: PageInfo,
+    nodes: Vec<ReviewThreadComment>,
+}
+
+/// Fetch the comments of `thread` beyond its first page.
+async fn fetch_remaining_comments(
+    inner: &Octocrab,
+    thread: &mut ReviewThread,
+    mut page: PageInfo,
+) -> Result<(), GitHubError> {
+    while page.has_next_page {
+        let data: ThreadCommentsData = graphql(
+            inner,
+            THREAD_COMMENTS_QUERY,
+            serde_json::json!({
+                "threadId": thread.id,
+                "cursor": page.end_cursor,
+            }),
+        )
+        .await?;
+
+        let connection = data
+            .node
+            .ok_or_else(|| GitHubError::NotFound(format!("review thread {}", thread.id)))?
+            .comments;
+        thread.comments.extend(connection.nodes);
+        page = connection.page_info;
+    }
+    Ok(())
+}
+
+/// Stream review threads on a PR, with their comments grouped.
+pub(crate) fn list_review_threads(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    pr_number: u64,
+) -> AsyncStream<Result<ReviewThread, GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+
+    let builder = EmitterBuilder::new(Box::new(move || {
+        Box::pin(async move {
+            let mut threads = Vec::new();
+            let mut cursor: Option<String> = None;
+
+            loop {
+                let data: ThreadsData = graphql(
+                    &inner,
+                    LIST_THREADS_QUERY,
+                    serde_json::json!({
+                        "owner": owner,
+                        "repo": repo,
+                        "number": pr_number,
+                        "cursor": cursor,
+                    }),
+                )
+                .await?;
+
+                let connection = data
+                    .repository
+                    .and_then(|r| r.pull_request)
+                    .ok_or_else(|| GitHubError::NotFound(format!("{owner}/{repo}#{pr_number}")))?
+                    .review_threads;
+
+                for node in connection.nodes {
+                    let mut thread = node.thread;
+                    if let Some(page) = node.comments_page {
+                        fetch_remaining_comments(&inner, &mut thread, page).await?;
+                    }
+                    threads.push(thread);
+                }
+
+                if !connection.page_info.has_next_page {
+                    break;
+                }
+                cursor = connection.page_info.end_cursor;
+            }
+            Ok(threads)
+        })
+    }));
+    builder.emit(|v| v, |_| {})
+}
+
+const RESOLVE_THREAD_MUTATION: &str = r"
+mutation($threadId: ID!) {
+  resolveReviewThread(input: { threadId: $threadId }) { thread { id isResolved } }
+}";
+
+const UNRESOLVE_THREAD_MUTATION: &str = r"
+mutation($threadId: ID!) {
+  unresolveReviewThread(input: { threadId: $threadId }) { thread { id isResolved } }
+}";
+
+/// Mark a review thread as resolved or unresolved. Returns the new resolved state.
+pub(crate) fn set_review_thread_resolved(
+    inner: Arc<Octocrab>,
+    thread_id: impl Into<String>,
+    resolved: bool,
+) -> AsyncTask<Result<bool, GitHubError>> {
+    let thread_id = thread_id.into();
+
+    spawn_task(async move {
+        let (mutation, field) = if resolved {
+            (RESOLVE_THREAD_MUTATION, "resolveReviewThread")
+        } else {
+            (UNRESOLVE_THREAD_MUTATION, "unresolveReviewThread")
+        };
+
+        let data: serde_json::Value = graphql(
+            &inner,
+            mutation,
+            serde_json::json!({ "threadId": thread_id }),
+        )
+        .await?;
+
+        data.get(field)
+            .and_then(|m| m.get("thread"))
+            .and_then(|t| t.get("isResolved"))
+            .and_then(serde_json::Value::as_bool)
+            .ok_or_else(|| GitHubError::NotFound(format!("review thread {thread_id}")))
+    })
+}
@@ -1,5 +1,7 @@
 //! GitHub code search operation.
 
+use crate::github::search_query::{CodeSearchQuery, CodeSearchSort};
+use crate::github::search_users::SearchOrder;
 use crate::github::{error::GitHubError, util::spawn_task};
 use crate::runtime::AsyncTask;
 use octocrab::{Octocrab, models::Code};
@@ -78,16 +80,24 @@ pub(crate) fn search_code(
     let query = query.into();
 
     spawn_task(async move {
+        let query = CodeSearchQuery::new().text(query).build()?;
+        let sort = sort
+            .as_deref()
+            .map(str::parse::<CodeSearchSort>)
+            .transpose()?;
+        let order = order
+            .as_deref()
+            .map(str::parse::<SearchOrder>)
+            .transpose()?;
+
         let mut request = inner.search().code(&query);
 
         if let Some(sort_val) = sort {
-            // Valid values: "indexed"
-            request = request.sort(&sort_val);
+            request = request.sort(sort_val.as_str());
         }
 
         if let Some(order_val) = order {
-            // Valid values: "asc", "desc"
-            request = request.order(&order_val);
+            request = request.order(order_val.as_str());
         }
 
         if let Some(p) = page {
@@ -1,6 +1,8 @@
 //! GitHub Issues search operation.
 
 use crate::github::error::GitHubError;
+use crate::github::search_query::{IssueSearchQuery, IssueSearchSort};
+use crate::github::search_users::SearchOrder;
 use crate::runtime::{AsyncStream, EmitterBuilder};
 use octocrab::{Octocrab, Page, models::issues::Issue};
 use std::sync::Arc;
@@ -17,18 +19,28 @@ pub(crate) fn search_issues(
     let q = query.into();
     let builder = EmitterBuilder::new(Box::new(move || {
         Box::pin(async move {
+            let query = IssueSearchQuery::new().text(q.as_str()).build()?;
+            let sort = sort
+                .as_deref()
+                .map(str::parse::<IssueSearchSort>)
+                .transpose()?;
+            let order = order
+                .as_deref()
+                .map(str::parse::<SearchOrder>)
+                .transpose()?;
+
             let mut results = Vec::new();
             let mut req = inner
                 .search()
-                .issues_and_pull_requests(&q)
+                .issues_and_pull_requests(&query)
                 .per_page(per_page.unwrap_or(100))
                 .page(page.unwrap_or(1));
 
-            if let Some(s) = &sort {
-                req = req.sort(s);
+            if let Some(s) = sort {
+                req = req.sort(s.as_str());
             }
-            if let Some(o) = &order {
-                req = req.order(o);
+            if let Some(o) = order {
+                req = req.order(o.as_str());
             }
 
             let mut page_res: Page<Issue> = req.send().await.map_err(GitHubError::from)?;
@@ -43,3 +55,29 @@ pub(crate) fn search_issues(
     }));
     builder.emit(|v| v, |_| {})
 }
+
+/// Search results up to `limit`, following pages only as far as needed.
+pub(crate) async fn search_issues_page(
+    inner: &Octocrab,
+    query: &str,
+    limit: usize,
+) -> Result<Vec<Issue>, GitHubError> {
+    let per_page = u8::try_from(limit.min(100)).unwrap_or(100);
+    let mut page: Page<Issue> = inner
+        .search()
+        .issues_and_pull_requests(query)
+        .per_page(per_page)
+        .send()
+        .await
+        .map_err(GitHubError::from)?;
+
+    let mut issues = std::mem::take(&mut page.items);
+    while issues.len() < limit
+        && let Some(mut next) = inner.get_page::<Issue>(&page.next).await?
+    {
+        issues.append(&mut next.items);
+        page = next;
+    }
+    issues.truncate(limit);
+    Ok(issues)
+}
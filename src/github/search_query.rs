@@ -0,0 +1,710 @@
+//! Typed query builders for the issue/PR and code search APIs.
+//!
+//! Setters only record qualifiers; `build` validates and quotes them, so a
+//! bad value fails before a request is sent rather than silently matching
+//! something else. Sort strings parse into enums for the same reason.
+
+use crate::github::error::GitHubError;
+use chrono::NaiveDate;
+use std::fmt;
+use std::str::FromStr;
+
+/// Longest keyword text the search API accepts. Qualifiers and operators
+/// do not count towards it.
+pub const MAX_QUERY_LENGTH: usize = 256;
+
+/// A numeric or date range, as used by `created:`, `comments:`, `size:` etc.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryRange<T> {
+    /// `value`
+    Exactly(T),
+    /// `>value`
+    Above(T),
+    /// `>=value`
+    AtLeast(T),
+    /// `<value`
+    Below(T),
+    /// `<=value`
+    AtMost(T),
+    /// `from..to`, inclusive
+    Between(T, T),
+}
+
+/// Range of calendar dates.
+pub type DateRange = QueryRange<NaiveDate>;
+
+impl<T: fmt::Display> fmt::Display for QueryRange<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Exactly(v) => write!(f, "{v}"),
+            Self::Above(v) => write!(f, ">{v}"),
+            Self::AtLeast(v) => write!(f, ">={v}"),
+            Self::Below(v) => write!(f, "<{v}"),
+            Self::AtMost(v) => write!(f, "<={v}"),
+            Self::Between(from, to) => write!(f, "{from}..{to}"),
+        }
+    }
+}
+
+impl<T: FromStr> FromStr for QueryRange<T> {
+    type Err = GitHubError;
+
+    /// Parse `5`, `>5`, `>=5`, `<5`, `<=5`, `1..5`, `1..*` or `*..5`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let value = |v: &str| {
+            v.trim()
+                .parse::<T>()
+                .map_err(|_| GitHubError::InvalidInput(format!("Invalid range '{s}'")))
+        };
+
+        if let Some((from, to)) = s.split_once("..") {
+            return match (from.trim(), to.trim()) {
+                ("*", "*") | ("", _) | (_, "") => {
+                    Err(GitHubError::InvalidInput(format!("Invalid range '{s}'")))
+                }
+                ("*", to) => Ok(Self::AtMost(value(to)?)),
+                (from, "*") => Ok(Self::AtLeast(value(from)?)),
+                (from, to) => Ok(Self::Between(value(from)?, value(to)?)),
+            };
+        }
+        if let Some(v) = s.strip_prefix(">=") {
+            Ok(Self::AtLeast(value(v)?))
+        } else if let Some(v) = s.strip_prefix("<=") {
+            Ok(Self::AtMost(value(v)?))
+        } else if let Some(v) = s.strip_prefix('>') {
+            Ok(Self::Above(value(v)?))
+        } else if let Some(v) = s.strip_prefix('<') {
+            Ok(Self::Below(value(v)?))
+        } else {
+            Ok(Self::Exactly(value(s)?))
+        }
+    }
+}
+
+/// Sort field for issue and PR search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueSearchSort {
+    /// Number of comments
+    Comments,
+    /// Number of reactions
+    Reactions,
+    /// Comments plus reactions
+    Interactions,
+    /// Creation time
+    Created,
+    /// Last update time
+    Updated,
+}
+
+impl IssueSearchSort {
+    /// Returns the GitHub API string representation of this sort field.
+    #[inline]
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Comments => "comments",
+            Self::Reactions => "reactions",
+            Self::Interactions => "interactions",
+            Self::Created => "created",
+            Self::Updated => "updated",
+        }
+    }
+}
+
+impl FromStr for IssueSearchSort {
+    type Err = GitHubError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "comments" => Ok(Self::Comments),
+            "reactions" => Ok(Self::Reactions),
+            "interactions" => Ok(Self::Interactions),
+            "created" => Ok(Self::Created),
+            "updated" => Ok(Self::Updated),
+            _ => Err(GitHubError::InvalidInput(format!(
+                "Unknown sort '{s}', expected comments, reactions, interactions, created or updated"
+            ))),
+        }
+    }
+}
+
+/// Sort field for code search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CodeSearchSort {
+    /// When the file was last indexed
+    Indexed,
+}
+
+impl CodeSearchSort {
+    /// Returns the GitHub API string representation of this sort field.
+    #[inline]
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Indexed => "indexed",
+        }
+    }
+}
+
+impl FromStr for CodeSearchSort {
+    type Err = GitHubError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "indexed" => Ok(Self::Indexed),
+            _ => Err(GitHubError::InvalidInput(format!(
+                "Unknown sort '{s}', expected indexed"
+            ))),
+        }
+    }
+}
+
+/// Issues or pull requests (`is:issue` / `is:pr`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueKind {
+    /// Issues only
+    Issue,
+    /// Pull requests only
+    PullRequest,
+}
+
+impl FromStr for IssueKind {
+    type Err = GitHubError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "issue" | "issues" => Ok(Self::Issue),
+            "pr" | "prs" | "pull_request" | "pull-request" => Ok(Self::PullRequest),
+            _ => Err(GitHubError::InvalidInput(format!(
+                "Unknown kind '{s}', expected issue or pr"
+            ))),
+        }
+    }
+}
+
+/// State filter (`is:open`, `is:closed`, `is:merged`, `is:unmerged`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueSearchState {
+    /// Open
+    Open,
+    /// Closed (including merged PRs)
+    Closed,
+    /// Merged pull requests
+    Merged,
+    /// Closed pull requests that were not merged
+    Unmerged,
+}
+
+impl FromStr for IssueSearchState {
+    type Err = GitHubError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "open" => Ok(Self::Open),
+            "closed" => Ok(Self::Closed),
+            "merged" => Ok(Self::Merged),
+            "unmerged" => Ok(Self::Unmerged),
+            _ => Err(GitHubError::InvalidInput(format!(
+                "Unknown state '{s}', expected open, closed, merged or unmerged"
+            ))),
+        }
+    }
+}
+
+/// Quote a qualifier value or phrase for a search query.
+///
+/// Values with whitespace or characters that would otherwise be read as
+/// syntax are wrapped in double quotes. The search syntax has no escape for
+/// a double quote or a line break, so those are rejected.
+pub fn quote_search_value(value: &str) -> Result<String, GitHubError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(GitHubError::InvalidInput(
+            "Search values must not be empty".to_string(),
+        ));
+    }
+    if value.contains(['"', '\n', '\r']) {
+        return Err(GitHubError::InvalidInput(format!(
+            "Search value {value:?} contains a double quote or line break"
+        )));
+    }
+    if value.contains(|c: char| c.is_whitespace() || "():".contains(c)) {
+        Ok(format!("\"{value}\""))
+    } else {
+        Ok(value.to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Term {
+    Text(String),
+    Phrase(String),
+    Qualifier {
+        negated: bool,
+        name: String,
+        value: String,
+        verbatim: bool,
+    },
+}
+
+/// Terms and qualifiers shared by the search builders.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct QueryParts {
+    terms: Vec<Term>,
+    search_in: Vec<&'static str>,
+}
+
+impl QueryParts {
+    fn push(&mut self, negated: bool, name: &str, value: String, verbatim: bool) {
+        self.terms.push(Term::Qualifier {
+            negated,
+            name: name.to_string(),
+            value,
+            verbatim,
+        });
+    }
+
+    fn search_in(&mut self, field: &'static str) {
+        if !self.search_in.contains(&field) {
+            self.search_in.push(field);
+        }
+    }
+
+    fn has_keywords(&self) -> bool {
+        self.terms
+            .iter()
+            .any(|t| matches!(t, Term::Text(_) | Term::Phrase(_)))
+    }
+
+    fn build(&self) -> Result<String, GitHubError> {
+        let mut tokens = Vec::with_capacity(self.terms.len() + 1);
+        for term in &self.terms {
+            match term {
+                Term::Text(text) => tokens.push(text.clone()),
+                Term::Phrase(phrase) => {
+                    let quoted = quote_search_value(phrase)?;
+                    tokens.push(if quoted.starts_with('"') {
+                        quoted
+                    } else {
+                        format!("\"{quoted}\"")
+                    });
+                }
+                Term::Qualifier {
+                    negated,
+                    name,
+                    value,
+                    verbatim,
+                } => {
+                    if name.is_empty() || !name.chars().all(|c| c.is_ascii_lowercase() || c == '-')
+                    {
+                        return Err(GitHubError::InvalidInput(format!(
+                            "Invalid search qualifier '{name}'"
+                        )));
+                    }
+                    let value = if *verbatim {
+                        value.clone()
+                    } else {
+                        quote_search_value(value)?
+                    };
+                    let sign = if *negated { "-" } else { "" };
+                    tokens.push(format!("{sign}{name}:{value}"));
+                }
+            }
+        }
+        if !self.search_in.is_empty() {
+            tokens.push(format!("in:{}", self.search_in.join(",")));
+        }
+
+        let query = tokens.join(" ");
+        if query.is_empty() {
+            return Err(GitHubError::InvalidInput(
+                "Search query is empty".to_string(),
+            ));
+        }
+        let keyword_length: usize = self
+            .terms
+            .iter()
+            .map(|term| match term {
+                Term::Text(text) => {
+                    let qualifiers: usize = text
+                        .split_whitespace()
+                        .filter(|token| is_raw_qualifier(token))
+                        .map(|token| token.chars().count())
+                        .sum();
+                    text.chars().count() - qualifiers
+                }
+                Term::Phrase(phrase) => phrase.chars().count(),
+                Term::Qualifier { .. } => 0,
+            })
+            .sum();
+        if keyword_length > MAX_QUERY_LENGTH {
+            return Err(GitHubError::InvalidInput(format!(
+                "Search keywords are longer than {MAX_QUERY_LENGTH} characters"
+            )));
+        }
+        Ok(query)
+    }
+}
+
+/// Whether a token of raw search text is a `name:value` qualifier.
+fn is_raw_qualifier(token: &str) -> bool {
+    token
+        .trim_start_matches('-')
+        .split_once(':')
+        .is_some_and(|(name, value)| {
+            !name.is_empty()
+                && !value.is_empty()
+                && name.chars().all(|c| c.is_ascii_lowercase() || c == '-')
+        })
+}
+
+fn check_repo(parts: &QueryParts) -> Result<(), GitHubError> {
+    for term in &parts.terms {
+        if let Term::Qualifier { name, value, .. } = term
+            && name == "repo"
+        {
+            let value = value.trim();
+            let valid = value
+                .split_once('/')
+                .is_some_and(|(o, r)| !o.is_empty() && !r.is_empty() && !r.contains('/'))
+                && !value.contains(char::is_whitespace);
+            if !valid {
+                return Err(GitHubError::InvalidInput(format!(
+                    "Invalid repository '{value}', expected owner/name"
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Builder for issue and pull request search queries.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IssueSearchQuery {
+    parts: QueryParts,
+}
+
+impl IssueSearchQuery {
+    /// Create an empty query
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add free text, passed through as written (may hold raw search syntax)
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        let text = text.into();
+        if !text.trim().is_empty() {
+            self.parts.terms.push(Term::Text(text.trim().to_string()));
+        }
+        self
+    }
+
+    /// Add an exact phrase
+    pub fn phrase(mut self, phrase: impl Into<String>) -> Self {
+        self.parts.terms.push(Term::Phrase(phrase.into()));
+        self
+    }
+
+    /// Restrict to a repository (`owner/name`)
+    pub fn repo(mut self, full_name: impl Into<String>) -> Self {
+        self.parts.push(false, "repo", full_name.into(), false);
+        self
+    }
+
+    /// Restrict to an organization's repositories
+    pub fn org(mut self, org: impl Into<String>) -> Self {
+        self.parts.push(false, "org", org.into(), false);
+        self
+    }
+
+    /// Restrict to a user's repositories
+    pub fn user(mut self, user: impl Into<String>) -> Self {
+        self.parts.push(false, "user", user.into(), false);
+        self
+    }
+
+    /// Only issues or only pull requests
+    pub fn kind(mut self, kind: IssueKind) -> Self {
+        let value = match kind {
+            IssueKind::Issue => "issue",
+            IssueKind::PullRequest => "pr",
+        };
+        self.parts.push(false, "is", value.to_string(), true);
+        self
+    }
+
+    /// Filter by state
+    pub fn state(mut self, state: IssueSearchState) -> Self {
+        let value = match state {
+            IssueSearchState::Open => "open",
+            IssueSearchState::Closed => "closed",
+            IssueSearchState::Merged => "merged",
+            IssueSearchState::Unmerged => "unmerged",
+        };
+        self.parts.push(false, "is", value.to_string(), true);
+        self
+    }
+
+    /// Only draft (or only non-draft) pull requests
+    pub fn draft(mut self, draft: bool) -> Self {
+        self.parts.push(false, "draft", draft.to_string(), true);
+        self
+    }
+
+    /// Require a label
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.parts.push(false, "label", label.into(), false);
+        self
+    }
+
+    /// Exclude a label
+    pub fn exclude_label(mut self, label: impl Into<String>) -> Self {
+        self.parts.push(true, "label", label.into(), false);
+        self
+    }
+
+    /// Filter by author
+    pub fn author(mut self, login: impl Into<String>) -> Self {
+        self.parts.push(false, "author", login.into(), false);
+        self
+    }
+
+    /// Filter by assignee
+    pub fn assignee(mut self, login: impl Into<String>) -> Self {
+        self.parts.push(false, "assignee", login.into(), false);
+        self
+    }
+
+    /// Filter by mentioned user
+    pub fn mentions(mut self, login: impl Into<String>) -> Self {
+        self.parts.push(false, "mentions", login.into(), false);
+        self
+    }
+
+    /// Filter by commenter
+    pub fn commenter(mut self, login: impl Into<String>) -> Self {
+        self.parts.push(false, "commenter", login.into(), false);
+        self
+    }
+
+    /// Filter by a user who is author, assignee, mentioned or commenter
+    pub fn involves(mut self, login: impl Into<String>) -> Self {
+        self.parts.push(false, "involves", login.into(), false);
+        self
+    }
+
+    /// Filter by milestone title
+    pub fn milestone(mut self, title: impl Into<String>) -> Self {
+        self.parts.push(false, "milestone", title.into(), false);
+        self
+    }
+
+    /// Only items missing a field: `label`, `assignee`, `milestone` or `project`
+    pub fn missing(mut self, field: impl Into<String>) -> Self {
+        self.parts.push(false, "no", field.into(), false);
+        self
+    }
+
+    /// Match keywords in the title
+    #[must_use]
+    pub fn in_title(mut self) -> Self {
+        self.parts.search_in("title");
+        self
+    }
+
+    /// Match keywords in the body
+    #[must_use]
+    pub fn in_body(mut self) -> Self {
+        self.parts.search_in("body");
+        self
+    }
+
+    /// Match keywords in comments
+    #[must_use]
+    pub fn in_comments(mut self) -> Self {
+        self.parts.search_in("comments");
+        self
+    }
+
+    /// Filter by creation date
+    pub fn created(mut self, range: DateRange) -> Self {
+        self.parts.push(false, "created", range.to_string(), true);
+        self
+    }
+
+    /// Filter by last update date
+    pub fn updated(mut self, range: DateRange) -> Self {
+        self.parts.push(false, "updated", range.to_string(), true);
+        self
+    }
+
+    /// Filter by close date
+    pub fn closed(mut self, range: DateRange) -> Self {
+        self.parts.push(false, "closed", range.to_string(), true);
+        self
+    }
+
+    /// Filter by merge date (pull requests)
+    pub fn merged(mut self, range: DateRange) -> Self {
+        self.parts.push(false, "merged", range.to_string(), true);
+        self
+    }
+
+    /// Filter by number of comments
+    pub fn comments(mut self, range: QueryRange<u64>) -> Self {
+        self.parts.push(false, "comments", range.to_string(), true);
+        self
+    }
+
+    /// Add any other qualifier
+    pub fn qualifier(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.parts.push(false, &name.into(), value.into(), false);
+        self
+    }
+
+    /// Exclude matches of a qualifier (`-name:value`)
+    pub fn exclude(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.parts.push(true, &name.into(), value.into(), false);
+        self
+    }
+
+    /// Validate and assemble the query string
+    pub fn build(&self) -> Result<String, GitHubError> {
+        check_repo(&self.parts)?;
+        for term in &self.parts.terms {
+            if let Term::Qualifier { name, value, .. } = term
+                && name == "no"
+                && !["label", "assignee", "milestone", "project"].contains(&value.as_str())
+            {
+                return Err(GitHubError::InvalidInput(format!(
+                    "Unknown missing field '{value}', expected label, assignee, milestone or project"
+                )));
+            }
+        }
+        self.parts.build()
+    }
+}
+
+/// Builder for code search queries.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CodeSearchQuery {
+    parts: QueryParts,
+}
+
+impl CodeSearchQuery {
+    /// Create an empty query
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add free text, passed through as written (may hold raw search syntax)
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        let text = text.into();
+        if !text.trim().is_empty() {
+            self.parts.terms.push(Term::Text(text.trim().to_string()));
+        }
+        self
+    }
+
+    /// Add an exact phrase
+    pub fn phrase(mut self, phrase: impl Into<String>) -> Self {
+        self.parts.terms.push(Term::Phrase(phrase.into()));
+        self
+    }
+
+    /// Restrict to a repository (`owner/name`)
+    pub fn repo(mut self, full_name: impl Into<String>) -> Self {
+        self.parts.push(false, "repo", full_name.into(), false);
+        self
+    }
+
+    /// Restrict to an organization's repositories
+    pub fn org(mut self, org: impl Into<String>) -> Self {
+        self.parts.push(false, "org", org.into(), false);
+        self
+    }
+
+    /// Restrict to a user's repositories
+    pub fn user(mut self, user: impl Into<String>) -> Self {
+        self.parts.push(false, "user", user.into(), false);
+        self
+    }
+
+    /// Filter by language
+    pub fn language(mut self, language: impl Into<String>) -> Self {
+        self.parts.push(false, "language", language.into(), false);
+        self
+    }
+
+    /// Restrict to files under a path
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.parts.push(false, "path", path.into(), false);
+        self
+    }
+
+    /// Filter by file name
+    pub fn filename(mut self, filename: impl Into<String>) -> Self {
+        self.parts.push(false, "filename", filename.into(), false);
+        self
+    }
+
+    /// Filter by file extension (without the dot)
+    pub fn extension(mut self, extension: impl Into<String>) -> Self {
+        let extension = extension.into();
+        self.parts.push(
+            false,
+            "extension",
+            extension.trim_start_matches('.').to_string(),
+            false,
+        );
+        self
+    }
+
+    /// Match keywords in file contents
+    #[must_use]
+    pub fn in_file(mut self) -> Self {
+        self.parts.search_in("file");
+        self
+    }
+
+    /// Match keywords in file paths
+    #[must_use]
+    pub fn in_path(mut self) -> Self {
+        self.parts.search_in("path");
+        self
+    }
+
+    /// Filter by file size in bytes
+    pub fn size(mut self, range: QueryRange<u64>) -> Self {
+        self.parts.push(false, "size", range.to_string(), true);
+        self
+    }
+
+    /// Add any other qualifier
+    pub fn qualifier(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.parts.push(false, &name.into(), value.into(), false);
+        self
+    }
+
+    /// Exclude matches of a qualifier (`-name:value`)
+    pub fn exclude(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.parts.push(true, &name.into(), value.into(), false);
+        self
+    }
+
+    /// Validate and assemble the query string
+    ///
+    /// Code search needs at least one keyword besides qualifiers.
+    pub fn build(&self) -> Result<String, GitHubError> {
+        check_repo(&self.parts)?;
+        if !self.parts.has_keywords() {
+            return Err(GitHubError::InvalidInput(
+                "Code search needs at least one search term besides qualifiers".to_string(),
+            ));
+        }
+        self.parts.build()
+    }
+}
@@ -58,6 +58,20 @@ impl SearchOrder {
     }
 }
 
+impl std::str::FromStr for SearchOrder {
+    type Err = GitHubError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "asc" | "ascending" => Ok(Self::Asc),
+            "desc" | "descending" => Ok(Self::Desc),
+            _ => Err(GitHubError::InvalidInput(format!(
+                "Unknown order '{s}', expected asc or desc"
+            ))),
+        }
+    }
+}
+
 /// Search for GitHub users matching a query.
 ///
 /// This function provides type-safe access to the GitHub user search API with
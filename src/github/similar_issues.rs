@@ -0,0 +1,374 @@
+//! Duplicate detection: find existing issues similar to a candidate.
+//!
+//! GitHub search narrows the field with keyword queries; candidates are then
+//! ranked locally by TF-IDF cosine similarity over title, body and labels,
+//! blended with title token overlap since duplicate titles tend to share
+//! most of their words.
+
+use crate::github::error::GitHubError;
+use crate::github::search_issues::search_issues_page;
+use crate::github::util::spawn_task;
+use crate::runtime::AsyncTask;
+use octocrab::{Octocrab, models::IssueState};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::Arc;
+
+/// Score at or above which an issue is treated as a likely duplicate.
+pub const DEFAULT_DUPLICATE_THRESHOLD: f64 = 0.5;
+
+/// Results per search query.
+const RESULTS_PER_QUERY: usize = 30;
+
+/// Keywords used in each search query; GitHub ANDs plain terms, so fewer
+/// terms find more candidates.
+const QUERY_KEYWORDS: usize = 4;
+
+/// Share of the score coming from title overlap rather than TF-IDF.
+const TITLE_WEIGHT: f64 = 0.4;
+
+const STOPWORDS: &[&str] = &[
+    "a", "about", "after", "all", "also", "am", "an", "and", "any", "are", "as", "at", "be",
+    "been", "before", "but", "by", "can", "cannot", "could", "did", "do", "does", "doesn", "don",
+    "for", "from", "get", "gets", "got", "had", "has", "have", "how", "i", "if", "in", "into",
+    "is", "isn", "it", "its", "just", "me", "my", "no", "not", "of", "on", "or", "our", "should",
+    "so", "some", "than", "that", "the", "their", "then", "there", "these", "this", "to", "too",
+    "us", "use", "using", "was", "we", "were", "what", "when", "where", "which", "while", "who",
+    "why", "will", "with", "would", "you", "your",
+];
+
+/// The text of an issue that similarity is computed over.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IssueText {
+    /// Issue title
+    pub title: String,
+    /// Issue body
+    pub body: String,
+    /// Label names
+    pub labels: Vec<String>,
+}
+
+impl IssueText {
+    /// Build from parts.
+    pub fn new(title: impl Into<String>, body: impl Into<String>, labels: Vec<String>) -> Self {
+        Self {
+            title: title.into(),
+            body: body.into(),
+            labels,
+        }
+    }
+
+    /// Weighted terms: title tokens count twice, labels once as `label:name`.
+    fn terms(&self) -> Vec<String> {
+        let title = tokenize(&self.title);
+        let mut terms = title.clone();
+        terms.extend(title);
+        terms.extend(tokenize(&self.body));
+        terms.extend(
+            self.labels
+                .iter()
+                .map(|l| format!("label:{}", l.to_lowercase())),
+        );
+        terms
+    }
+}
+
+/// Similarity of one candidate to the query issue.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimilarityScore {
+    /// Score between 0 and 1
+    pub score: f64,
+    /// Title and body terms both issues contain, most significant first
+    pub shared_terms: Vec<String>,
+}
+
+/// An existing issue that resembles the candidate.
+#[derive(Debug, Clone, Serialize)]
+pub struct SimilarIssue {
+    /// Issue number
+    pub number: u64,
+    /// Issue title
+    pub title: String,
+    /// `open` or `closed`
+    pub state: String,
+    /// Issue web URL
+    pub html_url: String,
+    /// Label names
+    pub labels: Vec<String>,
+    /// Similarity between 0 and 1
+    pub score: f64,
+    /// Terms the two issues share
+    pub shared_terms: Vec<String>,
+}
+
+/// Request parameters for finding issues similar to a candidate
+#[derive(Debug, Clone)]
+pub struct FindSimilarIssuesRequest {
+    /// Repository owner (user or organization)
+    pub owner: String,
+    /// Repository name
+    pub repo: String,
+    /// Candidate title
+    pub title: String,
+    /// Candidate body
+    pub body: Option<String>,
+    /// Candidate labels
+    pub labels: Vec<String>,
+    /// Also consider closed issues
+    pub include_closed: bool,
+    /// Maximum number of results
+    pub limit: usize,
+    /// Drop results scoring below this
+    pub min_score: f64,
+}
+
+/// Lowercase word tokens without stopwords, numbers or one-letter words;
+/// a plural `s` is stripped so `crash` and `crashes` match.
+#[must_use]
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.chars().count() > 1 && !w.chars().all(|c| c.is_ascii_digit()))
+        .map(str::to_lowercase)
+        .filter(|w| !STOPWORDS.contains(&w.as_str()))
+        .map(|w| stem(&w))
+        .collect()
+}
+
+fn stem(word: &str) -> String {
+    if word.len() > 4 && word.ends_with("ies") {
+        format!("{}y", &word[..word.len() - 3])
+    } else if word.len() > 4 && (word.ends_with("shes") || word.ends_with("ches")) {
+        word[..word.len() - 2].to_string()
+    } else if word.len() > 3 && word.ends_with('s') && !word.ends_with("ss") {
+        word[..word.len() - 1].to_string()
+    } else {
+        word.to_string()
+    }
+}
+
+/// Search queries for candidates: title keywords restricted to titles, then
+/// the most frequent title and body keywords anywhere, OR-ed together.
+#[must_use]
+pub fn build_search_queries(
+    owner: &str,
+    repo: &str,
+    candidate: &IssueText,
+    include_closed: bool,
+) -> Vec<String> {
+    let scope = if include_closed {
+        format!("repo:{owner}/{repo} is:issue")
+    } else {
+        format!("repo:{owner}/{repo} is:issue is:open")
+    };
+
+    let title_terms = dedup(tokenize(&candidate.title));
+    let mut queries = Vec::new();
+    if !title_terms.is_empty() {
+        let keywords = title_terms.iter().take(QUERY_KEYWORDS).cloned();
+        queries.push(format!(
+            "{scope} in:title {}",
+            keywords.collect::<Vec<_>>().join(" ")
+        ));
+    }
+
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+    for term in candidate
+        .terms()
+        .into_iter()
+        .filter(|t| !t.starts_with("label:"))
+    {
+        *counts.entry(term).or_default() += 1;
+    }
+    let mut ranked: Vec<(String, usize)> = counts.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| b.0.len().cmp(&a.0.len())));
+    let keywords: Vec<String> = ranked
+        .into_iter()
+        .take(QUERY_KEYWORDS + 1)
+        .map(|(term, _)| term)
+        .collect();
+    if keywords.len() > 1 {
+        let query = format!("{scope} {}", keywords.join(" OR "));
+        if !queries.contains(&query) {
+            queries.push(query);
+        }
+    }
+    queries
+}
+
+/// Score each candidate against `query`, in the order given.
+///
+/// Document frequencies are taken over the query and the candidates, so
+/// words every candidate shares (the project name, say) count for little.
+#[must_use]
+pub fn score_similarity(query: &IssueText, candidates: &[IssueText]) -> Vec<SimilarityScore> {
+    let docs: Vec<Vec<String>> = std::iter::once(query)
+        .chain(candidates)
+        .map(IssueText::terms)
+        .collect();
+
+    let mut document_frequency: HashMap<&str, usize> = HashMap::new();
+    for doc in &docs {
+        for term in doc.iter().map(String::as_str).collect::<BTreeSet<_>>() {
+            *document_frequency.entry(term).or_default() += 1;
+        }
+    }
+    let total = docs.len() as f64;
+    let idf: HashMap<&str, f64> = document_frequency
+        .into_iter()
+        .map(|(term, df)| (term, (1.0 + total / (1 + df) as f64).ln()))
+        .collect();
+
+    let query_vector = tf_idf(&docs[0], &idf);
+    let query_norm = norm(&query_vector);
+    let query_title: BTreeSet<String> = tokenize(&query.title).into_iter().collect();
+
+    candidates
+        .iter()
+        .zip(&docs[1..])
+        .map(|(candidate, doc)| {
+            let candidate_vector = tf_idf(doc, &idf);
+            let dot: f64 = query_vector
+                .iter()
+                .filter_map(|(term, w)| candidate_vector.get(term).map(|c| w * c))
+                .sum();
+            let denominator = query_norm * norm(&candidate_vector);
+            let cosine = if denominator > 0.0 {
+                dot / denominator
+            } else {
+                0.0
+            };
+
+            let candidate_title: BTreeSet<String> =
+                tokenize(&candidate.title).into_iter().collect();
+            let union = query_title.union(&candidate_title).count();
+            let jaccard = if union > 0 {
+                query_title.intersection(&candidate_title).count() as f64 / union as f64
+            } else {
+                0.0
+            };
+
+            let mut shared: Vec<(&str, f64)> = query_vector
+                .iter()
+                .filter(|(term, _)| !term.starts_with("label:"))
+                .filter_map(|(term, w)| candidate_vector.get(term).map(|c| (*term, w * c)))
+                .collect();
+            shared.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+
+            SimilarityScore {
+                score: ((1.0 - TITLE_WEIGHT) * cosine + TITLE_WEIGHT * jaccard).clamp(0.0, 1.0),
+                shared_terms: shared
+                    .into_iter()
+                    .take(8)
+                    .map(|(t, _)| t.to_string())
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+fn tf_idf<'a>(doc: &'a [String], idf: &HashMap<&str, f64>) -> HashMap<&'a str, f64> {
+    let mut weights: HashMap<&str, f64> = HashMap::new();
+    for term in doc {
+        *weights.entry(term.as_str()).or_default() += 1.0;
+    }
+    for (term, weight) in &mut weights {
+        *weight *= idf.get(term).copied().unwrap_or_default();
+    }
+    weights
+}
+
+fn norm(vector: &HashMap<&str, f64>) -> f64 {
+    vector.values().map(|w| w * w).sum::<f64>().sqrt()
+}
+
+fn dedup(terms: Vec<String>) -> Vec<String> {
+    let mut seen = BTreeSet::new();
+    terms
+        .into_iter()
+        .filter(|t| seen.insert(t.clone()))
+        .collect()
+}
+
+/// Issues scoring at or above `threshold`, i.e. the ones that should stop a
+/// guarded issue creation.
+#[must_use]
+pub fn likely_duplicates(similar: Vec<SimilarIssue>, threshold: f64) -> Vec<SimilarIssue> {
+    similar
+        .into_iter()
+        .filter(|issue| issue.score >= threshold)
+        .collect()
+}
+
+/// Find existing issues that look like duplicates of a candidate, best first.
+pub(crate) fn find_similar_issues(
+    inner: Arc<Octocrab>,
+    request: FindSimilarIssuesRequest,
+) -> AsyncTask<Result<Vec<SimilarIssue>, GitHubError>> {
+    spawn_task(async move { similar_issues(&inner, &request).await })
+}
+
+/// Shared implementation used by the similarity search and the create guard.
+pub(crate) async fn similar_issues(
+    inner: &Octocrab,
+    request: &FindSimilarIssuesRequest,
+) -> Result<Vec<SimilarIssue>, GitHubError> {
+    let candidate = IssueText::new(
+        request.title.clone(),
+        request.body.clone().unwrap_or_default(),
+        request.labels.clone(),
+    );
+    let queries = build_search_queries(
+        &request.owner,
+        &request.repo,
+        &candidate,
+        request.include_closed,
+    );
+    if queries.is_empty() {
+        return Err(GitHubError::InvalidInput(
+            "Title has no searchable keywords".to_string(),
+        ));
+    }
+
+    let mut found = BTreeMap::new();
+    for query in &queries {
+        for issue in search_issues_page(inner, query, RESULTS_PER_QUERY).await? {
+            if issue.pull_request.is_none() {
+                found.entry(issue.number).or_insert(issue);
+            }
+        }
+    }
+    let issues: Vec<_> = found.into_values().collect();
+
+    let texts: Vec<IssueText> = issues
+        .iter()
+        .map(|i| {
+            IssueText::new(
+                i.title.clone(),
+                i.body.clone().unwrap_or_default(),
+                i.labels.iter().map(|l| l.name.clone()).collect(),
+            )
+        })
+        .collect();
+    let scores = score_similarity(&candidate, &texts);
+
+    let mut similar: Vec<SimilarIssue> = issues
+        .into_iter()
+        .zip(scores)
+        .filter(|(_, s)| s.score >= request.min_score)
+        .map(|(issue, s)| SimilarIssue {
+            number: issue.number,
+            title: issue.title,
+            state: match issue.state {
+                IssueState::Closed => "closed".to_string(),
+                _ => "open".to_string(),
+            },
+            html_url: issue.html_url.to_string(),
+            labels: issue.labels.into_iter().map(|l| l.name).collect(),
+            score: s.score,
+            shared_terms: s.shared_terms,
+        })
+        .collect();
+    similar.sort_by(|a, b| b.score.total_cmp(&a.score).then(a.number.cmp(&b.number)));
+    similar.truncate(request.limit);
+    Ok(similar)
+}
@@ -0,0 +1,165 @@
+//! Sticky comments: one bot comment per issue or PR, updated in place.
+//!
+//! The comment is identified by a hidden HTML marker holding a caller chosen
+//! key, plus its author, so several tools can each keep their own comment.
+
+use crate::github::comments::{CommentKind, send_update};
+use crate::github::{error::GitHubError, util::spawn_task};
+use crate::runtime::AsyncTask;
+use octocrab::{Octocrab, models::issues::Comment};
+use serde::Serialize;
+use std::sync::Arc;
+
+/// Hidden marker embedded in a sticky comment with the given key.
+#[must_use]
+pub fn sticky_marker(key: &str) -> String {
+    format!("<!-- sticky-comment:{} -->", key.trim())
+}
+
+/// Full body of a sticky comment: the marker line followed by `body`.
+#[must_use]
+pub fn render_sticky_body(key: &str, body: &str) -> String {
+    let marker = sticky_marker(key);
+    let body = body.replace(&marker, "");
+    format!("{marker}\n{}", body.trim_start_matches('\n'))
+}
+
+/// Whether a comment body carries the marker for `key`.
+#[must_use]
+pub fn has_sticky_marker(body: &str, key: &str) -> bool {
+    body.contains(&sticky_marker(key))
+}
+
+/// What an upsert did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StickyCommentAction {
+    /// No matching comment existed; a new one was posted.
+    Created,
+    /// The existing comment was edited.
+    Updated,
+    /// The existing comment already had this body.
+    Unchanged,
+}
+
+/// Result of upserting a sticky comment.
+#[derive(Debug, Clone)]
+pub struct StickyCommentResult {
+    /// The comment as it is now.
+    pub comment: Comment,
+    /// What was done.
+    pub action: StickyCommentAction,
+}
+
+/// Create or update the sticky comment for `key` on an issue or PR.
+///
+/// Only comments by `author` are considered, defaulting to the authenticated
+/// user. When several match, the most recent one is updated.
+pub(crate) fn upsert_sticky_comment(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    issue_number: u64,
+    key: impl Into<String>,
+    body: impl Into<String>,
+    author: Option<String>,
+) -> AsyncTask<Result<StickyCommentResult, GitHubError>> {
+    let (owner, repo, key, body) = (owner.into(), repo.into(), key.into(), body.into());
+
+    spawn_task(async move {
+        if key.trim().is_empty() || key.contains("-->") {
+            return Err(GitHubError::InvalidInput(
+                "Sticky comment key must be non-empty and must not contain '-->'".to_string(),
+            ));
+        }
+
+        let author = match author {
+            Some(a) => a,
+            None => {
+                inner
+                    .current()
+                    .user()
+                    .await
+                    .map_err(GitHubError::from)?
+                    .login
+            }
+        };
+        let full_body = render_sticky_body(&key, &body);
+
+        let existing =
+            find_sticky_comment(&inner, &owner, &repo, issue_number, &key, &author).await?;
+
+        match existing {
+            Some(comment)
+                if comment.body.as_deref().map(str::trim_end) == Some(full_body.trim_end()) =>
+            {
+                Ok(StickyCommentResult {
+                    comment,
+                    action: StickyCommentAction::Unchanged,
+                })
+            }
+            Some(comment) => {
+                let comment = send_update(
+                    &inner,
+                    &owner,
+                    &repo,
+                    CommentKind::Issue,
+                    comment.id.into_inner(),
+                    &full_body,
+                )
+                .await?;
+                Ok(StickyCommentResult {
+                    comment,
+                    action: StickyCommentAction::Updated,
+                })
+            }
+            None => {
+                let comment = inner
+                    .issues(&owner, &repo)
+                    .create_comment(issue_number, full_body)
+                    .await
+                    .map_err(GitHubError::from)?;
+                Ok(StickyCommentResult {
+                    comment,
+                    action: StickyCommentAction::Created,
+                })
+            }
+        }
+    })
+}
+
+/// Most recent comment by `author` carrying the marker for `key`.
+async fn find_sticky_comment(
+    inner: &Octocrab,
+    owner: &str,
+    repo: &str,
+    issue_number: u64,
+    key: &str,
+    author: &str,
+) -> Result<Option<Comment>, GitHubError> {
+    let mut page = inner
+        .issues(owner, repo)
+        .list_comments(issue_number)
+        .per_page(100)
+        .send()
+        .await
+        .map_err(GitHubError::from)?;
+
+    let mut found = None;
+    loop {
+        for comment in page.items {
+            if comment.user.login.eq_ignore_ascii_case(author)
+                && comment
+                    .body
+                    .as_deref()
+                    .is_some_and(|b| has_sticky_marker(b, key))
+            {
+                found = Some(comment);
+            }
+        }
+        match inner.get_page::<Comment>(&page.next).await? {
+            Some(next) => page = next,
+            None => return Ok(found),
+        }
+    }
+}
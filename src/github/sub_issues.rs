@@ -0,0 +1,436 @@
+//! Sub-issues and issue dependencies.
+//!
+//! The REST endpoints address the related issue by its database ID rather
+//! than its number, and sub-issues may live in other repositories, so related
+//! issues are passed around as [`IssueRef`]s and resolved before each call.
+
+use crate::github::error::GitHubError;
+use crate::github::util::{delete_no_content, spawn_task};
+use crate::runtime::{AsyncStream, AsyncTask, EmitterBuilder};
+use octocrab::{Octocrab, Page, models::issues::Issue};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// Deepest sub-issue tree `get_issue_tree` will walk.
+pub const MAX_ISSUE_TREE_DEPTH: usize = 8;
+
+/// An issue in some repository.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct IssueRef {
+    /// Repository owner
+    pub owner: String,
+    /// Repository name
+    pub repo: String,
+    /// Issue number
+    pub number: u64,
+}
+
+impl IssueRef {
+    /// Build a reference from its parts.
+    pub fn new(owner: impl Into<String>, repo: impl Into<String>, number: u64) -> Self {
+        Self {
+            owner: owner.into(),
+            repo: repo.into(),
+            number,
+        }
+    }
+
+    /// Parse `123`, `#123`, `owner/repo#123` or an issue URL, resolving bare
+    /// numbers against the default repository.
+    pub fn parse(s: &str, default_owner: &str, default_repo: &str) -> Result<Self, GitHubError> {
+        let invalid = || {
+            GitHubError::InvalidInput(format!(
+                "Invalid issue reference '{s}', expected 123, owner/repo#123 or an issue URL"
+            ))
+        };
+        let s = s.trim();
+
+        if let Some(path) = s
+            .strip_prefix("https://github.com/")
+            .or_else(|| s.strip_prefix("http://github.com/"))
+        {
+            let path = path.split(['#', '?']).next().unwrap_or_default();
+            let parts: Vec<&str> = path.trim_end_matches('/').split('/').collect();
+            return match parts.as_slice() {
+                [owner, repo, "issues" | "pull", number] => Ok(Self::new(
+                    *owner,
+                    *repo,
+                    number.parse().map_err(|_| invalid())?,
+                )),
+                _ => Err(invalid()),
+            };
+        }
+
+        let (repo_part, number) = match s.rsplit_once('#') {
+            Some((repo_part, number)) => (repo_part, number),
+            None => ("", s),
+        };
+        let number = number.parse::<u64>().map_err(|_| invalid())?;
+
+        if repo_part.is_empty() {
+            return Ok(Self::new(default_owner, default_repo, number));
+        }
+        match repo_part.split_once('/') {
+            Some((owner, repo)) if !owner.is_empty() && !repo.is_empty() && !repo.contains('/') => {
+                Ok(Self::new(owner, repo, number))
+            }
+            _ => Err(invalid()),
+        }
+    }
+
+    /// Reference for an issue returned by the API, using its `repository_url`.
+    #[must_use]
+    pub fn from_issue(issue: &Issue) -> Option<Self> {
+        Self::from_repository_url(issue.repository_url.as_str(), issue.number)
+    }
+
+    /// Reference from an API `repository_url` (`.../repos/{owner}/{repo}`).
+    #[must_use]
+    pub fn from_repository_url(url: &str, number: u64) -> Option<Self> {
+        let (_, path) = url.split_once("/repos/")?;
+        let (owner, repo) = path.trim_end_matches('/').split_once('/')?;
+        (!owner.is_empty() && !repo.is_empty() && !repo.contains('/'))
+            .then(|| Self::new(owner, repo, number))
+    }
+
+    fn issue_path(&self) -> String {
+        format!("/repos/{}/{}/issues/{}", self.owner, self.repo, self.number)
+    }
+}
+
+impl fmt::Display for IssueRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}#{}", self.owner, self.repo, self.number)
+    }
+}
+
+/// Where to move a sub-issue within its parent's list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubIssuePosition {
+    /// Directly before this sibling
+    Before(IssueRef),
+    /// Directly after this sibling
+    After(IssueRef),
+}
+
+/// Which way a dependency points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DependencyKind {
+    /// Issues that must be resolved before this one
+    BlockedBy,
+    /// Issues waiting on this one
+    Blocking,
+}
+
+impl DependencyKind {
+    /// API path segment below `/dependencies/`.
+    #[must_use]
+    pub fn path(self) -> &'static str {
+        match self {
+            Self::BlockedBy => "blocked_by",
+            Self::Blocking => "blocking",
+        }
+    }
+}
+
+/// An issue and its sub-issues, recursively.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueTreeNode {
+    /// The issue
+    pub issue: IssueRef,
+    /// Issue title
+    pub title: String,
+    /// Whether the issue is closed
+    pub closed: bool,
+    /// Issue web URL
+    pub html_url: String,
+    /// Sub-issues, in their configured order
+    pub children: Vec<IssueTreeNode>,
+    /// Whether children were left out because the depth limit was reached
+    pub truncated: bool,
+}
+
+impl IssueTreeNode {
+    fn from_issue(issue: &Issue, fallback: &IssueRef) -> Self {
+        Self {
+            issue: IssueRef::from_issue(issue).unwrap_or_else(|| fallback.clone()),
+            title: issue.title.clone(),
+            closed: matches!(issue.state, octocrab::models::IssueState::Closed),
+            html_url: issue.html_url.to_string(),
+            children: Vec::new(),
+            truncated: false,
+        }
+    }
+
+    /// Closed and total descendants, not counting this node.
+    #[must_use]
+    pub fn progress(&self) -> (usize, usize) {
+        self.children.iter().fold((0, 0), |(closed, total), child| {
+            let (c, t) = child.progress();
+            (closed + c + usize::from(child.closed), total + t + 1)
+        })
+    }
+
+    /// Render as a nested Markdown task list.
+    #[must_use]
+    pub fn render_markdown(&self) -> String {
+        let mut out = String::new();
+        self.render_into(&mut out, 0);
+        out
+    }
+
+    fn render_into(&self, out: &mut String, depth: usize) {
+        let indent = "  ".repeat(depth);
+        let check = if self.closed { 'x' } else { ' ' };
+        out.push_str(&format!(
+            "{indent}- [{check}] {} {}",
+            self.issue, self.title
+        ));
+        let (closed, total) = self.progress();
+        if total > 0 {
+            out.push_str(&format!(" ({closed}/{total})"));
+        }
+        if self.truncated {
+            out.push_str(" …");
+        }
+        out.push('\n');
+        for child in &self.children {
+            child.render_into(out, depth + 1);
+        }
+    }
+}
+
+/// Stream the sub-issues of an issue, in order.
+pub(crate) fn list_sub_issues(
+    inner: Arc<Octocrab>,
+    issue: IssueRef,
+) -> AsyncStream<Result<Issue, GitHubError>> {
+    let builder = EmitterBuilder::new(Box::new(move || {
+        Box::pin(async move {
+            fetch_issue_list(&inner, format!("{}/sub_issues", issue.issue_path())).await
+        })
+    }));
+    builder.emit(|v| v, |_| {})
+}
+
+/// Make `sub_issue` a sub-issue of `parent`.
+///
+/// With `replace_parent`, an issue that already has a parent is moved;
+/// otherwise GitHub rejects it.
+pub(crate) fn add_sub_issue(
+    inner: Arc<Octocrab>,
+    parent: IssueRef,
+    sub_issue: IssueRef,
+    replace_parent: bool,
+) -> AsyncTask<Result<Issue, GitHubError>> {
+    spawn_task(async move {
+        let sub_issue_id = issue_id(&inner, &sub_issue).await?;
+        inner
+            .post(
+                format!("{}/sub_issues", parent.issue_path()),
+                Some(&serde_json::json!({
+                    "sub_issue_id": sub_issue_id,
+                    "replace_parent": replace_parent,
+                })),
+            )
+            .await
+            .map_err(GitHubError::from)
+    })
+}
+
+/// Detach `sub_issue` from `parent`. The issue itself is kept.
+pub(crate) fn remove_sub_issue(
+    inner: Arc<Octocrab>,
+    parent: IssueRef,
+    sub_issue: IssueRef,
+) -> AsyncTask<Result<Issue, GitHubError>> {
+    spawn_task(async move {
+        let sub_issue_id = issue_id(&inner, &sub_issue).await?;
+        inner
+            .delete(
+                format!("{}/sub_issue", parent.issue_path()),
+                Some(&serde_json::json!({ "sub_issue_id": sub_issue_id })),
+            )
+            .await
+            .map_err(GitHubError::from)
+    })
+}
+
+/// Move a sub-issue before or after one of its siblings.
+pub(crate) fn reprioritize_sub_issue(
+    inner: Arc<Octocrab>,
+    parent: IssueRef,
+    sub_issue: IssueRef,
+    position: SubIssuePosition,
+) -> AsyncTask<Result<Issue, GitHubError>> {
+    spawn_task(async move {
+        let sub_issue_id = issue_id(&inner, &sub_issue).await?;
+        let (field, sibling) = match &position {
+            SubIssuePosition::Before(s) => ("before_id", s),
+            SubIssuePosition::After(s) => ("after_id", s),
+        };
+        let sibling_id = issue_id(&inner, sibling).await?;
+
+        let mut body = serde_json::json!({ "sub_issue_id": sub_issue_id });
+        body[field] = sibling_id.into();
+        inner
+            .patch(
+                format!("{}/sub_issues/priority", parent.issue_path()),
+                Some(&body),
+            )
+            .await
+            .map_err(GitHubError::from)
+    })
+}
+
+/// The parent of an issue, or `None` when it has none.
+pub(crate) fn get_parent_issue(
+    inner: Arc<Octocrab>,
+    issue: IssueRef,
+) -> AsyncTask<Result<Option<Issue>, GitHubError>> {
+    spawn_task(async move { fetch_parent(&inner, &issue).await })
+}
+
+/// Walk the sub-issue tree below an issue, up to `max_depth` levels
+/// (capped at [`MAX_ISSUE_TREE_DEPTH`]).
+///
+/// Also returns the issue's parent, if any.
+pub(crate) fn get_issue_tree(
+    inner: Arc<Octocrab>,
+    root: IssueRef,
+    max_depth: usize,
+) -> AsyncTask<Result<(IssueTreeNode, Option<IssueRef>), GitHubError>> {
+    spawn_task(async move {
+        let max_depth = max_depth.clamp(1, MAX_ISSUE_TREE_DEPTH);
+        let root_issue = inner
+            .issues(&root.owner, &root.repo)
+            .get(root.number)
+            .await
+            .map_err(GitHubError::from)?;
+        let parent = fetch_parent(&inner, &root)
+            .await?
+            .and_then(|p| IssueRef::from_issue(&p));
+
+        let mut tree = IssueTreeNode::from_issue(&root_issue, &root);
+        fill_tree(&inner, &mut tree, 0, max_depth).await?;
+        Ok((tree, parent))
+    })
+}
+
+/// List the issues an issue is blocked by, or is blocking.
+pub(crate) fn list_issue_dependencies(
+    inner: Arc<Octocrab>,
+    issue: IssueRef,
+    kind: DependencyKind,
+) -> AsyncTask<Result<Vec<Issue>, GitHubError>> {
+    spawn_task(async move {
+        fetch_issue_list(
+            &inner,
+            format!("{}/dependencies/{}", issue.issue_path(), kind.path()),
+        )
+        .await
+    })
+}
+
+/// Record that `issue` is blocked by `blocker`.
+pub(crate) fn add_blocked_by(
+    inner: Arc<Octocrab>,
+    issue: IssueRef,
+    blocker: IssueRef,
+) -> AsyncTask<Result<Issue, GitHubError>> {
+    spawn_task(async move {
+        let blocker_id = issue_id(&inner, &blocker).await?;
+        inner
+            .post(
+                format!("{}/dependencies/blocked_by", issue.issue_path()),
+                Some(&serde_json::json!({ "issue_id": blocker_id })),
+            )
+            .await
+            .map_err(GitHubError::from)
+    })
+}
+
+/// Remove a blocked-by relation.
+pub(crate) fn remove_blocked_by(
+    inner: Arc<Octocrab>,
+    issue: IssueRef,
+    blocker: IssueRef,
+) -> AsyncTask<Result<(), GitHubError>> {
+    spawn_task(async move {
+        let blocker_id = issue_id(&inner, &blocker).await?;
+        delete_no_content(
+            &inner,
+            format!(
+                "{}/dependencies/blocked_by/{blocker_id}",
+                issue.issue_path()
+            ),
+        )
+        .await
+    })
+}
+
+/// Fetch the sub-issues of `node`, recursing until `max_depth`. Nodes at the
+/// limit that still have sub-issues are marked as truncated.
+fn fill_tree<'a>(
+    inner: &'a Octocrab,
+    node: &'a mut IssueTreeNode,
+    depth: usize,
+    max_depth: usize,
+) -> Pin<Box<dyn Future<Output = Result<(), GitHubError>> + Send + 'a>> {
+    Box::pin(async move {
+        let children =
+            fetch_issue_list(inner, format!("{}/sub_issues", node.issue.issue_path())).await?;
+        if depth >= max_depth {
+            node.truncated = !children.is_empty();
+            return Ok(());
+        }
+
+        for child in &children {
+            let fallback = IssueRef::new(&node.issue.owner, &node.issue.repo, child.number);
+            let mut child_node = IssueTreeNode::from_issue(child, &fallback);
+            fill_tree(inner, &mut child_node, depth + 1, max_depth).await?;
+            node.children.push(child_node);
+        }
+        Ok(())
+    })
+}
+
+async fn issue_id(inner: &Octocrab, issue: &IssueRef) -> Result<u64, GitHubError> {
+    let found = inner
+        .issues(&issue.owner, &issue.repo)
+        .get(issue.number)
+        .await
+        .map_err(GitHubError::from)?;
+    Ok(found.id.into_inner())
+}
+
+async fn fetch_parent(inner: &Octocrab, issue: &IssueRef) -> Result<Option<Issue>, GitHubError> {
+    match inner
+        .get::<Issue, _, _>(format!("{}/parent", issue.issue_path()), None::<&()>)
+        .await
+    {
+        Ok(parent) => Ok(Some(parent)),
+        Err(octocrab::Error::GitHub { source, .. }) if source.status_code.as_u16() == 404 => {
+            Ok(None)
+        }
+        Err(e) => Err(GitHubError::from(e)),
+    }
+}
+
+async fn fetch_issue_list(inner: &Octocrab, route: String) -> Result<Vec<Issue>, GitHubError> {
+    let mut page: Page<Issue> = inner
+        .get(format!("{route}?per_page=100"), None::<&()>)
+        .await
+        .map_err(GitHubError::from)?;
+
+    let mut issues = std::mem::take(&mut page.items);
+    while let Some(next) = inner.get_page::<Issue>(&page.next).await? {
+        page = next;
+        issues.append(&mut page.items);
+    }
+    Ok(issues)
+}
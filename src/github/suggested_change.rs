@@ -0,0 +1,150 @@
+//! Suggested-change review comments validated against the pull request diff.
+
+use crate::github::error::GitHubError;
+use crate::github::get_pull_request_diff::{
+    DiffSide, FileDiffStatus, PullRequestDiff, fetch_pull_request_diff,
+};
+use octocrab::Octocrab;
+use serde::{Deserialize, Serialize};
+
+/// Replacement text for a line range in the head of a pull request.
+///
+/// Rendered as a GitHub ```` ```suggestion ```` block that the author can
+/// apply with one click. Suggestions always target the `RIGHT` side.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuggestedChange {
+    /// File path in the head revision.
+    pub path: String,
+    /// First line of the range, `None` for a single line.
+    pub start_line: Option<u32>,
+    /// Last line of the range (inclusive).
+    pub line: u32,
+    /// Text replacing the range. Empty deletes the lines.
+    pub replacement: String,
+    /// Optional explanation shown above the suggestion.
+    pub comment: Option<String>,
+}
+
+impl SuggestedChange {
+    /// Suggest replacing a single line.
+    #[must_use]
+    pub fn new(path: impl Into<String>, line: u32, replacement: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            start_line: None,
+            line,
+            replacement: replacement.into(),
+            comment: None,
+        }
+    }
+
+    /// Comment body containing the suggestion block.
+    ///
+    /// The fence is lengthened when the replacement itself contains backticks
+    /// so nested code fences survive.
+    #[must_use]
+    pub fn body(&self) -> String {
+        let longest_run = self
+            .replacement
+            .split(|c| c != '`')
+            .map(str::len)
+            .max()
+            .unwrap_or(0);
+        let fence = "`".repeat(longest_run.max(2) + 1);
+
+        let replacement = self
+            .replacement
+            .strip_suffix('\n')
+            .unwrap_or(&self.replacement);
+        let block = if self.replacement.is_empty() {
+            format!("{fence}suggestion\n{fence}")
+        } else {
+            format!("{fence}suggestion\n{replacement}\n{fence}")
+        };
+
+        match self.comment.as_deref().map(str::trim) {
+            Some(comment) if !comment.is_empty() => format!("{comment}\n\n{block}"),
+            _ => block,
+        }
+    }
+
+    /// Check that the range can carry a suggestion on this diff.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the problem if the file is not in the diff,
+    /// has no text hunks on the head side, or the range leaves a single hunk.
+    pub fn validate(&self, diff: &PullRequestDiff) -> Result<(), String> {
+        let start = self.start_line.unwrap_or(self.line);
+        let location = if start == self.line {
+            format!("{}:{}", self.path, self.line)
+        } else {
+            format!("{}:{}-{}", self.path, start, self.line)
+        };
+
+        let Some(file) = diff.file(&self.path) else {
+            return Err(format!(
+                "{location}: file is not changed in this pull request"
+            ));
+        };
+        if file.binary {
+            return Err(format!("{location}: binary files cannot take suggestions"));
+        }
+        if file.status == FileDiffStatus::Removed {
+            return Err(format!("{location}: file is deleted in this pull request"));
+        }
+        if start > self.line {
+            return Err(format!("{location}: start_line is after line"));
+        }
+        if !file.range_in_one_hunk(DiffSide::Right, start, self.line) {
+            return Err(format!(
+                "{location}: range must lie within a single diff hunk on the RIGHT side"
+            ));
+        }
+        Ok(())
+    }
+
+    /// Review comment payload for the pull request reviews endpoint.
+    #[must_use]
+    pub fn to_review_comment(&self) -> serde_json::Value {
+        let mut comment = serde_json::json!({
+            "path": self.path,
+            "body": self.body(),
+            "line": self.line,
+            "side": DiffSide::Right.as_str(),
+        });
+        if let Some(start) = self.start_line.filter(|&s| s != self.line) {
+            comment["start_line"] = serde_json::json!(start);
+            comment["start_side"] = serde_json::json!(DiffSide::Right.as_str());
+        }
+        comment
+    }
+}
+
+/// Fetch the PR diff and validate every suggestion, reporting all failures at once.
+pub(crate) async fn validate_suggestions(
+    inner: &Octocrab,
+    owner: &str,
+    repo: &str,
+    pr_number: u64,
+    suggestions: &[SuggestedChange],
+) -> Result<(), GitHubError> {
+    if suggestions.is_empty() {
+        return Ok(());
+    }
+
+    let diff = fetch_pull_request_diff(inner, owner, repo, pr_number).await?;
+    let problems: Vec<String> = suggestions
+        .iter()
+        .filter_map(|s| s.validate(&diff).err())
+        .collect();
+
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(GitHubError::InvalidInput(format!(
+            "Invalid suggestion(s): {}",
+            problems.join("; ")
+        )))
+    }
+}
@@ -1,5 +1,6 @@
 //! GitHub Issue update operation.
 
+use crate::github::issue_moderation::CloseReason;
 use crate::github::{error::GitHubError, util::spawn_task};
 use crate::runtime::AsyncTask;
 use octocrab::{Octocrab, models::IssueState, models::issues::Issue};
@@ -24,8 +25,12 @@ pub struct UpdateIssueRequest {
     pub labels: Option<Vec<String>>,
     /// New assignees
     pub assignees: Option<Vec<String>>,
-    /// New milestone number
+    /// Milestone number to assign the issue or PR to
     pub milestone: Option<u64>,
+    /// Remove the current milestone (ignored when `milestone` is set)
+    pub clear_milestone: bool,
+    /// Close the issue with this reason (implies `state: closed`)
+    pub state_reason: Option<CloseReason>,
 }
 
 /// Update an existing issue.
@@ -56,6 +61,28 @@ pub(crate) fn update_issue(
             req = req.milestone(ms);
         }
 
-        req.send().await.map_err(GitHubError::from)
+        let issue = req.send().await.map_err(GitHubError::from)?;
+
+        // The update builder can neither send an explicit null nor a state reason
+        let mut extra = serde_json::Map::new();
+        if request.clear_milestone && request.milestone.is_none() {
+            extra.insert("milestone".to_string(), serde_json::Value::Null);
+        }
+        if let Some(reason) = request.state_reason {
+            extra.insert("state".to_string(), "closed".into());
+            extra.insert("state_reason".to_string(), reason.as_str().into());
+        }
+        if extra.is_empty() {
+            return Ok(issue);
+        }
+
+        let (owner, repo, number) = (&request.owner, &request.repo, request.issue_number);
+        inner
+            .patch(
+                format!("/repos/{owner}/{repo}/issues/{number}"),
+                Some(&serde_json::Value::Object(extra)),
+            )
+            .await
+            .map_err(GitHubError::from)
     })
 }
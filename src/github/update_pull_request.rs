@@ -1,8 +1,9 @@
 //! GitHub Pull Request update operation.
 
-use crate::github::{error::GitHubError, util::spawn_task};
+use crate::github::error::GitHubError;
+use crate::github::util::{graphql, spawn_task};
 use crate::runtime::AsyncTask;
-use octocrab::{Octocrab, models::pulls::PullRequest, params};
+use octocrab::{Octocrab, models::pulls::PullRequest, params, pulls::PullRequestHandler};
 use std::sync::Arc;
 
 /// Options for updating a pull request.
@@ -18,8 +19,31 @@ pub struct UpdatePullRequestOptions {
     pub base: Option<String>,
     /// Whether maintainer can modify the PR.
     pub maintainer_can_modify: Option<bool>,
+    /// Convert to draft (`true`) or mark ready for review (`false`).
+    /// REST cannot change this, so it is applied through GraphQL.
+    pub draft: Option<bool>,
 }
 
+impl UpdatePullRequestOptions {
+    fn has_rest_changes(&self) -> bool {
+        self.title.is_some()
+            || self.body.is_some()
+            || self.state.is_some()
+            || self.base.is_some()
+            || self.maintainer_can_modify.is_some()
+    }
+}
+
+const MARK_READY_MUTATION: &str = r"
+mutation($id: ID!) {
+  markPullRequestReadyForReview(input: { pullRequestId: $id }) { pullRequest { isDraft } }
+}";
+
+const CONVERT_TO_DRAFT_MUTATION: &str = r"
+mutation($id: ID!) {
+  convertPullRequestToDraft(input: { pullRequestId: $id }) { pullRequest { isDraft } }
+}";
+
 /// Update an existing pull-request.
 pub(crate) fn update_pull_request(
     inner: Arc<Octocrab>,
@@ -31,25 +55,64 @@ pub(crate) fn update_pull_request(
     let (owner, repo) = (owner.into(), repo.into());
 
     spawn_task(async move {
+        let draft = options.draft;
         let pulls_handler = inner.pulls(&owner, &repo);
-        let mut req = pulls_handler.update(pr_number);
-
-        if let Some(t) = options.title {
-            req = req.title(t);
-        }
-        if let Some(b) = options.body {
-            req = req.body(b);
-        }
-        if let Some(s) = options.state {
-            req = req.state(s);
-        }
-        if let Some(bs) = options.base {
-            req = req.base(bs);
-        }
-        if let Some(mcm) = options.maintainer_can_modify {
-            req = req.maintainer_can_modify(mcm);
-        }
-
-        req.send().await.map_err(GitHubError::from)
+
+        let pr = if options.has_rest_changes() {
+            send_update(&pulls_handler, pr_number, options).await?
+        } else {
+            pulls_handler
+                .get(pr_number)
+                .await
+                .map_err(GitHubError::from)?
+        };
+
+        let Some(draft) = draft.filter(|&d| pr.draft != Some(d)) else {
+            return Ok(pr);
+        };
+
+        let node_id = pr
+            .node_id
+            .clone()
+            .ok_or_else(|| GitHubError::Api(format!("PR #{pr_number} has no node ID")))?;
+        let mutation = if draft {
+            CONVERT_TO_DRAFT_MUTATION
+        } else {
+            MARK_READY_MUTATION
+        };
+        let _: serde_json::Value =
+            graphql(&inner, mutation, serde_json::json!({ "id": node_id })).await?;
+
+        // Re-fetch so the returned PR reflects the new draft state
+        pulls_handler
+            .get(pr_number)
+            .await
+            .map_err(GitHubError::from)
     })
 }
+
+async fn send_update(
+    pulls_handler: &PullRequestHandler<'_>,
+    pr_number: u64,
+    options: UpdatePullRequestOptions,
+) -> Result<PullRequest, GitHubError> {
+    let mut req = pulls_handler.update(pr_number);
+
+    if let Some(t) = options.title {
+        req = req.title(t);
+    }
+    if let Some(b) = options.body {
+        req = req.body(b);
+    }
+    if let Some(s) = options.state {
+        req = req.state(s);
+    }
+    if let Some(bs) = options.base {
+        req = req.base(bs);
+    }
+    if let Some(mcm) = options.maintainer_can_modify {
+        req = req.maintainer_can_modify(mcm);
+    }
+
+    req.send().await.map_err(GitHubError::from)
+}
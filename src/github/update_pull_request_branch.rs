@@ -0,0 +1,200 @@
+//! GitHub Pull Request branch update operation.
+
+use crate::github::{check_merge_readiness::MergeBlocker, error::GitHubError, util::spawn_task};
+use crate::runtime::AsyncTask;
+use gix::merge::blob::builtin_driver::text::Labels;
+use gix::merge::tree::TreatAsUnresolved;
+use octocrab::{Octocrab, models::pulls::PullRequest};
+use serde::Serialize;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use tempfile::TempDir;
+
+/// Options for updating a pull request branch with its base.
+#[derive(Debug, Clone, Default)]
+pub struct UpdatePullRequestBranchOptions {
+    /// SHA the head branch must still point at for the update to proceed.
+    /// If it has moved, the update fails with `GitHubError::MergeBlocked`.
+    pub expected_head_sha: Option<String>,
+    /// When GitHub cannot update the branch, merge base into head in a
+    /// temporary local clone to find out which paths conflict.
+    ///
+    /// The clone uses the configured git credential helpers for authentication.
+    pub local_fallback: bool,
+}
+
+/// Outcome of bringing a pull request branch up to date.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum UpdateBranchOutcome {
+    /// GitHub accepted the update and is merging base into head asynchronously.
+    Scheduled { message: String },
+    /// GitHub refused and the local merge hit conflicts in these paths.
+    Conflicts {
+        base_sha: String,
+        head_sha: String,
+        paths: Vec<String>,
+    },
+    /// GitHub refused but base merges cleanly into head locally.
+    ///
+    /// Usually a permissions problem, e.g. the head lives in a fork that
+    /// does not allow maintainer edits.
+    MergesCleanly {
+        base_sha: String,
+        head_sha: String,
+        reason: String,
+    },
+}
+
+/// Update a pull request branch by merging its base into it.
+pub(crate) fn update_pull_request_branch(
+    inner: Arc<Octocrab>,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    pr_number: u64,
+    options: UpdatePullRequestBranchOptions,
+) -> AsyncTask<Result<UpdateBranchOutcome, GitHubError>> {
+    let (owner, repo) = (owner.into(), repo.into());
+
+    spawn_task(async move {
+        let mut body = serde_json::json!({});
+        if let Some(sha) = &options.expected_head_sha {
+            body["expected_head_sha"] = serde_json::json!(sha);
+        }
+
+        let url = format!("/repos/{owner}/{repo}/pulls/{pr_number}/update-branch");
+        let result: Result<serde_json::Value, octocrab::Error> = inner.put(url, Some(&body)).await;
+
+        let reason = match result {
+            Ok(response) => {
+                let message = response
+                    .get("message")
+                    .and_then(|m| m.as_str())
+                    .unwrap_or("Updating pull request branch.")
+                    .to_string();
+                return Ok(UpdateBranchOutcome::Scheduled { message });
+            }
+            // 422 covers both merge conflicts and a stale expected_head_sha;
+            // only the former is worth a local merge.
+            Err(octocrab::Error::GitHub { source, .. })
+                if source.status_code.as_u16() == 422
+                    && source.message.to_lowercase().contains("expected head sha") =>
+            {
+                let Some(expected) = options.expected_head_sha else {
+                    return Err(GitHubError::Api(source.message));
+                };
+                let pr: PullRequest = inner
+                    .pulls(&owner, &repo)
+                    .get(pr_number)
+                    .await
+                    .map_err(GitHubError::from)?;
+                return Err(GitHubError::MergeBlocked(vec![MergeBlocker::HeadChanged {
+                    expected,
+                    actual: pr.head.sha,
+                }]));
+            }
+            Err(octocrab::Error::GitHub { source, .. })
+                if options.local_fallback && source.status_code.as_u16() == 422 =>
+            {
+                source.message
+            }
+            Err(e) => return Err(GitHubError::from(e)),
+        };
+
+        let pr: PullRequest = inner
+            .pulls(&owner, &repo)
+            .get(pr_number)
+            .await
+            .map_err(GitHubError::from)?;
+
+        let clone_url = pr
+            .base
+            .repo
+            .as_ref()
+            .and_then(|r| r.clone_url.as_ref())
+            .map(|u| u.to_string())
+            .ok_or_else(|| GitHubError::Api("Base repository has no clone URL".to_string()))?;
+        let base_ref = pr.base.ref_field.clone();
+        let head_sha = pr.head.sha.clone();
+
+        let merge = tokio::task::spawn_blocking(move || {
+            merge_locally(&clone_url, &base_ref, &head_sha, pr_number)
+        })
+        .await
+        .map_err(|e| GitHubError::Other(format!("Local merge task failed: {e}")))??;
+
+        Ok(if merge.paths.is_empty() {
+            UpdateBranchOutcome::MergesCleanly {
+                base_sha: merge.base_sha,
+                head_sha: merge.head_sha,
+                reason,
+            }
+        } else {
+            UpdateBranchOutcome::Conflicts {
+                base_sha: merge.base_sha,
+                head_sha: merge.head_sha,
+                paths: merge.paths,
+            }
+        })
+    })
+}
+
+struct LocalMerge {
+    base_sha: String,
+    head_sha: String,
+    paths: Vec<String>,
+}
+
+/// Bare-clone the base repository and merge the base branch into the PR head.
+fn merge_locally(
+    clone_url: &str,
+    base_ref: &str,
+    head_sha: &str,
+    pr_number: u64,
+) -> Result<LocalMerge, GitHubError> {
+    let local_err = |e: &dyn std::fmt::Display| GitHubError::Other(format!("Local merge: {e}"));
+
+    let temp_dir = TempDir::new().map_err(|e| local_err(&e))?;
+    let pull_refspec = format!("+refs/pull/{pr_number}/head:refs/pull/{pr_number}/head");
+
+    let mut prep = gix::prepare_clone_bare(clone_url, temp_dir.path())
+        .map_err(|e| local_err(&e))?
+        .configure_remote(move |remote| {
+            Ok(remote.with_refspecs([pull_refspec.as_str()], gix::remote::Direction::Fetch)?)
+        });
+    let (repo, _) = prep
+        .fetch_only(gix::progress::Discard, &AtomicBool::new(false))
+        .map_err(|e| local_err(&e))?;
+
+    let base_id = repo
+        .rev_parse_single(format!("refs/heads/{base_ref}").as_str())
+        .map_err(|e| local_err(&e))?
+        .detach();
+    let head_id = gix::ObjectId::from_hex(head_sha.as_bytes()).map_err(|e| local_err(&e))?;
+
+    let options = repo.tree_merge_options().map_err(|e| local_err(&e))?;
+    let labels = Labels {
+        ancestor: None,
+        current: Some("head".into()),
+        other: Some(base_ref.into()),
+    };
+    let outcome = repo
+        .merge_commits(head_id, base_id, labels, options.into())
+        .map_err(|e| local_err(&e))?;
+
+    let mut paths: Vec<String> = outcome
+        .tree_merge
+        .conflicts
+        .iter()
+        .filter(|c| c.is_unresolved(TreatAsUnresolved::git()))
+        .map(|c| c.ours.location().to_string())
+        .collect();
+    paths.sort();
+    paths.dedup();
+
+    Ok(LocalMerge {
+        base_sha: base_id.to_string(),
+        head_sha: head_sha.to_string(),
+        paths,
+    })
+}
@@ -15,3 +15,80 @@ where
 {
     AsyncTask::spawn_async(work)
 }
+
+/// Run a GraphQL query and deserialize its `data` payload.
+///
+/// GraphQL reports failures in an `errors` array alongside a 200 response,
+/// so those are surfaced as `GitHubError::Api` here rather than by Octocrab.
+pub(crate) async fn graphql<T>(
+    inner: &octocrab::Octocrab,
+    query: &str,
+    variables: serde_json::Value,
+) -> Result<T, crate::github::error::GitHubError>
+where
+    T: serde::de::DeserializeOwned,
+{
+    use crate::github::error::GitHubError;
+
+    let response: serde_json::Value = inner
+        .graphql(&serde_json::json!({ "query": query, "variables": variables }))
+        .await
+        .map_err(GitHubError::from)?;
+
+    if let Some(errors) = response.get("errors").and_then(|e| e.as_array())
+        && !errors.is_empty()
+    {
+        let messages = errors
+            .iter()
+            .filter_map(|e| e.get("message").and_then(|m| m.as_str()))
+            .collect::<Vec<_>>()
+            .join("; ");
+        return Err(GitHubError::Api(format!("GraphQL error: {messages}")));
+    }
+
+    let data = response
+        .get("data")
+        .cloned()
+        .ok_or_else(|| GitHubError::Api("GraphQL response missing data".to_string()))?;
+
+    serde_json::from_value(data)
+        .map_err(|e| GitHubError::Api(format!("Unexpected GraphQL response: {e}")))
+}
+
+/// Send a DELETE for endpoints that answer `204 No Content`.
+///
+/// `Octocrab::delete` always deserializes the response body, which fails on
+/// an empty one.
+pub(crate) async fn delete_no_content(
+    inner: &octocrab::Octocrab,
+    route: String,
+) -> Result<(), crate::github::error::GitHubError> {
+    use crate::github::error::GitHubError;
+
+    let response = inner
+        ._delete(route, None::<&()>)
+        .await
+        .map_err(GitHubError::from)?;
+    octocrab::map_github_error(response)
+        .await
+        .map(drop)
+        .map_err(GitHubError::from)
+}
+
+/// Send a PUT for endpoints that answer `204 No Content`.
+pub(crate) async fn put_no_content<P>(
+    inner: &octocrab::Octocrab,
+    route: String,
+    body: Option<&P>,
+) -> Result<(), crate::github::error::GitHubError>
+where
+    P: serde::Serialize + ?Sized,
+{
+    use crate::github::error::GitHubError;
+
+    let response = inner._put(route, body).await.map_err(GitHubError::from)?;
+    octocrab::map_github_error(response)
+        .await
+        .map(drop)
+        .map_err(GitHubError::from)
+}
@@ -21,15 +21,47 @@ pub use github::{GitHubError, GitHubResult};
 
 // Re-export GitHub operation options
 pub use github::{
-    CreatePullRequestReviewOptions, CreateReleaseOptions as GitHubReleaseOptions,
-    ListCommitsOptions, MergePullRequestOptions, ReleaseResult as GitHubReleaseResult,
-    UpdatePullRequestOptions, create_release, delete_release, get_release_by_tag,
-    update_release,
+    BulkIssueChange, BulkIssueReport, BulkIssueRequest, BulkIssueResult, BulkIssueStatus,
+    BulkIssueTarget, BurndownPoint, CSV_COLUMNS, CloseReason, CodeOwnerRule, CodeOwners,
+    ColumnMapping, CommentKind, CommitSummary, ContactLink, CreateIssueFromTemplateRequest,
+    CreateIssueOutcome, CreateIssueRequest, CreateMilestoneRequest, CreatePullRequestReviewOptions,
+    CreateReleaseOptions as GitHubReleaseOptions, DEFAULT_BULK_CONCURRENCY,
+    DEFAULT_DUPLICATE_THRESHOLD, DependencyKind, DiffHunk, DiffLine, DiffLineKind, DiffSide,
+    ExistingLabel, ExportCheckpoint, ExportFormat, ExportIssuesRequest, ExportSummary,
+    ExportedComment, ExportedIssue, FieldValue, FileDiff, FileDiffStatus, FileOwners,
+    FindSimilarIssuesRequest, ISSUE_TEMPLATE_DIR, ImportFormat, ImportIssuesRequest, ImportReport,
+    ImportStatus, ImportedIssue, InvalidIssueTemplate, IssueDraft, IssueFormField,
+    IssueFormFieldKind, IssuePatch, IssueRef, IssueSnapshot, IssueTemplate, IssueTemplateConfig,
+    IssueTemplates, IssueText, IssueTreeNode, LabelChange, LabelSpec, LabelSpecFormat, LinkedIssue,
+    LinkedPullRequest, ListCommitsOptions, LockReason, MAX_BULK_CONCURRENCY, MAX_BULK_ISSUES,
+    MAX_BURNDOWN_DAYS, MAX_ISSUE_TREE_DEPTH, MAX_PULL_REQUEST_COMMITS, MergeBlocker,
+    MergePullRequestOptions, MergeReadiness, Milestone, MilestoneItem, MilestoneProgress,
+    NO_RESPONSE, PendingReviewComment, PullRequestCommit, PullRequestContent, PullRequestDiff,
+    ReactionTarget, ReleaseResult as GitHubReleaseResult, RenderedIssue, ReviewThread,
+    ReviewThreadComment, ReviewerSuggestion, STACK_SECTION_END, STACK_SECTION_START, SimilarIssue,
+    SimilarityScore, StackEntry, StackEntryStatus, StickyCommentAction, StickyCommentResult,
+    SubIssuePosition, SuggestedChange, SyncPullRequestStackRequest, TimelineEvent,
+    TimelineEventKind, TransferredIssue, UpdateBranchOutcome, UpdateMilestoneRequest,
+    UpdatePullRequestBranchOptions, UpdatePullRequestOptions, build_search_queries, burndown,
+    checkpoint_path, create_release, delete_release, derive_import_key, extract_import_key,
+    get_release_by_tag, has_sticky_marker, import_marker, likely_duplicates, linked_pull_requests,
+    merge_csv, merge_jsonl, missing_collaborators, normalize_color, parse_csv_import,
+    parse_issue_template_config, parse_label_spec, parse_linked_issues, parse_markdown_import,
+    parse_reaction_content, plan_issue_patch, plan_label_sync, reaction_name, render_csv,
+    render_import_body, render_jsonl, render_markdown_digest, render_stack_section,
+    render_sticky_body, scope_bulk_query, score_similarity, sticky_marker, team_slug, tokenize,
+    update_release, upsert_stack_section, validate_login,
 };
 
 // Re-export release asset upload types
 pub use github::upload_release_asset::{UploadAssetOptions, upload_release_asset};
 
+// Re-export search query builders
+pub use github::{
+    CodeSearchQuery, CodeSearchSort, DateRange, IssueKind, IssueSearchQuery, IssueSearchSort,
+    IssueSearchState, MAX_QUERY_LENGTH, QueryRange, quote_search_value,
+};
+
 // Re-export GitHub types for public API
 pub use github::{
     ActivityMetrics,
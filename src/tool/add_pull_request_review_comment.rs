@@ -62,6 +62,7 @@ impl Tool for AddPullRequestReviewCommentTool {
             start_side: args.start_side.clone(),
             subject_type: args.subject_type.clone(),
             in_reply_to: args.in_reply_to,
+            suggestion: None,
         };
 
         // Call API wrapper (returns AsyncTask<Result<ReviewComment, GitHubError>>)
@@ -61,6 +61,7 @@ impl Tool for CreateIssueTool {
                 args.body.clone(),
                 args.assignees.clone(),
                 args.labels.clone(),
+                None,
             )
             .await;
 
@@ -57,6 +57,11 @@ impl Tool for CreatePullRequestTool {
             base: args.base.clone(),
             draft: args.draft,
             maintainer_can_modify: args.maintainer_can_modify,
+            reviewers: None,
+            team_reviewers: None,
+            issue: None,
+            generate_body: None,
+            template: None,
         };
 
         let task_result = client.create_pull_request(request).await;
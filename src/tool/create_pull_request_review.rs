@@ -68,6 +68,7 @@ impl Tool for CreatePullRequestReviewTool {
             body: args.body.clone(),
             commit_id: args.commit_id.clone(),
             comments: None, // Inline comments not supported in this tool
+            suggestions: Vec::new(),
         };
 
         // Call API wrapper (returns AsyncTask<Result<Review, GitHubError>>)
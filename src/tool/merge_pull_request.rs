@@ -52,6 +52,7 @@ impl Tool for MergePullRequestTool {
             commit_message: args.commit_message.clone(),
             sha: args.sha.clone(),
             merge_method: args.merge_method.clone(),
+            safety_checks: false,
         };
 
         let task_result = client
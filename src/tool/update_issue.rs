@@ -75,6 +75,8 @@ impl Tool for UpdateIssueTool {
             labels: args.labels.clone(),
             assignees: args.assignees.clone(),
             milestone: None,
+            clear_milestone: false,
+            state_reason: None,
         };
 
         // Call API wrapper (returns AsyncTask<Result<Issue, GitHubError>>)
@@ -64,6 +64,7 @@ impl Tool for UpdatePullRequestTool {
             state,
             base: args.base.clone(),
             maintainer_can_modify: args.maintainer_can_modify,
+            draft: None,
         };
 
         let task_result = client
@@ -1,12 +1,35 @@
 //! Integration tests for GitHub operations.
 
 mod github {
+    mod test_bulk_issues;
     mod test_code_scanning_alerts;
+    mod test_codeowners;
     mod test_get_commit;
+    mod test_get_pull_request_diff;
+    mod test_issue_export;
+    mod test_issue_import;
+    mod test_issue_moderation;
+    mod test_issue_templates;
+    mod test_issue_timeline;
+    mod test_labels;
     mod test_list_branches;
     mod test_list_commits;
+    mod test_list_pull_request_commits;
     mod test_merge_pull_request;
+    mod test_milestones;
+    mod test_pending_review;
+    mod test_pull_request_content;
+    mod test_pull_request_stack;
+    mod test_reactions;
+    mod test_requested_reviewers;
+    mod test_review_threads;
     mod test_search_code;
+    mod test_search_query;
     mod test_search_users;
     mod test_secret_scanning_alerts;
+    mod test_similar_issues;
+    mod test_sticky_comment;
+    mod test_sub_issues;
+    mod test_suggested_change;
+    mod test_update_pull_request_branch;
 }
@@ -0,0 +1,199 @@
+//! Tests for bulk issue change planning.
+
+use kodegen_tools_github::{
+    BulkIssueChange, BulkIssueRequest, BulkIssueTarget, CloseReason, DEFAULT_BULK_CONCURRENCY,
+    GitHubError, IssueSnapshot, MAX_BULK_CONCURRENCY, MAX_BULK_ISSUES, plan_issue_patch,
+    scope_bulk_query,
+};
+use octocrab::models::IssueState;
+
+fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|v| (*v).to_string()).collect()
+}
+
+fn snapshot(labels: &[&str], assignees: &[&str], milestone: Option<u64>) -> IssueSnapshot {
+    IssueSnapshot {
+        labels: strings(labels),
+        assignees: strings(assignees),
+        milestone,
+        closed: false,
+    }
+}
+
+#[test]
+fn test_plan_issue_patch_labels_and_assignees() {
+    let change = BulkIssueChange {
+        add_labels: strings(&["triaged", "Bug"]),
+        remove_labels: strings(&["NEEDS-TRIAGE"]),
+        add_assignees: strings(&["alice"]),
+        remove_assignees: strings(&["bob"]),
+        ..BulkIssueChange::default()
+    };
+    let patch = plan_issue_patch(&snapshot(&["bug", "needs-triage"], &["bob"], None), &change);
+
+    assert_eq!(patch.labels, Some(strings(&["bug", "triaged"])));
+    assert_eq!(patch.assignees, Some(strings(&["alice"])));
+    assert_eq!(
+        patch.changes,
+        [
+            "+label:triaged",
+            "-label:needs-triage",
+            "+assignee:alice",
+            "-assignee:bob"
+        ]
+    );
+    assert_eq!(patch.milestone, None);
+    assert_eq!(patch.state, None);
+
+    let body = patch.to_json();
+    assert_eq!(body["labels"], serde_json::json!(["bug", "triaged"]));
+    assert!(body.get("milestone").is_none());
+}
+
+#[test]
+fn test_plan_issue_patch_skips_what_already_matches() {
+    let change = BulkIssueChange {
+        add_labels: strings(&["BUG"]),
+        milestone: Some(3),
+        state: Some(IssueState::Closed),
+        state_reason: Some(CloseReason::NotPlanned),
+        ..BulkIssueChange::default()
+    };
+    let mut current = snapshot(&["bug"], &[], Some(3));
+    current.closed = true;
+    assert!(plan_issue_patch(&current, &change).is_empty());
+
+    current.closed = false;
+    current.milestone = None;
+    let patch = plan_issue_patch(&current, &change);
+    assert_eq!(
+        patch.changes,
+        ["milestone: 3", "state: closed (not_planned)"]
+    );
+    let body = patch.to_json();
+    assert_eq!(body["milestone"], 3);
+    assert_eq!(body["state"], "closed");
+    assert_eq!(body["state_reason"], "not_planned");
+    assert!(body.get("labels").is_none());
+}
+
+#[test]
+fn test_plan_issue_patch_set_labels_clear_milestone_and_reopen() {
+    let change = BulkIssueChange {
+        set_labels: Some(strings(&["p1", "P1", "bug"])),
+        clear_milestone: true,
+        state: Some(IssueState::Open),
+        ..BulkIssueChange::default()
+    };
+    let mut current = snapshot(&["bug", "wontfix"], &[], Some(7));
+    current.closed = true;
+    let patch = plan_issue_patch(&current, &change);
+
+    assert_eq!(patch.labels, Some(strings(&["p1", "bug"])));
+    assert_eq!(patch.milestone, Some(None));
+    assert_eq!(
+        patch.changes,
+        [
+            "+label:p1",
+            "-label:wontfix",
+            "milestone: none",
+            "state: open"
+        ]
+    );
+    let body = patch.to_json();
+    assert!(body["milestone"].is_null());
+    assert_eq!(body["state"], "open");
+    assert!(body.get("state_reason").is_none());
+}
+
+#[test]
+fn test_bulk_issue_change_validate() {
+    assert!(BulkIssueChange::default().validate().is_err());
+    assert!(
+        BulkIssueChange {
+            set_labels: Some(strings(&["bug"])),
+            add_labels: strings(&["p1"]),
+            ..BulkIssueChange::default()
+        }
+        .validate()
+        .is_err()
+    );
+    assert!(
+        BulkIssueChange {
+            add_labels: strings(&["bug"]),
+            remove_labels: strings(&["Bug"]),
+            ..BulkIssueChange::default()
+        }
+        .validate()
+        .is_err()
+    );
+    assert!(
+        BulkIssueChange {
+            state: Some(IssueState::Open),
+            state_reason: Some(CloseReason::Completed),
+            ..BulkIssueChange::default()
+        }
+        .validate()
+        .is_err()
+    );
+    assert!(
+        BulkIssueChange {
+            clear_milestone: true,
+            ..BulkIssueChange::default()
+        }
+        .validate()
+        .is_ok()
+    );
+}
+
+#[test]
+fn test_scope_bulk_query() {
+    assert_eq!(
+        scope_bulk_query("octo", "hello", " is:open label:bug "),
+        "repo:octo/hello is:open label:bug"
+    );
+    assert_eq!(
+        scope_bulk_query("octo", "hello", "org:octo is:open"),
+        "org:octo is:open"
+    );
+}
+
+#[test]
+fn test_bulk_request_concurrency_and_limit() {
+    let mut request = BulkIssueRequest {
+        owner: "octo".to_string(),
+        repo: "hello".to_string(),
+        target: BulkIssueTarget::Query("is:open".to_string()),
+        change: BulkIssueChange::default(),
+        concurrency: None,
+        limit: None,
+        dry_run: true,
+    };
+    assert_eq!(
+        request.effective_concurrency().unwrap(),
+        DEFAULT_BULK_CONCURRENCY
+    );
+    assert_eq!(request.effective_limit().unwrap(), MAX_BULK_ISSUES);
+
+    request.concurrency = Some(MAX_BULK_CONCURRENCY + 5);
+    request.limit = Some(25);
+    assert_eq!(
+        request.effective_concurrency().unwrap(),
+        MAX_BULK_CONCURRENCY
+    );
+    assert_eq!(request.effective_limit().unwrap(), 25);
+
+    request.limit = Some(MAX_BULK_ISSUES * 2);
+    assert_eq!(request.effective_limit().unwrap(), MAX_BULK_ISSUES);
+
+    request.concurrency = Some(0);
+    request.limit = Some(0);
+    assert!(matches!(
+        request.effective_concurrency(),
+        Err(GitHubError::InvalidInput(_))
+    ));
+    assert!(matches!(
+        request.effective_limit(),
+        Err(GitHubError::InvalidInput(_))
+    ));
+}
@@ -0,0 +1,71 @@
+//! Tests for CODEOWNERS parsing and matching.
+
+use kodegen_tools_github::CodeOwners;
+
+const CODEOWNERS: &str = r"
+# Default owners
+*       @global-owner
+
+*.js    @js-owner #This is an inline comment.
+/build/logs/ @doctocat
+docs/*  docs@example.com
+apps/   @octocat
+/docs/  @doctocat
+/scripts/ @doctocat @octocat
+**/logs @octocat
+/apps/github
+/src/lib\ name.rs @org/core-team
+";
+
+#[test]
+fn test_last_match_wins() {
+    let owners = CodeOwners::parse(CODEOWNERS);
+    assert_eq!(owners.owners_for("README.md"), ["@global-owner"]);
+    assert_eq!(owners.owners_for("web/app.js"), ["@js-owner"]);
+    assert_eq!(
+        owners.owners_for("scripts/run.sh"),
+        ["@doctocat", "@octocat"]
+    );
+}
+
+#[test]
+fn test_directory_patterns() {
+    let owners = CodeOwners::parse(CODEOWNERS);
+    // `apps/` is unanchored and matches at any depth
+    assert_eq!(owners.owners_for("nested/apps/main.rs"), ["@octocat"]);
+    // `/build/logs/` is anchored
+    assert_eq!(owners.owners_for("build/logs/out.txt"), ["@octocat"]);
+    // `**/logs` matches everything under any logs directory
+    assert_eq!(owners.owners_for("deep/logs/a/b.txt"), ["@octocat"]);
+}
+
+#[test]
+fn test_wildcard_matches_direct_children_only() {
+    let owners = CodeOwners::parse("docs/* docs@example.com\n");
+    assert_eq!(
+        owners.owners_for("docs/getting-started.md"),
+        ["docs@example.com"]
+    );
+    assert!(
+        owners
+            .owners_for("docs/build-app/troubleshooting.md")
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_unowned_and_escapes() {
+    let owners = CodeOwners::parse(CODEOWNERS);
+    let rule = owners
+        .rule_for("apps/github/main.go")
+        .expect("rule matches");
+    assert!(rule.owners.is_empty());
+    assert_eq!(owners.owners_for("src/lib name.rs"), ["@org/core-team"]);
+}
+
+#[test]
+fn test_unsupported_patterns_skipped() {
+    let owners = CodeOwners::parse("!secret.txt @a\n[Rr]eadme.md @b\nfoo @c\n");
+    assert_eq!(owners.rules.len(), 1);
+    assert_eq!(owners.rules[0].line, 3);
+}
@@ -0,0 +1,77 @@
+//! Tests for GitHub Pull Request diff parsing.
+
+use kodegen_tools_github::{DiffLineKind, DiffSide, FileDiffStatus, PullRequestDiff};
+
+const DIFF: &str = "diff --git a/src/main.rs b/src/main.rs
+index 1111111..2222222 100644
+--- a/src/main.rs
++++ b/src/main.rs
+@@ -1,3 +1,4 @@ fn main() {
+ fn main() {
+-    println!(\"old\");
++    println!(\"new\");
++    println!(\"extra\");
+ }
+@@ -20,2 +21,2 @@
+ // tail
+-// old tail
++// new tail
+\\ No newline at end of file
+diff --git a/a.txt b/b.txt
+similarity index 100%
+rename from a.txt
+rename to b.txt
+diff --git a/gone.txt b/gone.txt
+deleted file mode 100644
+--- a/gone.txt
++++ /dev/null
+@@ -1 +0,0 @@
+-bye
+";
+
+#[test]
+fn test_parse_hunks_with_line_numbers() {
+    let diff = PullRequestDiff::parse(DIFF);
+    assert_eq!(diff.files.len(), 3);
+
+    let main = diff.file("src/main.rs").expect("main.rs parsed");
+    assert_eq!(main.status, FileDiffStatus::Modified);
+    assert_eq!(main.hunks.len(), 2);
+
+    let first = &main.hunks[0];
+    assert_eq!((first.old_start, first.old_lines), (1, 3));
+    assert_eq!((first.new_start, first.new_lines), (1, 4));
+    assert_eq!(first.lines[1].kind, DiffLineKind::Removed);
+    assert_eq!(first.lines[1].old_line, Some(2));
+    assert_eq!(first.lines[3].new_line, Some(3));
+    assert_eq!(first.lines[4].old_line, Some(3));
+    assert_eq!(first.lines[4].new_line, Some(4));
+
+    assert_eq!(main.hunks[1].lines.len(), 3);
+}
+
+#[test]
+fn test_range_addressing() {
+    let diff = PullRequestDiff::parse(DIFF);
+    let main = diff.file("src/main.rs").expect("main.rs parsed");
+
+    assert!(main.range_in_one_hunk(DiffSide::Right, 2, 3));
+    assert!(main.range_in_one_hunk(DiffSide::Left, 2, 2));
+    // Spans two hunks
+    assert!(!main.range_in_one_hunk(DiffSide::Right, 3, 22));
+    // Outside any hunk
+    assert!(main.hunk_for(DiffSide::Right, 10).is_none());
+}
+
+#[test]
+fn test_renames_and_deletions() {
+    let diff = PullRequestDiff::parse(DIFF);
+
+    let renamed = diff.file("b.txt").expect("rename parsed");
+    assert_eq!(renamed.status, FileDiffStatus::Renamed);
+    assert_eq!(renamed.old_path.as_deref(), Some("a.txt"));
+
+    let removed = diff.file("gone.txt").expect("deletion parsed");
+    assert_eq!(removed.status, FileDiffStatus::Removed);
+    assert_eq!(removed.hunks[0].lines[0].old_line, Some(1));
+}
@@ -0,0 +1,167 @@
+//! Tests for issue export rendering and incremental merging.
+
+use chrono::{DateTime, TimeZone, Utc};
+use kodegen_tools_github::{
+    CSV_COLUMNS, ExportFormat, ExportedComment, ExportedIssue, TimelineEvent, checkpoint_path,
+    merge_csv, merge_jsonl, render_csv, render_jsonl, render_markdown_digest,
+};
+use serde_json::json;
+
+fn at(day: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 3, day, 12, 0, 0).unwrap()
+}
+
+fn issue(number: u64, title: &str, updated: u32) -> ExportedIssue {
+    ExportedIssue {
+        number,
+        is_pull_request: false,
+        title: title.to_string(),
+        state: "open".to_string(),
+        author: "octocat".to_string(),
+        labels: vec!["bug".to_string(), "p1".to_string()],
+        assignees: Vec::new(),
+        milestone: None,
+        created_at: at(1),
+        updated_at: at(updated),
+        closed_at: None,
+        html_url: format!("https://github.com/octo/hello/issues/{number}"),
+        body: "Steps:\n1. click, then \"submit\"".to_string(),
+        comment_count: 3,
+        comments: Vec::new(),
+        timeline: Vec::new(),
+    }
+}
+
+#[test]
+fn test_export_format() {
+    assert_eq!(
+        "JSONL".parse::<ExportFormat>().ok(),
+        Some(ExportFormat::Jsonl)
+    );
+    assert_eq!(
+        "md".parse::<ExportFormat>().ok(),
+        Some(ExportFormat::Markdown)
+    );
+    assert!("xml".parse::<ExportFormat>().is_err());
+    assert_eq!(
+        ExportFormat::from_path("out/issues.csv"),
+        Some(ExportFormat::Csv)
+    );
+    assert_eq!(ExportFormat::from_path("issues"), None);
+    assert_eq!(
+        checkpoint_path("out/issues.csv"),
+        "out/issues.csv.checkpoint.json"
+    );
+}
+
+#[test]
+fn test_jsonl_render_and_merge() {
+    let mut first = issue(1, "Crash on login", 2);
+    first.comments.push(ExportedComment {
+        id: 10,
+        author: "alice".to_string(),
+        created_at: at(2),
+        updated_at: None,
+        body: "Same here".to_string(),
+    });
+    let rendered = render_jsonl(&[issue(3, "Typo", 2), first]).expect("jsonl");
+    let lines: Vec<&str> = rendered.lines().collect();
+    assert_eq!(lines.len(), 2);
+    let record: serde_json::Value = serde_json::from_str(lines[1]).expect("json line");
+    assert_eq!(record["number"], 1);
+    assert_eq!(record["comments"][0]["author"], "alice");
+
+    let merged = merge_jsonl(
+        &rendered,
+        &[issue(3, "Typo in README", 5), issue(2, "New", 4)],
+    )
+    .expect("merge");
+    let numbers: Vec<(u64, String)> = merged
+        .lines()
+        .map(|l| {
+            let v: serde_json::Value = serde_json::from_str(l).expect("json line");
+            (
+                v["number"].as_u64().unwrap(),
+                v["title"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        numbers,
+        [
+            (1, "Crash on login".to_string()),
+            (2, "New".to_string()),
+            (3, "Typo in README".to_string())
+        ]
+    );
+
+    assert!(merge_jsonl("{\"title\":\"no number\"}\n", &[]).is_err());
+}
+
+#[test]
+fn test_csv_render_and_merge() {
+    let rendered = render_csv(&[issue(2, "Crash, on login", 2)]).expect("csv");
+    let mut lines = rendered.lines();
+    assert_eq!(lines.next(), Some(CSV_COLUMNS.join(",").as_str()));
+    assert!(rendered.contains("\"Crash, on login\""));
+    assert!(rendered.contains("bug; p1"));
+
+    let merged =
+        merge_csv(&rendered, &[issue(1, "First", 3), issue(2, "Crash", 3)]).expect("merge");
+    let mut reader = csv::Reader::from_reader(merged.as_bytes());
+    let rows: Vec<(String, String)> = reader
+        .records()
+        .map(|r| {
+            let r = r.expect("record");
+            (r[0].to_string(), r[2].to_string())
+        })
+        .collect();
+    assert_eq!(
+        rows,
+        [
+            ("1".to_string(), "First".to_string()),
+            ("2".to_string(), "Crash".to_string())
+        ]
+    );
+    let first = csv::Reader::from_reader(merged.as_bytes())
+        .records()
+        .next()
+        .expect("row")
+        .expect("record");
+    // Comments are counted even when they were not fetched
+    assert_eq!(&first[8], "3");
+    assert_eq!(&first[13], "Steps:\n1. click, then \"submit\"");
+
+    assert!(merge_csv("number,title\n1,x\n", &[]).is_err());
+}
+
+#[test]
+fn test_render_markdown_digest() {
+    let mut closed = issue(7, "Flaky test", 4);
+    closed.state = "closed".to_string();
+    closed.is_pull_request = true;
+    closed.closed_at = Some(at(4));
+    closed.timeline.push(TimelineEvent::from_value(&json!({
+        "event": "labeled",
+        "actor": { "login": "bob" },
+        "created_at": "2025-03-03T09:00:00Z",
+        "label": { "name": "ci" }
+    })));
+
+    let digest = render_markdown_digest(
+        "octo",
+        "hello",
+        &[issue(1, "Crash", 2), closed],
+        None,
+        at(5),
+    );
+    assert!(digest.starts_with("# octo/hello: issues and pull requests\n"));
+    assert!(digest.contains("2 items (1 open, 1 closed)"));
+    assert!(digest.contains("## Issue #1: Crash (open)"));
+    assert!(digest.contains("## PR #7: Flaky test (closed)"));
+    assert!(digest.contains("**Labels:** bug, p1"));
+    assert!(digest.contains("- 2025-03-03 09:00 UTC @bob added label 'ci'"));
+
+    let update = render_markdown_digest("octo", "hello", &[], Some(at(4)), at(5));
+    assert!(update.starts_with("# octo/hello: updates since 2025-03-04 12:00 UTC\n"));
+}
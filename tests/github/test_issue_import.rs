@@ -0,0 +1,108 @@
+//! Tests for issue import parsing and idempotency keys.
+
+use kodegen_tools_github::{
+    ColumnMapping, ImportFormat, IssueDraft, derive_import_key, extract_import_key,
+    parse_csv_import, parse_markdown_import, render_import_body,
+};
+
+#[test]
+fn test_import_keys() {
+    assert_eq!(
+        derive_import_key("Crash on login (iOS 17)!"),
+        "crash-on-login-ios-17"
+    );
+    assert!(derive_import_key(&"word ".repeat(40)).len() <= 80);
+
+    let draft = IssueDraft {
+        key: "login-crash".to_string(),
+        title: "Crash on login".to_string(),
+        body: "Steps to reproduce\n".to_string(),
+        ..IssueDraft::default()
+    };
+    let body = render_import_body(&draft);
+    assert_eq!(
+        body,
+        "Steps to reproduce\n\n<!-- issue-import:login-crash -->"
+    );
+    assert_eq!(extract_import_key(&body).as_deref(), Some("login-crash"));
+    assert_eq!(extract_import_key("no marker here"), None);
+    assert_eq!(
+        ImportFormat::from_path("todo.md"),
+        Some(ImportFormat::Markdown)
+    );
+    assert!("xlsx".parse::<ImportFormat>().is_err());
+}
+
+#[test]
+fn test_parse_csv_import() {
+    let csv = "Title,Body,Labels,Assignees,Milestone,Key\n\
+               Crash on login,\"Happens on submit,\nevery time\",bug; p1,@alice,v1.0,\n\
+               ,ignored,,,,\n\
+               Dark mode,,enhancement,,,ui-dark-mode\n";
+    let drafts = parse_csv_import(csv, &ColumnMapping::default()).expect("valid csv");
+
+    assert_eq!(drafts.len(), 2);
+    assert_eq!(drafts[0].key, "crash-on-login");
+    assert_eq!(drafts[0].body, "Happens on submit,\nevery time");
+    assert_eq!(drafts[0].labels, ["bug", "p1"]);
+    assert_eq!(drafts[0].assignees, ["alice"]);
+    assert_eq!(drafts[0].milestone.as_deref(), Some("v1.0"));
+    assert_eq!(drafts[1].key, "ui-dark-mode");
+    assert_eq!(drafts[1].milestone, None);
+
+    let mapping = ColumnMapping {
+        title: "summary".to_string(),
+        ..ColumnMapping::default()
+    };
+    let drafts = parse_csv_import("Summary,Tags\nFix typo,docs\n", &mapping).expect("mapped");
+    assert_eq!(drafts[0].title, "Fix typo");
+    assert!(drafts[0].labels.is_empty());
+
+    assert!(parse_csv_import("name\nx\n", &ColumnMapping::default()).is_err());
+    assert!(parse_csv_import("title\nSame\nsame\n", &ColumnMapping::default()).is_err());
+}
+
+#[test]
+fn test_parse_markdown_import() {
+    let markdown = "\
+# Launch checklist
+
+- [ ] Write release notes @alice #docs
+  Cover the new API and
+  the migration steps.
+
+- [x] Already done
+  this body is ignored
+- [ ] Fix #123 regression #bug #p1 <!-- key: regression-123 -->
+  - [ ] nested items stay in the body
+* [ ] See [the docs](https://example.com)
+- [link](https://example.com) is not a task
+
+Closing paragraph.
+";
+    let drafts = parse_markdown_import(markdown).expect("valid markdown");
+    let titles: Vec<&str> = drafts.iter().map(|d| d.title.as_str()).collect();
+    assert_eq!(
+        titles,
+        [
+            "Write release notes",
+            "Fix #123 regression",
+            "See [the docs](https://example.com)"
+        ]
+    );
+
+    assert_eq!(drafts[0].assignees, ["alice"]);
+    assert_eq!(drafts[0].labels, ["docs"]);
+    assert_eq!(
+        drafts[0].body,
+        "Cover the new API and\nthe migration steps."
+    );
+    assert_eq!(drafts[0].key, "write-release-notes");
+
+    assert_eq!(drafts[1].labels, ["bug", "p1"]);
+    assert_eq!(drafts[1].key, "regression-123");
+    assert_eq!(drafts[1].body, "- [ ] nested items stay in the body");
+
+    assert!(drafts[2].body.is_empty());
+    assert!(parse_markdown_import("- [ ] Same\n- [ ] same\n").is_err());
+}
@@ -0,0 +1,46 @@
+//! Tests for lock and close reason parsing.
+
+use kodegen_tools_github::{CloseReason, LockReason};
+
+#[test]
+fn test_lock_reason_parsing() {
+    for (input, expected) in [
+        ("off-topic", LockReason::OffTopic),
+        ("off_topic", LockReason::OffTopic),
+        ("too heated", LockReason::TooHeated),
+        ("Too_Heated", LockReason::TooHeated),
+        ("resolved", LockReason::Resolved),
+        ("spam", LockReason::Spam),
+    ] {
+        assert_eq!(
+            input.parse::<LockReason>().ok(),
+            Some(expected),
+            "input {input}"
+        );
+    }
+    assert_eq!(LockReason::TooHeated.as_str(), "too heated");
+    assert!("rude".parse::<LockReason>().is_err());
+}
+
+#[test]
+fn test_close_reason_parsing() {
+    for (input, expected) in [
+        ("completed", CloseReason::Completed),
+        ("not_planned", CloseReason::NotPlanned),
+        ("not-planned", CloseReason::NotPlanned),
+        ("Not Planned", CloseReason::NotPlanned),
+        ("duplicate", CloseReason::Duplicate),
+    ] {
+        assert_eq!(
+            input.parse::<CloseReason>().ok(),
+            Some(expected),
+            "input {input}"
+        );
+    }
+    assert_eq!(CloseReason::NotPlanned.as_str(), "not_planned");
+    assert_eq!(
+        serde_json::to_value(CloseReason::Duplicate).ok(),
+        Some(serde_json::json!("duplicate"))
+    );
+    assert!("reopened".parse::<CloseReason>().is_err());
+}
@@ -0,0 +1,200 @@
+//! Tests for issue form parsing, validation and rendering.
+
+use kodegen_tools_github::{
+    FieldValue, IssueFormFieldKind, IssueTemplate, IssueTemplates, parse_issue_template_config,
+};
+use std::collections::BTreeMap;
+
+const BUG_FORM: &str = r#"
+name: Bug Report
+description: File a bug report
+title: "[Bug]: "
+labels: ["bug", "triage"]
+assignees: octocat
+body:
+  - type: markdown
+    attributes:
+      value: Thanks for taking the time to fill out this bug report!
+  - type: textarea
+    id: what-happened
+    attributes:
+      label: What happened?
+    validations:
+      required: true
+  - type: dropdown
+    id: version
+    attributes:
+      label: Version
+      options:
+        - 1.0.2 (Default)
+        - 1.0.3 (Edge)
+      default: 0
+  - type: textarea
+    id: logs
+    attributes:
+      label: Relevant log output
+      render: shell
+  - type: input
+    attributes:
+      label: Contact
+  - type: checkboxes
+    id: terms
+    attributes:
+      label: Code of Conduct
+      options:
+        - label: I agree to follow this project's Code of Conduct
+          required: true
+        - label: I searched existing issues
+"#;
+
+fn values(pairs: &[(&str, FieldValue)]) -> BTreeMap<String, FieldValue> {
+    pairs
+        .iter()
+        .map(|(k, v)| ((*k).to_string(), v.clone()))
+        .collect()
+}
+
+fn text(s: &str) -> FieldValue {
+    FieldValue::Text(s.to_string())
+}
+
+#[test]
+fn test_parse_issue_form() {
+    let form = IssueTemplate::parse(".github/ISSUE_TEMPLATE/bug_report.yml", BUG_FORM)
+        .expect("valid form");
+
+    assert_eq!(form.id, "bug_report");
+    assert_eq!(form.title.as_deref(), Some("[Bug]: "));
+    assert_eq!(form.labels, ["bug", "triage"]);
+    assert_eq!(form.assignees, ["octocat"]);
+    assert!(form.is_form());
+
+    let keys: Vec<_> = form.fields.iter().map(|f| f.key()).collect();
+    assert_eq!(
+        keys,
+        ["what-happened", "version", "logs", "Contact", "terms"]
+    );
+    assert_eq!(form.fields[1].kind, IssueFormFieldKind::Dropdown);
+    assert_eq!(form.fields[1].default.as_deref(), Some("1.0.2 (Default)"));
+    assert_eq!(
+        form.fields[4].required_options,
+        ["I agree to follow this project's Code of Conduct"]
+    );
+
+    let bad = "name: Broken\nbody:\n  - type: slider\n    attributes:\n      label: X\n";
+    assert!(IssueTemplate::parse("bad.yml", bad).is_err());
+}
+
+#[test]
+fn test_render_issue_form() {
+    let form = IssueTemplate::parse(".github/ISSUE_TEMPLATE/bug_report.yml", BUG_FORM)
+        .expect("valid form");
+
+    let rendered = form
+        .render(
+            "Login fails",
+            &values(&[
+                ("what-happened", text("The form doesn't submit")),
+                ("logs", text("error: 500")),
+                (
+                    "terms",
+                    FieldValue::List(vec![
+                        "i agree to follow this project's code of conduct".to_string(),
+                    ]),
+                ),
+            ]),
+        )
+        .expect("valid values");
+
+    assert_eq!(rendered.title, "[Bug]: Login fails");
+    assert_eq!(rendered.labels, ["bug", "triage"]);
+    assert_eq!(
+        rendered.body,
+        "### What happened?\n\nThe form doesn't submit\n\n\
+         ### Version\n\n1.0.2 (Default)\n\n\
+         ### Relevant log output\n\n```shell\nerror: 500\n```\n\n\
+         ### Contact\n\n_No response_\n\n\
+         ### Code of Conduct\n\n\
+         - [X] I agree to follow this project's Code of Conduct\n\
+         - [ ] I searched existing issues"
+    );
+
+    // A title that already carries the prefix is kept as is
+    let prefixed = form
+        .render(
+            "[Bug]: Crash",
+            &values(&[
+                ("What happened?", text("Crash")),
+                (
+                    "terms",
+                    text("I agree to follow this project's Code of Conduct"),
+                ),
+            ]),
+        )
+        .expect("label keys work");
+    assert_eq!(prefixed.title, "[Bug]: Crash");
+}
+
+#[test]
+fn test_render_issue_form_validation() {
+    let form = IssueTemplate::parse(".github/ISSUE_TEMPLATE/bug_report.yml", BUG_FORM)
+        .expect("valid form");
+
+    let err = form
+        .render(
+            "Login fails",
+            &values(&[("version", text("2.0")), ("colour", text("red"))]),
+        )
+        .expect_err("invalid values")
+        .to_string();
+    assert!(err.contains("unknown field 'colour'"), "{err}");
+    assert!(err.contains("'what-happened' is required"), "{err}");
+    assert!(err.contains("'2.0' is not an option of 'version'"), "{err}");
+    assert!(err.contains("'terms' requires checking"), "{err}");
+
+    assert!(form.render("", &BTreeMap::new()).is_err());
+}
+
+#[test]
+fn test_markdown_template_and_config() {
+    let md = "---\nname: Feature request\nabout: Suggest an idea\ntitle: ''\nlabels: enhancement, idea\n---\n\n**Describe the feature**\n";
+    let template =
+        IssueTemplate::parse(".github/ISSUE_TEMPLATE/feature.md", md).expect("valid template");
+    assert!(!template.is_form());
+    assert_eq!(template.labels, ["enhancement", "idea"]);
+    assert_eq!(
+        template.markdown.as_deref(),
+        Some("**Describe the feature**")
+    );
+
+    let rendered = template
+        .render("Dark mode", &BTreeMap::new())
+        .expect("no fields");
+    assert_eq!(rendered.body, "**Describe the feature**");
+    assert!(
+        template
+            .render("Dark mode", &values(&[("x", text("y"))]))
+            .is_err()
+    );
+
+    let templates = IssueTemplates {
+        templates: vec![template],
+        ..Default::default()
+    };
+    assert!(templates.find("feature").is_some());
+    assert!(templates.find("FEATURE REQUEST").is_some());
+    assert!(templates.find("feature.md").is_some());
+    assert!(templates.find("bug").is_none());
+
+    let config = parse_issue_template_config(
+        "blank_issues_enabled: false\ncontact_links:\n  - name: Forum\n    url: https://example.com\n",
+    )
+    .expect("valid config");
+    assert!(!config.blank_issues_enabled);
+    assert_eq!(config.contact_links[0].name, "Forum");
+    assert!(
+        parse_issue_template_config("")
+            .expect("empty config")
+            .blank_issues_enabled
+    );
+}
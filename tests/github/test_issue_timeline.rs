@@ -0,0 +1,83 @@
+//! Tests for issue timeline event parsing.
+
+use kodegen_tools_github::{TimelineEvent, TimelineEventKind, linked_pull_requests};
+use serde_json::json;
+
+#[test]
+fn test_parse_timeline_events() {
+    let labeled = TimelineEvent::from_value(&json!({
+        "event": "labeled",
+        "actor": { "login": "octocat" },
+        "created_at": "2025-03-01T10:00:00Z",
+        "label": { "name": "bug", "color": "d73a4a" }
+    }));
+    assert_eq!(labeled.actor.as_deref(), Some("octocat"));
+    assert!(labeled.created_at.is_some());
+    assert_eq!(
+        labeled.kind,
+        TimelineEventKind::Labeled {
+            label: "bug".to_string()
+        }
+    );
+    assert_eq!(labeled.summary(), "added label 'bug'");
+
+    let comment = TimelineEvent::from_value(&json!({
+        "event": "commented",
+        "id": 42,
+        "user": { "login": "hubot" },
+        "created_at": "2025-03-01T11:00:00Z",
+        "body": "Looking into it\nmore detail"
+    }));
+    assert_eq!(comment.actor.as_deref(), Some("hubot"));
+    assert_eq!(comment.kind_name(), "commented");
+    assert_eq!(comment.summary(), "commented: Looking into it");
+
+    let closed = TimelineEvent::from_value(&json!({
+        "event": "closed",
+        "actor": { "login": "octocat" },
+        "commit_id": "0123456789abcdef",
+        "state_reason": "completed"
+    }));
+    assert_eq!(closed.summary(), "closed as completed by commit 0123456");
+
+    let unknown = TimelineEvent::from_value(&json!({ "event": "pinned" }));
+    assert_eq!(unknown.kind_name(), "pinned");
+    assert!(unknown.actor.is_none());
+}
+
+#[test]
+fn test_linked_pull_requests() {
+    let reference = |number: u64, pull_request: serde_json::Value| {
+        TimelineEvent::from_value(&json!({
+            "event": "cross-referenced",
+            "actor": { "login": "octocat" },
+            "created_at": "2025-03-02T09:00:00Z",
+            "source": {
+                "type": "issue",
+                "issue": {
+                    "number": number,
+                    "title": format!("Change {number}"),
+                    "state": "closed",
+                    "pull_request": pull_request,
+                    "repository": { "full_name": "octo/repo" }
+                }
+            }
+        }))
+    };
+
+    let events = vec![
+        reference(7, json!({ "merged_at": "2025-03-03T00:00:00Z" })),
+        reference(8, serde_json::Value::Null),
+        reference(7, json!({ "merged_at": "2025-03-03T00:00:00Z" })),
+        reference(9, json!({ "merged_at": null })),
+    ];
+
+    let linked = linked_pull_requests(&events);
+    let numbers: Vec<_> = linked.iter().map(|pr| (pr.number, pr.merged)).collect();
+    assert_eq!(numbers, [(7, true), (9, false)]);
+    assert_eq!(linked[0].repo.as_deref(), Some("octo/repo"));
+    assert_eq!(
+        events[1].summary(),
+        "referenced from issue octo/repo#8 'Change 8'"
+    );
+}
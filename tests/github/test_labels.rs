@@ -0,0 +1,120 @@
+//! Tests for label spec parsing and label sync planning.
+
+use kodegen_tools_github::{
+    ExistingLabel, LabelChange, LabelSpec, LabelSpecFormat, normalize_color, parse_label_spec,
+    plan_label_sync,
+};
+
+fn existing(name: &str, color: &str, description: Option<&str>) -> ExistingLabel {
+    ExistingLabel {
+        name: name.to_string(),
+        color: color.to_string(),
+        description: description.map(str::to_string),
+    }
+}
+
+fn spec(name: &str, color: &str, description: Option<&str>, aliases: &[&str]) -> LabelSpec {
+    LabelSpec {
+        name: name.to_string(),
+        color: color.to_string(),
+        description: description.map(str::to_string),
+        aliases: aliases.iter().map(|a| (*a).to_string()).collect(),
+    }
+}
+
+#[test]
+fn test_parse_label_spec_yaml_and_toml() {
+    let yaml = "
+- name: bug
+  color: '#D73A4A'
+  description: Something isn't working
+- name: enhancement
+  color: a2eeef
+  aliases: [feature]
+";
+    let toml = r##"
+[[labels]]
+name = "bug"
+color = "#D73A4A"
+description = "Something isn't working"
+
+[[labels]]
+name = "enhancement"
+color = "a2eeef"
+aliases = ["feature"]
+"##;
+
+    let from_yaml = parse_label_spec(yaml, LabelSpecFormat::Yaml).expect("valid yaml");
+    let from_toml = parse_label_spec(toml, LabelSpecFormat::Toml).expect("valid toml");
+    assert_eq!(from_yaml, from_toml);
+    assert_eq!(from_yaml[0].color, "d73a4a");
+    assert_eq!(from_yaml[1].aliases, ["feature"]);
+
+    let wrapped = parse_label_spec(
+        "labels:\n  - name: bug\n    color: d73a4a\n",
+        LabelSpecFormat::Yaml,
+    )
+    .expect("labels key");
+    assert_eq!(wrapped.len(), 1);
+}
+
+#[test]
+fn test_parse_label_spec_rejects_bad_input() {
+    assert!(parse_label_spec("- name: bug\n  color: red\n", LabelSpecFormat::Yaml).is_err());
+    assert!(
+        parse_label_spec(
+            "- name: bug\n  color: d73a4a\n- name: defect\n  color: d73a4a\n  aliases: [Bug]\n",
+            LabelSpecFormat::Yaml
+        )
+        .is_err()
+    );
+    assert_eq!(
+        LabelSpecFormat::from_path(".github/labels.yml"),
+        Some(LabelSpecFormat::Yaml)
+    );
+    assert_eq!(LabelSpecFormat::from_path("labels.json"), None);
+    assert_eq!(normalize_color("#ABCDEF").ok().as_deref(), Some("abcdef"));
+}
+
+#[test]
+fn test_plan_label_sync() {
+    let current = vec![
+        existing("bug", "d73a4a", Some("Something isn't working")),
+        existing("feature", "cccccc", None),
+        existing("Docs", "0075ca", None),
+        existing("wontfix", "ffffff", None),
+    ];
+    let desired = vec![
+        spec("bug", "d73a4a", Some("Something isn't working"), &[]),
+        spec("enhancement", "a2eeef", None, &["feature"]),
+        spec("docs", "0075ca", None, &[]),
+        spec("good first issue", "7057ff", None, &[]),
+    ];
+
+    let changes = plan_label_sync(&current, &desired, false);
+    assert_eq!(
+        changes,
+        vec![
+            LabelChange::Update {
+                current_name: "feature".to_string(),
+                label: desired[1].clone(),
+            },
+            LabelChange::Update {
+                current_name: "Docs".to_string(),
+                label: desired[2].clone(),
+            },
+            LabelChange::Create {
+                label: desired[3].clone(),
+            },
+        ]
+    );
+
+    let pruned = plan_label_sync(&current, &desired, true);
+    assert_eq!(
+        pruned.last(),
+        Some(&LabelChange::Delete {
+            name: "wontfix".to_string(),
+        })
+    );
+    assert_eq!(pruned.len(), 4);
+}
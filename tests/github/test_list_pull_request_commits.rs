@@ -0,0 +1,70 @@
+//! Tests for GitHub Pull Request commit listing.
+
+use kodegen_tools_github::PullRequestCommit;
+use octocrab::models::repos::RepoCommit;
+use serde_json::json;
+
+fn repo_commit(detailed: bool) -> RepoCommit {
+    let mut value = json!({
+        "url": "https://api.github.com/repos/octo/hello/commits/6dcb09b5",
+        "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+        "node_id": "C_kwDOA",
+        "html_url": "https://github.com/octo/hello/commit/6dcb09b5",
+        "comments_url": "https://api.github.com/repos/octo/hello/commits/6dcb09b5/comments",
+        "commit": {
+            "url": "https://api.github.com/repos/octo/hello/git/commits/6dcb09b5",
+            "author": {
+                "name": "Mona Octocat",
+                "email": "mona@github.com",
+                "date": "2025-03-01T12:00:00Z"
+            },
+            "committer": null,
+            "message": "Fix login crash\n\nCloses #12",
+            "comment_count": 0,
+            "tree": {
+                "sha": "827efc6d",
+                "url": "https://api.github.com/repos/octo/hello/git/trees/827efc6d"
+            }
+        },
+        "author": null,
+        "committer": null,
+        "parents": []
+    });
+    if detailed {
+        value["stats"] = json!({ "total": 12, "additions": 10, "deletions": 2 });
+        value["files"] = json!([{
+            "sha": "bbcd538c",
+            "filename": "src/login.rs",
+            "status": "modified",
+            "additions": 10,
+            "deletions": 2,
+            "changes": 12,
+            "blob_url": null,
+            "raw_url": null,
+            "contents_url": "https://api.github.com/repos/octo/hello/contents/src/login.rs"
+        }]);
+    }
+    serde_json::from_value(value).expect("commit deserializes")
+}
+
+#[test]
+fn test_commit_summary_with_stats() {
+    let commit = PullRequestCommit::from(&repo_commit(true));
+    assert_eq!(commit.sha, "6dcb09b5b57875f334f61aebed695e2e4193db5e");
+    assert_eq!(commit.author_name, "Mona Octocat");
+    assert_eq!(commit.author_email, "mona@github.com");
+    assert_eq!(commit.author_login, None);
+    assert_eq!(commit.date, "2025-03-01T12:00:00+00:00");
+    assert_eq!(commit.additions, Some(10));
+    assert_eq!(commit.deletions, Some(2));
+    assert_eq!(commit.files, Some(vec!["src/login.rs".to_string()]));
+}
+
+#[test]
+fn test_commit_summary_without_stats() {
+    let commit = PullRequestCommit::from(&repo_commit(false));
+    assert_eq!(commit.message, "Fix login crash\n\nCloses #12");
+    assert_eq!(commit.additions, None);
+    assert_eq!(commit.deletions, None);
+    assert_eq!(commit.files, None);
+}
@@ -5,3 +5,34 @@ async fn test_merge_pull_request_structure() {
     // Test that the function signature compiles
     // Actual API test would require a token and test repo
 }
+
+#[test]
+fn test_merge_readiness_blockers() {
+    use kodegen_tools_github::{GitHubError, MergeBlocker, MergeReadiness};
+
+    let ready = MergeReadiness {
+        head_sha: "abc123".to_string(),
+        blockers: Vec::new(),
+    };
+    assert!(ready.is_ready());
+
+    let blocked = MergeReadiness {
+        head_sha: "abc123".to_string(),
+        blockers: vec![
+            MergeBlocker::CheckPending {
+                name: "ci/build".to_string(),
+            },
+            MergeBlocker::InsufficientApprovals {
+                required: 2,
+                approved: 1,
+            },
+        ],
+    };
+    assert!(!blocked.is_ready());
+
+    let err = GitHubError::MergeBlocked(blocked.blockers);
+    assert_eq!(
+        err.to_string(),
+        "Merge blocked: required check 'ci/build' is pending; 1 of 2 required approvals"
+    );
+}
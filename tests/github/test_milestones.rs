@@ -0,0 +1,89 @@
+//! Tests for milestone progress and burndown computation.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use kodegen_tools_github::{Milestone, MilestoneItem, MilestoneProgress, burndown};
+
+fn at(date: &str) -> DateTime<Utc> {
+    format!("{date}T12:00:00Z")
+        .parse()
+        .expect("valid timestamp")
+}
+
+fn day(date: &str) -> NaiveDate {
+    date.parse().expect("valid date")
+}
+
+fn item(number: u64, created: &str, closed: Option<&str>) -> MilestoneItem {
+    MilestoneItem {
+        number,
+        title: format!("Item {number}"),
+        is_pull_request: number % 2 == 0,
+        open: closed.is_none(),
+        created_at: at(created),
+        closed_at: closed.map(at),
+        html_url: format!("https://github.com/octo/repo/issues/{number}"),
+    }
+}
+
+fn milestone(created: &str, due: Option<&str>) -> Milestone {
+    Milestone {
+        number: 3,
+        title: "v1.0".to_string(),
+        description: None,
+        state: "open".to_string(),
+        open_issues: 0,
+        closed_issues: 0,
+        due_on: due.map(at),
+        created_at: at(created),
+        closed_at: None,
+        html_url: "https://github.com/octo/repo/milestone/3".to_string(),
+    }
+}
+
+#[test]
+fn test_burndown_counts_per_day() {
+    let items = vec![
+        item(1, "2025-01-01", Some("2025-01-02")),
+        item(2, "2025-01-01", None),
+        item(3, "2025-01-03", Some("2025-01-03")),
+    ];
+
+    let points = burndown(&items, day("2025-01-01"), day("2025-01-04"));
+    let counts: Vec<_> = points.iter().map(|p| (p.open, p.closed)).collect();
+    assert_eq!(counts, [(2, 0), (1, 1), (1, 2), (1, 2)]);
+    assert_eq!(points[0].date, day("2025-01-01"));
+    assert_eq!(points[3].date, day("2025-01-04"));
+}
+
+#[test]
+fn test_milestone_progress_overdue_and_percent() {
+    let items = vec![
+        item(1, "2025-01-01", Some("2025-01-02")),
+        item(2, "2025-01-01", None),
+        item(3, "2025-01-02", None),
+        item(4, "2025-01-02", Some("2025-01-05")),
+    ];
+
+    let report = MilestoneProgress::build(
+        milestone("2025-01-01", Some("2025-01-04")),
+        &items,
+        at("2025-01-06"),
+    );
+    assert_eq!(report.open_count, 2);
+    assert_eq!(report.closed_count, 2);
+    assert!((report.percent_complete - 50.0).abs() < f64::EPSILON);
+    let overdue: Vec<_> = report.overdue.iter().map(|i| i.number).collect();
+    assert_eq!(overdue, [2, 3]);
+    assert_eq!(report.burndown.len(), 6);
+
+    let on_track = MilestoneProgress::build(
+        milestone("2025-01-01", Some("2025-02-01")),
+        &items,
+        at("2025-01-06"),
+    );
+    assert!(on_track.overdue.is_empty());
+
+    let empty = MilestoneProgress::build(milestone("2025-01-01", None), &[], at("2025-01-01"));
+    assert!(empty.percent_complete.abs() < f64::EPSILON);
+    assert_eq!(empty.burndown.len(), 1);
+}
@@ -0,0 +1,23 @@
+//! Tests for the pending review workflow.
+
+use kodegen_tools_github::{DiffSide, PendingReviewComment};
+
+#[test]
+fn test_pending_review_comment_sides() {
+    let comment: PendingReviewComment = serde_json::from_value(serde_json::json!({
+        "path": "src/lib.rs",
+        "body": "Consider a const here",
+        "line": 12,
+        "side": "LEFT",
+        "start_line": 10,
+        "start_side": null,
+    }))
+    .expect("comment deserializes");
+
+    assert_eq!(comment.side, DiffSide::Left);
+    assert_eq!(comment.start_side, None);
+    assert_eq!(
+        serde_json::to_value(&comment).expect("serializes")["side"],
+        "LEFT"
+    );
+}
@@ -0,0 +1,96 @@
+//! Tests for generated pull request titles and bodies.
+
+use kodegen_tools_github::{CommitSummary, PullRequestContent, parse_linked_issues};
+
+fn commit(sha: &str, subject: &str) -> CommitSummary {
+    CommitSummary {
+        sha: sha.to_string(),
+        subject: subject.to_string(),
+    }
+}
+
+#[test]
+fn test_parse_linked_issues() {
+    let issues = parse_linked_issues(
+        "Handle empty input\n\nFixes #12, closes octo/tools#7.\nResolved: #12\nSee #99",
+    );
+
+    let refs: Vec<_> = issues.iter().map(|i| i.reference.as_str()).collect();
+    assert_eq!(refs, ["#12", "octo/tools#7"]);
+    assert_eq!(issues[0].keyword, "fixes");
+    assert_eq!(issues[1].keyword, "closes");
+}
+
+#[test]
+fn test_title_from_single_commit_or_branch() {
+    let single = PullRequestContent::build(
+        "feature/x",
+        vec![commit("abc1234def", "Add retry to uploads")],
+        &[],
+        vec![],
+        None,
+    );
+    assert_eq!(single.title, "Add retry to uploads");
+
+    let several = PullRequestContent::build(
+        "feature/add-login_form",
+        vec![commit("a", "One"), commit("b", "Two")],
+        &[],
+        vec![],
+        None,
+    );
+    assert_eq!(several.title, "Add login form");
+}
+
+#[test]
+fn test_body_without_template_appends_sections() {
+    let content = PullRequestContent::build(
+        "fix-parser",
+        vec![commit("0123456789", "Fix parser")],
+        &["Fix parser\n\nFixes #5".to_string()],
+        vec![
+            "src/parser.rs".to_string(),
+            "src/lexer.rs".to_string(),
+            "README.md".to_string(),
+        ],
+        None,
+    );
+
+    assert!(content.template_path.is_none());
+    assert_eq!(
+        content.body,
+        "## Commits\n\n- 0123456 Fix parser\n\n\
+         ## Linked issues\n\nFixes #5\n\n\
+         ## Changed paths\n\n- **(root)** (1): `README.md`\n\
+         - **src/** (2): `src/parser.rs`, `src/lexer.rs`"
+    );
+}
+
+#[test]
+fn test_body_fills_template_headings_and_placeholders() {
+    let template = "## Description\n<!-- What does this change? -->\n\n\
+                    ## Related issue\n{{linked_issues}}\n\n\
+                    ## Checklist\n- [ ] Tests\n";
+    let content = PullRequestContent::build(
+        "topic",
+        vec![commit("aaaaaaa1", "First"), commit("bbbbbbb2", "Second")],
+        &["Second\n\ncloses #3".to_string()],
+        vec!["docs/guide.md".to_string()],
+        Some((
+            ".github/pull_request_template.md".to_string(),
+            template.to_string(),
+        )),
+    );
+
+    assert_eq!(
+        content.template_path.as_deref(),
+        Some(".github/pull_request_template.md")
+    );
+    assert_eq!(
+        content.body,
+        "## Description\n<!-- What does this change? -->\n- aaaaaaa First\n- bbbbbbb Second\n\n\
+         ## Related issue\nCloses #3\n\n\
+         ## Checklist\n- [ ] Tests\n\n\
+         ## Changed paths\n\n- **docs/** (1): `docs/guide.md`"
+    );
+}
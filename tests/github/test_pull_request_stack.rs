@@ -0,0 +1,56 @@
+//! Tests for stacked pull request navigation rendering.
+
+use kodegen_tools_github::{
+    STACK_SECTION_END, STACK_SECTION_START, StackEntry, StackEntryStatus, render_stack_section,
+    upsert_stack_section,
+};
+
+fn entry(branch: &str, pr_number: u64, status: StackEntryStatus) -> StackEntry {
+    StackEntry {
+        branch: branch.to_string(),
+        pr_number,
+        title: format!("Part {pr_number}"),
+        base: "main".to_string(),
+        html_url: None,
+        status,
+    }
+}
+
+#[test]
+fn test_render_stack_section() {
+    let entries = vec![
+        entry("part-1", 10, StackEntryStatus::Merged),
+        entry(
+            "part-2",
+            11,
+            StackEntryStatus::Retargeted {
+                from: "part-1".to_string(),
+            },
+        ),
+        entry("part-3", 12, StackEntryStatus::Created),
+    ];
+
+    let section = render_stack_section(&entries, 11);
+    assert!(section.starts_with(STACK_SECTION_START));
+    assert!(section.ends_with(STACK_SECTION_END));
+
+    let items: Vec<&str> = section.lines().filter(|l| l.starts_with("- ")).collect();
+    assert_eq!(items, vec!["- #12", "- 👉 #11", "- #10 ✅ merged"]);
+}
+
+#[test]
+fn test_upsert_stack_section() {
+    let first = format!("{STACK_SECTION_START}\nold\n{STACK_SECTION_END}");
+    let second = format!("{STACK_SECTION_START}\nnew\n{STACK_SECTION_END}");
+
+    // Appended after existing content
+    let body = upsert_stack_section("Adds the parser.\n", &first);
+    assert_eq!(body, format!("Adds the parser.\n\n{first}"));
+
+    // Replaced in place, surrounding text preserved
+    let body = upsert_stack_section(&format!("{body}\n\nFooter"), &second);
+    assert_eq!(body, format!("Adds the parser.\n\n{second}\n\nFooter"));
+
+    // Empty body becomes just the section
+    assert_eq!(upsert_stack_section("", &first), first);
+}
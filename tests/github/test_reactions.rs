@@ -0,0 +1,49 @@
+//! Tests for comment kinds, reaction targets and reaction parsing.
+
+use kodegen_tools_github::{CommentKind, ReactionTarget, parse_reaction_content, reaction_name};
+
+#[test]
+fn test_comment_kind_parsing() {
+    assert_eq!(
+        "issue".parse::<CommentKind>().ok(),
+        Some(CommentKind::Issue)
+    );
+    assert_eq!(
+        "Review_Comment".parse::<CommentKind>().ok(),
+        Some(CommentKind::Review)
+    );
+    assert!("commit".parse::<CommentKind>().is_err());
+    assert_eq!(CommentKind::Review.path(), "pulls/comments");
+}
+
+#[test]
+fn test_reaction_target_from_parts() {
+    let issue = ReactionTarget::from_parts(Some(12), None, CommentKind::Issue).ok();
+    assert_eq!(issue, Some(ReactionTarget::Issue(12)));
+    assert_eq!(ReactionTarget::Issue(12).path(), "issues/12/reactions");
+
+    let review = ReactionTarget::from_parts(Some(12), Some(99), CommentKind::Review).ok();
+    assert_eq!(review, Some(ReactionTarget::ReviewComment(99)));
+    assert_eq!(
+        ReactionTarget::IssueComment(5).path(),
+        "issues/comments/5/reactions"
+    );
+
+    assert!(ReactionTarget::from_parts(None, None, CommentKind::Issue).is_err());
+}
+
+#[test]
+fn test_parse_reaction_content() {
+    for (input, expected) in [
+        ("+1", "+1"),
+        ("thumbs_up", "+1"),
+        ("👎", "-1"),
+        ("Rocket", "rocket"),
+        ("tada", "hooray"),
+        ("eyes", "eyes"),
+    ] {
+        let content = parse_reaction_content(input).expect("known reaction");
+        assert_eq!(reaction_name(&content), expected, "input {input}");
+    }
+    assert!(parse_reaction_content("party").is_err());
+}
@@ -0,0 +1,90 @@
+//! Tests for requested reviewer validation.
+
+use kodegen_tools_github::{GitHubError, missing_collaborators, team_slug, validate_login};
+
+fn lookups(entries: &[(&str, u16)]) -> Vec<(String, u16)> {
+    entries
+        .iter()
+        .map(|(login, status)| (login.to_string(), *status))
+        .collect()
+}
+
+#[test]
+fn test_missing_collaborators_lists_not_found_logins() {
+    let result = missing_collaborators(&lookups(&[
+        ("octocat", 204),
+        ("typo-user", 404),
+        ("hubot", 204),
+        ("ghost", 404),
+    ]));
+    assert_eq!(
+        result,
+        Some(vec!["typo-user".to_string(), "ghost".to_string()])
+    );
+}
+
+#[test]
+fn test_missing_collaborators_all_present() {
+    let result = missing_collaborators(&lookups(&[("octocat", 204), ("hubot", 204)]));
+    assert_eq!(result, Some(Vec::new()));
+    assert_eq!(missing_collaborators(&[]), Some(Vec::new()));
+}
+
+#[test]
+fn test_missing_collaborators_skipped_without_access() {
+    let result = missing_collaborators(&lookups(&[("typo-user", 404), ("octocat", 403)]));
+    assert_eq!(result, None);
+}
+
+#[test]
+fn test_validate_login() {
+    assert!(validate_login("octocat").is_ok());
+    assert!(validate_login("Octo-Cat-42").is_ok());
+
+    for login in [
+        "",
+        "octo/cat",
+        "octocat?per_page=1",
+        "../octocat",
+        "octo cat",
+        "@octocat",
+    ] {
+        assert!(
+            matches!(validate_login(login), Err(GitHubError::InvalidInput(_))),
+            "{login:?} should be rejected"
+        );
+    }
+}
+
+#[test]
+fn test_team_slug_splits_org_prefix() {
+    assert_eq!(team_slug("octo-org", "core").unwrap(), "core");
+    assert_eq!(team_slug("octo-org", "octo-org/core").unwrap(), "core");
+    assert_eq!(
+        team_slug("octo-org", "@Octo-Org/core-team").unwrap(),
+        "core-team"
+    );
+    assert_eq!(
+        team_slug("octo-org", "octo-org/release_eng").unwrap(),
+        "release_eng"
+    );
+}
+
+#[test]
+fn test_team_slug_rejects_other_orgs_and_bad_slugs() {
+    for team in [
+        "other-org/core",
+        "octo-org/",
+        "",
+        "octo-org/core/extra",
+        "core?x=1",
+    ] {
+        assert!(
+            matches!(
+                team_slug("octo-org", team),
+                Err(GitHubError::InvalidInput(_))
+            ),
+            "{team:?} should be rejected"
+        );
+    }
+}
@@ -0,0 +1,36 @@
+//! Tests for GitHub Pull Request review thread operations.
+
+use kodegen_tools_github::ReviewThread;
+
+#[test]
+fn test_review_thread_from_graphql_node() {
+    let node = serde_json::json!({
+        "id": "PRRT_kwDOA",
+        "isResolved": true,
+        "isOutdated": false,
+        "path": "src/lib.rs",
+        "line": 42,
+        "originalLine": 40,
+        "startLine": null,
+        "diffSide": "RIGHT",
+        "resolvedBy": { "login": "octocat" },
+        "comments": {
+            "nodes": [{
+                "id": "PRRC_kwDOA",
+                "databaseId": 1234,
+                "author": null,
+                "body": "Consider renaming this.",
+                "createdAt": "2025-01-01T00:00:00Z",
+                "url": "https://github.com/o/r/pull/1#discussion_r1234"
+            }]
+        }
+    });
+
+    let thread: ReviewThread = serde_json::from_value(node).expect("thread deserializes");
+    assert!(thread.is_resolved);
+    assert_eq!(thread.line, Some(42));
+    assert_eq!(thread.resolved_by.as_deref(), Some("octocat"));
+    assert_eq!(thread.comments.len(), 1);
+    assert_eq!(thread.comments[0].database_id, Some(1234));
+    assert_eq!(thread.comments[0].author, None);
+}
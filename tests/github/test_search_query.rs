@@ -0,0 +1,175 @@
+//! Tests for the typed search query builders.
+
+use chrono::NaiveDate;
+use kodegen_tools_github::{
+    CodeSearchQuery, CodeSearchSort, DateRange, IssueKind, IssueSearchQuery, IssueSearchSort,
+    IssueSearchState, MAX_QUERY_LENGTH, QueryRange, SearchOrder, quote_search_value,
+};
+
+fn date(s: &str) -> NaiveDate {
+    s.parse().expect("valid date")
+}
+
+#[test]
+fn test_query_range() {
+    assert_eq!(
+        ">=2024-01-01".parse::<DateRange>().ok(),
+        Some(QueryRange::AtLeast(date("2024-01-01")))
+    );
+    assert_eq!(
+        "2024-01-01..2024-03-31".parse::<DateRange>().ok(),
+        Some(QueryRange::Between(date("2024-01-01"), date("2024-03-31")))
+    );
+    assert_eq!(
+        "*..10".parse::<QueryRange<u64>>().ok(),
+        Some(QueryRange::AtMost(10))
+    );
+    assert_eq!(
+        "<5".parse::<QueryRange<u64>>().ok(),
+        Some(QueryRange::Below(5))
+    );
+    assert_eq!(
+        "7".parse::<QueryRange<u64>>().ok(),
+        Some(QueryRange::Exactly(7))
+    );
+    assert!("last week".parse::<DateRange>().is_err());
+    assert!("1..".parse::<QueryRange<u64>>().is_err());
+
+    assert_eq!(QueryRange::Above(3u64).to_string(), ">3");
+    assert_eq!(
+        QueryRange::Between(date("2024-01-01"), date("2024-01-31")).to_string(),
+        "2024-01-01..2024-01-31"
+    );
+}
+
+#[test]
+fn test_quote_search_value() {
+    assert_eq!(quote_search_value("bug").ok().as_deref(), Some("bug"));
+    assert_eq!(
+        quote_search_value(" help wanted ").ok().as_deref(),
+        Some("\"help wanted\"")
+    );
+    assert_eq!(
+        quote_search_value("area:ui").ok().as_deref(),
+        Some("\"area:ui\"")
+    );
+    assert!(quote_search_value("say \"hi\"").is_err());
+    assert!(quote_search_value("  ").is_err());
+}
+
+#[test]
+fn test_issue_search_query() {
+    let query = IssueSearchQuery::new()
+        .text("crash login")
+        .repo("octo/hello")
+        .kind(IssueKind::PullRequest)
+        .state(IssueSearchState::Merged)
+        .label("help wanted")
+        .exclude_label("wontfix")
+        .author("alice")
+        .created(QueryRange::AtLeast(date("2024-01-01")))
+        .comments(QueryRange::Above(5))
+        .in_title()
+        .in_body()
+        .in_title()
+        .build()
+        .expect("valid query");
+    assert_eq!(
+        query,
+        "crash login repo:octo/hello is:pr is:merged label:\"help wanted\" -label:wontfix \
+         author:alice created:>=2024-01-01 comments:>5 in:title,body"
+    );
+
+    let query = IssueSearchQuery::new()
+        .phrase("out of memory")
+        .missing("assignee")
+        .exclude("author", "app/dependabot")
+        .build()
+        .expect("valid query");
+    assert_eq!(
+        query,
+        "\"out of memory\" no:assignee -author:app/dependabot"
+    );
+
+    assert!(IssueSearchQuery::new().build().is_err());
+    assert!(IssueSearchQuery::new().repo("hello").build().is_err());
+    assert!(IssueSearchQuery::new().missing("reviewer").build().is_err());
+    assert!(IssueSearchQuery::new().label("say \"hi\"").build().is_err());
+    assert!(
+        IssueSearchQuery::new()
+            .qualifier("Bad Name", "x")
+            .build()
+            .is_err()
+    );
+    assert!(
+        IssueSearchQuery::new()
+            .text("x".repeat(300))
+            .build()
+            .is_err()
+    );
+}
+
+#[test]
+fn test_code_search_query() {
+    let query = CodeSearchQuery::new()
+        .text("async fn")
+        .repo("octo/hello")
+        .language("rust")
+        .path("src/github")
+        .extension(".rs")
+        .in_file()
+        .build()
+        .expect("valid query");
+    assert_eq!(
+        query,
+        "async fn repo:octo/hello language:rust path:src/github extension:rs in:file"
+    );
+
+    assert!(CodeSearchQuery::new().language("rust").build().is_err());
+}
+
+#[test]
+fn test_search_sort_and_order() {
+    assert_eq!(
+        "Updated".parse::<IssueSearchSort>().ok(),
+        Some(IssueSearchSort::Updated)
+    );
+    assert_eq!(IssueSearchSort::Interactions.as_str(), "interactions");
+    assert!("stars".parse::<IssueSearchSort>().is_err());
+    assert_eq!(
+        "indexed".parse::<CodeSearchSort>().ok(),
+        Some(CodeSearchSort::Indexed)
+    );
+    assert_eq!("pr".parse::<IssueKind>().ok(), Some(IssueKind::PullRequest));
+    assert!("discussion".parse::<IssueKind>().is_err());
+    assert_eq!("DESC".parse::<SearchOrder>().ok(), Some(SearchOrder::Desc));
+    assert!("up".parse::<SearchOrder>().is_err());
+}
+
+#[test]
+fn test_length_limit_counts_keywords_only() {
+    let mut query = IssueSearchQuery::new()
+        .text("crash")
+        .repo("octo-org/hello-world");
+    for i in 0..30 {
+        query = query.exclude_label(format!("area-{i}"));
+    }
+    let built = query
+        .build()
+        .expect("qualifiers do not count towards the limit");
+    assert!(built.chars().count() > MAX_QUERY_LENGTH);
+
+    let long_text = "word ".repeat(MAX_QUERY_LENGTH / 5 + 1);
+    assert!(IssueSearchQuery::new().text(long_text).build().is_err());
+
+    let raw_qualifiers: Vec<String> = (0..30).map(|i| format!("-label:area-{i}")).collect();
+    let raw = format!(
+        "crash repo:octo-org/hello-world {}",
+        raw_qualifiers.join(" ")
+    );
+    assert!(raw.chars().count() > MAX_QUERY_LENGTH);
+    assert_eq!(
+        IssueSearchQuery::new().text(raw.as_str()).build().unwrap(),
+        raw
+    );
+}
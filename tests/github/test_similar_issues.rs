@@ -0,0 +1,112 @@
+//! Tests for duplicate-issue search queries and similarity ranking.
+
+use kodegen_tools_github::{
+    CreateIssueRequest, DEFAULT_DUPLICATE_THRESHOLD, IssueText, SimilarIssue, build_search_queries,
+    likely_duplicates, score_similarity, tokenize,
+};
+
+#[test]
+fn test_tokenize() {
+    assert_eq!(
+        tokenize("The login page crashes when I click Submit (v2.1)"),
+        ["login", "page", "crash", "click", "submit", "v2"]
+    );
+    assert_eq!(tokenize("Fix dependencies"), ["fix", "dependency"]);
+    assert!(tokenize("it is 42").is_empty());
+}
+
+#[test]
+fn test_build_search_queries() {
+    let candidate = IssueText::new(
+        "Login page crashes on submit",
+        "Clicking submit on the login page crashes the app. Login worked in 1.2.",
+        vec!["bug".to_string()],
+    );
+
+    let queries = build_search_queries("octo", "repo", &candidate, false);
+    assert_eq!(
+        queries[0],
+        "repo:octo/repo is:issue is:open in:title login page crash submit"
+    );
+    assert!(queries[1].starts_with("repo:octo/repo is:issue is:open login OR "));
+    assert!(!queries[1].contains("label:"));
+
+    let closed = build_search_queries("octo", "repo", &candidate, true);
+    assert!(closed[0].starts_with("repo:octo/repo is:issue in:title"));
+
+    assert!(build_search_queries("octo", "repo", &IssueText::default(), false).is_empty());
+}
+
+#[test]
+fn test_score_similarity() {
+    let query = IssueText::new(
+        "Login page crashes on submit",
+        "Clicking submit on the login form crashes the app",
+        vec!["bug".to_string()],
+    );
+    let candidates = [
+        IssueText::new(
+            "Crash when submitting the login page",
+            "The app crashes after pressing submit on login",
+            vec!["bug".to_string()],
+        ),
+        IssueText::new(
+            "Add dark mode to settings page",
+            "It would be nice to have a dark theme",
+            vec!["enhancement".to_string()],
+        ),
+        IssueText::new("Login page is slow", "Takes 10 seconds to load", Vec::new()),
+    ];
+
+    let scores = score_similarity(&query, &candidates);
+    assert_eq!(scores.len(), 3);
+    assert!(scores[0].score > scores[2].score);
+    assert!(scores[2].score > scores[1].score);
+    assert!(scores[0].score > 0.5, "{}", scores[0].score);
+    assert!(scores[1].score < 0.2, "{}", scores[1].score);
+    assert!(scores[0].shared_terms.contains(&"crash".to_string()));
+    assert!(scores.iter().all(|s| (0.0..=1.0).contains(&s.score)));
+
+    let identical = score_similarity(&query, std::slice::from_ref(&query));
+    assert!((identical[0].score - 1.0).abs() < 1e-9);
+}
+
+fn similar(number: u64, score: f64) -> SimilarIssue {
+    SimilarIssue {
+        number,
+        title: format!("Issue {number}"),
+        state: "open".to_string(),
+        html_url: format!("https://github.com/octo/hello/issues/{number}"),
+        labels: Vec::new(),
+        score,
+        shared_terms: Vec::new(),
+    }
+}
+
+#[test]
+fn test_duplicate_guard_threshold() {
+    let mut request = CreateIssueRequest::default();
+    assert_eq!(request.guard_threshold(), None);
+
+    request.duplicate_threshold = Some(0.8);
+    assert_eq!(request.guard_threshold(), None);
+
+    request.check_duplicates = true;
+    assert_eq!(request.guard_threshold(), Some(0.8));
+
+    request.duplicate_threshold = None;
+    assert_eq!(request.guard_threshold(), Some(DEFAULT_DUPLICATE_THRESHOLD));
+}
+
+#[test]
+fn test_likely_duplicates_blocks_at_threshold() {
+    let found = vec![similar(7, 0.9), similar(3, 0.5), similar(12, 0.2)];
+    let numbers: Vec<u64> = likely_duplicates(found.clone(), 0.5)
+        .iter()
+        .map(|i| i.number)
+        .collect();
+    assert_eq!(numbers, [7, 3]);
+
+    assert!(likely_duplicates(found, 0.95).is_empty());
+    assert!(likely_duplicates(Vec::new(), 0.0).is_empty());
+}
@@ -0,0 +1,25 @@
+//! Tests for sticky comment markers and bodies.
+
+use kodegen_tools_github::{has_sticky_marker, render_sticky_body, sticky_marker};
+
+#[test]
+fn test_sticky_marker() {
+    assert_eq!(
+        sticky_marker("coverage"),
+        "<!-- sticky-comment:coverage -->"
+    );
+    assert_eq!(sticky_marker(" coverage "), sticky_marker("coverage"));
+}
+
+#[test]
+fn test_render_sticky_body() {
+    let body = render_sticky_body("coverage", "Coverage: 87%");
+    assert_eq!(body, "<!-- sticky-comment:coverage -->\nCoverage: 87%");
+    assert!(has_sticky_marker(&body, "coverage"));
+    assert!(!has_sticky_marker(&body, "cover"));
+    assert!(!has_sticky_marker(&body, "lint"));
+
+    // Re-rendering an already marked body doesn't duplicate the marker
+    let again = render_sticky_body("coverage", &body);
+    assert_eq!(again, body);
+}
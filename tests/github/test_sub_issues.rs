@@ -0,0 +1,69 @@
+//! Tests for issue references and sub-issue trees.
+
+use kodegen_tools_github::{IssueRef, IssueTreeNode};
+
+fn node(number: u64, closed: bool, children: Vec<IssueTreeNode>) -> IssueTreeNode {
+    IssueTreeNode {
+        issue: IssueRef::new("octo", "repo", number),
+        title: format!("Task {number}"),
+        closed,
+        html_url: format!("https://github.com/octo/repo/issues/{number}"),
+        children,
+        truncated: false,
+    }
+}
+
+#[test]
+fn test_issue_ref_parse() {
+    let parse = |s: &str| IssueRef::parse(s, "octo", "repo").ok();
+
+    assert_eq!(parse("42"), Some(IssueRef::new("octo", "repo", 42)));
+    assert_eq!(parse("#42"), Some(IssueRef::new("octo", "repo", 42)));
+    assert_eq!(
+        parse("other/docs#7"),
+        Some(IssueRef::new("other", "docs", 7))
+    );
+    assert_eq!(
+        parse("https://github.com/other/docs/issues/7#issuecomment-1"),
+        Some(IssueRef::new("other", "docs", 7))
+    );
+    assert_eq!(parse("docs#7"), None);
+    assert_eq!(parse("a/b/c#7"), None);
+    assert_eq!(parse("https://github.com/other/docs/wiki/7"), None);
+    assert_eq!(parse("seven"), None);
+
+    assert_eq!(
+        IssueRef::new("other", "docs", 7).to_string(),
+        "other/docs#7"
+    );
+    assert_eq!(
+        IssueRef::from_repository_url("https://api.github.com/repos/other/docs", 7),
+        Some(IssueRef::new("other", "docs", 7))
+    );
+}
+
+#[test]
+fn test_issue_tree_progress_and_rendering() {
+    let mut deep = node(4, false, Vec::new());
+    deep.truncated = true;
+    let tree = node(
+        1,
+        false,
+        vec![
+            node(2, true, Vec::new()),
+            node(3, false, vec![deep, node(5, true, Vec::new())]),
+        ],
+    );
+
+    assert_eq!(tree.progress(), (2, 4));
+
+    let expected = [
+        "- [ ] octo/repo#1 Task 1 (2/4)",
+        "  - [x] octo/repo#2 Task 2",
+        "  - [ ] octo/repo#3 Task 3 (1/2)",
+        "    - [ ] octo/repo#4 Task 4 …",
+        "    - [x] octo/repo#5 Task 5",
+        "",
+    ];
+    assert_eq!(tree.render_markdown(), expected.join("\n"));
+}
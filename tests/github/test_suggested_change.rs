@@ -0,0 +1,73 @@
+//! Tests for suggested-change review comments.
+
+use kodegen_tools_github::{PullRequestDiff, SuggestedChange};
+
+const DIFF: &str = "diff --git a/src/lib.rs b/src/lib.rs
+index 1111111..2222222 100644
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -10,3 +10,4 @@
+ fn a() {}
+-fn b() {}
++fn b() -> u8 { 0 }
++fn c() {}
+ fn d() {}
+diff --git a/old.rs b/old.rs
+deleted file mode 100644
+--- a/old.rs
++++ /dev/null
+@@ -1 +0,0 @@
+-gone
+";
+
+#[test]
+fn test_suggestion_body() {
+    let mut suggestion = SuggestedChange::new("src/lib.rs", 11, "fn b() -> u16 { 0 }\n");
+    assert_eq!(suggestion.body(), "```suggestion\nfn b() -> u16 { 0 }\n```");
+
+    suggestion.comment = Some("Widen the return type".to_string());
+    assert_eq!(
+        suggestion.body(),
+        "Widen the return type\n\n```suggestion\nfn b() -> u16 { 0 }\n```"
+    );
+
+    // Deleting lines yields an empty block
+    let delete = SuggestedChange::new("src/lib.rs", 12, "");
+    assert_eq!(delete.body(), "```suggestion\n```");
+
+    // Embedded fences get a longer outer fence
+    let nested = SuggestedChange::new("README.md", 1, "```rust\nlet x = 1;\n```");
+    assert!(nested.body().starts_with("````suggestion\n"));
+    assert!(nested.body().ends_with("\n````"));
+}
+
+#[test]
+fn test_suggestion_validation() {
+    let diff = PullRequestDiff::parse(DIFF);
+
+    let mut range = SuggestedChange::new("src/lib.rs", 12, "fn c() -> u8 { 1 }");
+    range.start_line = Some(10);
+    assert!(range.validate(&diff).is_ok());
+
+    let outside = SuggestedChange::new("src/lib.rs", 40, "x");
+    assert!(outside.validate(&diff).is_err());
+
+    let unchanged = SuggestedChange::new("src/main.rs", 1, "x");
+    assert!(unchanged.validate(&diff).is_err());
+
+    let deleted = SuggestedChange::new("old.rs", 1, "x");
+    assert!(deleted.validate(&diff).is_err());
+}
+
+#[test]
+fn test_suggestion_review_comment_payload() {
+    let mut suggestion = SuggestedChange::new("src/lib.rs", 12, "fn c() {}");
+    let single = suggestion.to_review_comment();
+    assert_eq!(single["side"], "RIGHT");
+    assert!(single.get("start_line").is_none());
+
+    suggestion.start_line = Some(11);
+    let multi = suggestion.to_review_comment();
+    assert_eq!(multi["start_line"], 11);
+    assert_eq!(multi["start_side"], "RIGHT");
+}
@@ -0,0 +1,16 @@
+//! Tests for GitHub Pull Request branch update operation.
+
+use kodegen_tools_github::UpdateBranchOutcome;
+
+#[test]
+fn test_update_branch_outcome_serialization() {
+    let outcome = UpdateBranchOutcome::Conflicts {
+        base_sha: "base".to_string(),
+        head_sha: "head".to_string(),
+        paths: vec!["src/lib.rs".to_string()],
+    };
+
+    let value = serde_json::to_value(&outcome).expect("outcome serializes");
+    assert_eq!(value["status"], "conflicts");
+    assert_eq!(value["paths"][0], "src/lib.rs");
+}